@@ -26,6 +26,14 @@ CREATE TABLE IF NOT EXISTS tasks (
     created_at TEXT NOT NULL
 );
 
+CREATE TABLE IF NOT EXISTS recurring_task_instances (
+    recurring_id TEXT NOT NULL,
+    date TEXT NOT NULL,
+    task_id TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (recurring_id, date)
+);
+
 CREATE TABLE IF NOT EXISTS pomodoro_logs (
     id TEXT PRIMARY KEY,
     block_id TEXT NOT NULL,
@@ -33,19 +41,30 @@ CREATE TABLE IF NOT EXISTS pomodoro_logs (
     start_time TEXT NOT NULL,
     end_time TEXT,
     phase TEXT NOT NULL,
-    interruption_reason TEXT
+    interruption_reason TEXT,
+    resumed_from TEXT
 );
 
-CREATE TABLE IF NOT EXISTS sync_state (
-    id INTEGER PRIMARY KEY CHECK (id = 1),
+CREATE TABLE IF NOT EXISTS account_sync_state (
+    account_id TEXT PRIMARY KEY,
     sync_token TEXT,
     last_sync_time TEXT NOT NULL
 );
 
+CREATE TABLE IF NOT EXISTS calendar_event_cache (
+    account_id TEXT NOT NULL,
+    event_id TEXT NOT NULL,
+    calendar_id TEXT,
+    payload_json TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    PRIMARY KEY (account_id, event_id)
+);
+
 CREATE TABLE IF NOT EXISTS suppressions (
     instance TEXT PRIMARY KEY,
     suppressed_at TEXT NOT NULL,
-    reason TEXT
+    reason TEXT,
+    expires_at TEXT
 );
 
 CREATE TABLE IF NOT EXISTS audit_logs (
@@ -54,3 +73,10 @@ CREATE TABLE IF NOT EXISTS audit_logs (
     payload_json TEXT NOT NULL,
     created_at TEXT NOT NULL
 );
+
+CREATE TABLE IF NOT EXISTS pomodoro_session (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    block_end_time TEXT,
+    payload_json TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
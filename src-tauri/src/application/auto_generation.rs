@@ -0,0 +1,78 @@
+use crate::application::block_generation::generate_blocks_at;
+use crate::application::commands::AppState;
+use crate::application::policy_service::{load_runtime_policy, next_auto_generation_at};
+use chrono::{DateTime, Datelike, Duration, Utc};
+use std::future::Future;
+use tokio::sync::watch;
+
+const AUTO_GENERATION_COMMAND: &str = "auto_generation";
+const DISABLED_RECHECK_MINUTES: i64 = 60;
+
+pub(crate) trait SchedulerClock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+    fn sleep_until(&self, deadline: DateTime<Utc>) -> impl Future<Output = ()> + Send;
+}
+
+pub(crate) struct SystemClock;
+
+impl SchedulerClock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    async fn sleep_until(&self, deadline: DateTime<Utc>) {
+        let wait = (deadline - Utc::now()).to_std().unwrap_or_default();
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// Runs generation at each `generation.autoTime` until the app signals shutdown.
+pub async fn run_auto_generation(state: &AppState) {
+    let shutdown = state.auto_generation_shutdown_signal();
+    run_auto_generation_with_clock(state, &SystemClock, shutdown).await;
+}
+
+pub(crate) async fn run_auto_generation_with_clock(
+    state: &AppState,
+    clock: &impl SchedulerClock,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    loop {
+        if *shutdown.borrow() {
+            return;
+        }
+        // The policy is re-read every round so edits to policies.json apply
+        // without restarting the app.
+        let policy = load_runtime_policy(state.config_dir());
+        let next_run = next_auto_generation_at(&policy, clock.now());
+        let wake_at = next_run
+            .unwrap_or_else(|| clock.now() + Duration::minutes(DISABLED_RECHECK_MINUTES));
+        tokio::select! {
+            _ = clock.sleep_until(wake_at) => {}
+            _ = shutdown.changed() => return,
+        }
+        let Some(next_run) = next_run else {
+            continue;
+        };
+
+        let policy = load_runtime_policy(state.config_dir());
+        if !policy.auto_enabled {
+            continue;
+        }
+        let date = next_run.with_timezone(&policy.timezone).date_naive();
+        if !policy.work_days.contains(&date.weekday()) {
+            state.log_info(
+                AUTO_GENERATION_COMMAND,
+                &format!("skipped non-work day {date}"),
+            );
+            continue;
+        }
+        match generate_blocks_at(state, date.to_string(), None, clock.now()).await {
+            Ok(generated) => state.log_info(
+                AUTO_GENERATION_COMMAND,
+                &format!("generated {} blocks for {date}", generated.len()),
+            ),
+            Err(error) => state.log_error(AUTO_GENERATION_COMMAND, &error.to_string()),
+        }
+    }
+}
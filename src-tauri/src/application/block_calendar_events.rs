@@ -1,38 +1,74 @@
 use crate::application::calendar_sync::CalendarSyncService;
 use crate::application::commands::StoredBlock;
-use crate::infrastructure::calendar_cache::InMemoryCalendarCacheRepository;
+use crate::application::policy_service::{BlockColorBy, RuntimePolicy};
+use crate::domain::models::Block;
+use crate::infrastructure::calendar_cache::CalendarCacheRepository;
 use crate::infrastructure::error::InfraError;
-use crate::infrastructure::event_mapper::encode_block_event;
-use crate::infrastructure::google_calendar_client::ReqwestGoogleCalendarClient;
-use crate::infrastructure::sync_state_repository::SqliteSyncStateRepository;
+use crate::infrastructure::event_mapper::{
+    decode_block_event, encode_block_event_with_task, popup_reminders, GoogleCalendarEvent,
+};
+use crate::infrastructure::google_calendar_client::GoogleCalendarClient;
+use crate::infrastructure::sync_state_repository::SyncStateRepository;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
 use std::sync::Arc;
 use tokio::task::JoinSet;
 
 const BLOCK_CREATION_CONCURRENCY: usize = 4;
+pub(crate) const FOCUS_MINUTES_PER_POMODORO: u32 = 25;
 
 pub(crate) fn planned_pomodoros(block_duration_minutes: u32, break_duration_minutes: u32) -> i32 {
-    let cycle_minutes = 25u32.saturating_add(break_duration_minutes.max(1));
+    let cycle_minutes = FOCUS_MINUTES_PER_POMODORO.saturating_add(break_duration_minutes.max(1));
     (block_duration_minutes / cycle_minutes).max(1) as i32
 }
 
-pub(crate) async fn create_calendar_events_for_generated_blocks(
-    sync_service: Arc<
-        CalendarSyncService<
-            ReqwestGoogleCalendarClient,
-            SqliteSyncStateRepository,
-            InMemoryCalendarCacheRepository,
-        >,
-    >,
+pub(crate) fn suggested_pomodoros(estimated_minutes: u32, break_duration_minutes: u32) -> u32 {
+    let cycle_minutes = FOCUS_MINUTES_PER_POMODORO.saturating_add(break_duration_minutes.max(1));
+    estimated_minutes.div_ceil(cycle_minutes)
+}
+
+/// Encodes a block with the reminder and color settings from `policy`.
+pub(crate) fn encode_block_event_for_policy(
+    block: &Block,
+    task_id: Option<&str>,
+    policy: &RuntimePolicy,
+) -> GoogleCalendarEvent {
+    let mut event = encode_block_event_with_task(block, task_id);
+    event.reminders = popup_reminders(policy.reminder_minutes);
+    event.color_id = match policy.color_by {
+        BlockColorBy::Type => None,
+        BlockColorBy::Load => {
+            let last = policy.load_color_ramp.len().checked_sub(1);
+            last.map(|last| {
+                let index = (block.planned_pomodoros.max(1) as usize - 1).min(last);
+                policy.load_color_ramp[index].clone()
+            })
+        }
+    };
+    event
+}
+
+/// Creates an event for each block. Event ids that were created are written back even
+/// when another creation fails, so the remaining blocks can be retried later.
+pub(crate) async fn create_calendar_events_for_generated_blocks<C, S, R>(
+    sync_service: Arc<CalendarSyncService<C, S, R>>,
     access_token: &str,
     calendar_id: &str,
+    policy: &RuntimePolicy,
     generated: &mut [StoredBlock],
-) -> Result<(), InfraError> {
+) -> Result<(), InfraError>
+where
+    C: GoogleCalendarClient + 'static,
+    S: SyncStateRepository + 'static,
+    R: CalendarCacheRepository + 'static,
+{
     if generated.is_empty() {
         return Ok(());
     }
 
     let mut create_tasks: JoinSet<Result<(usize, String), InfraError>> = JoinSet::new();
     let mut created_event_ids = vec![None; generated.len()];
+    let mut first_error = None;
     let access_token = access_token.to_string();
     let calendar_id = calendar_id.to_string();
 
@@ -40,7 +76,7 @@ pub(crate) async fn create_calendar_events_for_generated_blocks(
         let sync_service = Arc::clone(&sync_service);
         let access_token = access_token.clone();
         let calendar_id = calendar_id.clone();
-        let event = encode_block_event(&stored.block);
+        let event = encode_block_event_for_policy(&stored.block, None, policy);
 
         create_tasks.spawn(async move {
             let event_id = sync_service
@@ -50,12 +86,15 @@ pub(crate) async fn create_calendar_events_for_generated_blocks(
         });
 
         if create_tasks.len() >= BLOCK_CREATION_CONCURRENCY {
-            collect_created_event_id(&mut create_tasks, &mut created_event_ids).await?;
+            let collected =
+                collect_created_event_id(&mut create_tasks, &mut created_event_ids).await;
+            first_error = first_error.or(collected.err());
         }
     }
 
     while !create_tasks.is_empty() {
-        collect_created_event_id(&mut create_tasks, &mut created_event_ids).await?;
+        let collected = collect_created_event_id(&mut create_tasks, &mut created_event_ids).await;
+        first_error = first_error.or(collected.err());
     }
 
     for (index, event_id) in created_event_ids.into_iter().enumerate() {
@@ -64,7 +103,10 @@ pub(crate) async fn create_calendar_events_for_generated_blocks(
         }
     }
 
-    Ok(())
+    match first_error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
 }
 
 async fn collect_created_event_id(
@@ -82,3 +124,489 @@ async fn collect_created_event_id(
     }
     Ok(())
 }
+
+/// Deletes block events in the window whose block the user deleted. An event is kept when
+/// its instance is not known to be deleted or a local block still uses it.
+pub(crate) async fn delete_orphaned_block_events<C, S, R>(
+    sync_service: &CalendarSyncService<C, S, R>,
+    access_token: &str,
+    calendar_id: &str,
+    window: (DateTime<Utc>, DateTime<Utc>),
+    local_instances: &HashSet<String>,
+    deleted_instances: &HashSet<String>,
+) -> Result<usize, InfraError>
+where
+    C: GoogleCalendarClient,
+    S: SyncStateRepository,
+    R: CalendarCacheRepository,
+{
+    let events = sync_service
+        .fetch_events(access_token, calendar_id, window.0, window.1, None)
+        .await?;
+    let mut deleted = 0;
+    for event in events {
+        let is_cancelled = event
+            .status
+            .as_deref()
+            .is_some_and(|status| status.eq_ignore_ascii_case("cancelled"));
+        let Some(event_id) = event.id.as_deref().filter(|_| !is_cancelled) else {
+            continue;
+        };
+        let Some(instance) = decode_block_event(&event).and_then(|decoded| decoded.instance) else {
+            continue;
+        };
+        if !deleted_instances.contains(&instance) || local_instances.contains(&instance) {
+            continue;
+        }
+        sync_service
+            .delete_event(access_token, calendar_id, event_id)
+            .await?;
+        deleted += 1;
+    }
+    Ok(deleted)
+}
+
+/// Deletes the given block events one by one, stopping at the first failure. Returns how
+/// many leading events were deleted, with the failure that stopped it if any.
+pub(crate) async fn delete_block_events<C, S, R>(
+    sync_service: &CalendarSyncService<C, S, R>,
+    access_token: &str,
+    calendar_id: &str,
+    event_ids: &[String],
+) -> (usize, Result<(), InfraError>)
+where
+    C: GoogleCalendarClient,
+    S: SyncStateRepository,
+    R: CalendarCacheRepository,
+{
+    for (deleted, event_id) in event_ids.iter().enumerate() {
+        if let Err(error) = sync_service
+            .delete_event(access_token, calendar_id, event_id)
+            .await
+        {
+            return (deleted, Err(error));
+        }
+    }
+    (event_ids.len(), Ok(()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::block_operations::{
+        cleanup_orphaned_events_with, sync_pending_block_events_with,
+    };
+    use crate::application::calendar_runtime::save_suppression;
+    use crate::application::commands::{lock_runtime, DEFAULT_ACCOUNT_ID};
+    use crate::application::test_support::workspace::TempWorkspace;
+    use crate::domain::models::{AutoDriveMode, BlockContents, Firmness};
+    use crate::infrastructure::calendar_cache::InMemoryCalendarCacheRepository;
+    use crate::infrastructure::google_calendar_client::{
+        GoogleCalendarSummary, ListEventsRequest, ListEventsResponse,
+    };
+    use crate::infrastructure::sync_state_repository::InMemorySyncStateRepository;
+    use async_trait::async_trait;
+    use chrono::{TimeZone, Utc};
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    /// Fails the first creation attempt for each block id in `failing_block_ids`,
+    /// lists `events` and records created block ids and deleted event ids.
+    #[derive(Debug, Default)]
+    struct FlakyCalendarClient {
+        failing_block_ids: Mutex<HashSet<String>>,
+        events: Vec<GoogleCalendarEvent>,
+        created_block_ids: Mutex<Vec<String>>,
+        deleted_event_ids: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl GoogleCalendarClient for FlakyCalendarClient {
+        async fn list_calendars(
+            &self,
+            _access_token: &str,
+        ) -> Result<Vec<GoogleCalendarSummary>, InfraError> {
+            Ok(Vec::new())
+        }
+
+        async fn create_calendar(
+            &self,
+            _access_token: &str,
+            _summary: &str,
+            _time_zone: Option<&str>,
+        ) -> Result<GoogleCalendarSummary, InfraError> {
+            Err(InfraError::OAuth("not implemented in fake".to_string()))
+        }
+
+        async fn get_calendar_timezone(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+        ) -> Result<Option<String>, InfraError> {
+            Ok(None)
+        }
+
+        async fn list_events(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+            _request: ListEventsRequest,
+        ) -> Result<ListEventsResponse, InfraError> {
+            Ok(ListEventsResponse {
+                events: self.events.clone(),
+                next_sync_token: None,
+                next_page_token: None,
+            })
+        }
+
+        async fn get_event(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+            _event_id: &str,
+        ) -> Result<Option<GoogleCalendarEvent>, InfraError> {
+            Ok(None)
+        }
+
+        async fn create_event(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+            event: &GoogleCalendarEvent,
+        ) -> Result<String, InfraError> {
+            let block_id = event
+                .extended_properties
+                .as_ref()
+                .and_then(|properties| properties.private.get("bs_block_id"))
+                .cloned()
+                .unwrap_or_default();
+            if self
+                .failing_block_ids
+                .lock()
+                .expect("failing block ids lock")
+                .remove(&block_id)
+            {
+                return Err(InfraError::OAuth("token expired".to_string()));
+            }
+            self.created_block_ids
+                .lock()
+                .expect("created block ids lock")
+                .push(block_id.clone());
+            Ok(format!("evt-{block_id}"))
+        }
+
+        async fn update_event(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+            _event_id: &str,
+            _event: &GoogleCalendarEvent,
+        ) -> Result<(), InfraError> {
+            Ok(())
+        }
+
+        async fn delete_event(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+            event_id: &str,
+        ) -> Result<(), InfraError> {
+            self.deleted_event_ids
+                .lock()
+                .expect("deleted event ids lock")
+                .push(event_id.to_string());
+            Ok(())
+        }
+    }
+
+    fn block_with_pomodoros(planned_pomodoros: i32) -> Block {
+        Block {
+            id: format!("blk-{planned_pomodoros}"),
+            instance: "tpl:focus:2026-02-16".to_string(),
+            date: "2026-02-16".to_string(),
+            start_at: Utc.with_ymd_and_hms(2026, 2, 16, 9, 0, 0).single().expect("valid start"),
+            end_at: Utc.with_ymd_and_hms(2026, 2, 16, 10, 0, 0).single().expect("valid end"),
+            firmness: Firmness::Draft,
+            planned_pomodoros,
+            source: "template".to_string(),
+            source_id: None,
+            project: None,
+            title: None,
+            recipe_id: "rcp-default".to_string(),
+            auto_drive_mode: AutoDriveMode::Manual,
+            contents: BlockContents::default(),
+        }
+    }
+
+    #[test]
+    fn load_coloring_shades_heavier_blocks_more_intensely() {
+        let policy = RuntimePolicy {
+            color_by: BlockColorBy::Load,
+            ..RuntimePolicy::default()
+        };
+        let light = encode_block_event_for_policy(&block_with_pomodoros(1), None, &policy);
+        let heavy = encode_block_event_for_policy(&block_with_pomodoros(4), None, &policy);
+
+        assert_eq!(light.color_id.as_deref(), Some("2"));
+        assert_eq!(heavy.color_id.as_deref(), Some("11"));
+
+        let by_type = encode_block_event_for_policy(
+            &block_with_pomodoros(4),
+            None,
+            &RuntimePolicy::default(),
+        );
+        assert_eq!(by_type.color_id, None);
+    }
+
+    #[tokio::test]
+    async fn failed_creations_leave_blocks_pending_for_a_follow_up_sync() {
+        let client = Arc::new(FlakyCalendarClient {
+            failing_block_ids: Mutex::new(HashSet::from(["blk-2".to_string()])),
+            ..FlakyCalendarClient::default()
+        });
+        let sync_service = Arc::new(CalendarSyncService::new(
+            client,
+            Arc::new(InMemorySyncStateRepository::default()),
+            Arc::new(InMemoryCalendarCacheRepository::default()),
+        ));
+        let policy = RuntimePolicy::default();
+        let mut generated = (1..=3)
+            .map(|planned_pomodoros| StoredBlock {
+                block: block_with_pomodoros(planned_pomodoros),
+                calendar_event_id: None,
+                calendar_account_id: None,
+            })
+            .collect::<Vec<_>>();
+
+        let result = create_calendar_events_for_generated_blocks(
+            Arc::clone(&sync_service),
+            "access-token",
+            "blocks-calendar",
+            &policy,
+            &mut generated,
+        )
+        .await;
+        assert!(result.is_err());
+        let event_ids = generated
+            .iter()
+            .map(|stored| stored.calendar_event_id.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(event_ids, vec![Some("evt-blk-1"), None, Some("evt-blk-3")]);
+
+        let mut pending = generated
+            .iter()
+            .filter(|stored| stored.calendar_event_id.is_none())
+            .cloned()
+            .collect::<Vec<_>>();
+        create_calendar_events_for_generated_blocks(
+            sync_service,
+            "access-token",
+            "blocks-calendar",
+            &policy,
+            &mut pending,
+        )
+        .await
+        .expect("follow-up sync");
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].calendar_event_id.as_deref(), Some("evt-blk-2"));
+    }
+
+    #[tokio::test]
+    async fn pending_sync_only_creates_events_for_blocks_without_one() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        {
+            let mut runtime = lock_runtime(&state).expect("runtime lock");
+            for planned_pomodoros in 1..=3 {
+                let block = block_with_pomodoros(planned_pomodoros);
+                let calendar_event_id =
+                    (planned_pomodoros == 1).then(|| format!("evt-{}", block.id));
+                runtime.blocks.insert(
+                    block.id.clone(),
+                    StoredBlock {
+                        block,
+                        calendar_event_id,
+                        calendar_account_id: None,
+                    },
+                );
+            }
+        }
+        let client = Arc::new(FlakyCalendarClient {
+            failing_block_ids: Mutex::new(HashSet::from(["blk-2".to_string()])),
+            ..FlakyCalendarClient::default()
+        });
+        let sync_service = Arc::new(CalendarSyncService::new(
+            Arc::clone(&client),
+            Arc::new(InMemorySyncStateRepository::default()),
+            Arc::new(InMemoryCalendarCacheRepository::default()),
+        ));
+
+        let partial = sync_pending_block_events_with(
+            &state,
+            DEFAULT_ACCOUNT_ID,
+            Arc::clone(&sync_service),
+            "access-token",
+            "blocks-calendar",
+        )
+        .await;
+        assert!(partial.is_err());
+        let retried = sync_pending_block_events_with(
+            &state,
+            DEFAULT_ACCOUNT_ID,
+            sync_service,
+            "access-token",
+            "blocks-calendar",
+        )
+        .await
+        .expect("follow-up sync");
+
+        assert_eq!(
+            retried.into_iter().map(|block| block.id).collect::<Vec<_>>(),
+            vec!["blk-2".to_string()]
+        );
+        assert_eq!(
+            *client.created_block_ids.lock().expect("created block ids lock"),
+            vec!["blk-3".to_string(), "blk-2".to_string()]
+        );
+        let runtime = lock_runtime(&state).expect("runtime lock");
+        for block_id in ["blk-1", "blk-2", "blk-3"] {
+            assert_eq!(
+                runtime.blocks[block_id].calendar_event_id,
+                Some(format!("evt-{block_id}"))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn orphaned_block_events_are_deleted() {
+        let mut kept = block_with_pomodoros(1);
+        kept.instance = "tpl:focus:2026-02-16".to_string();
+        let mut orphaned = block_with_pomodoros(2);
+        orphaned.instance = "tpl:focus:2026-02-17".to_string();
+        let events = [("evt-kept", &kept), ("evt-orphan", &orphaned)]
+            .into_iter()
+            .map(|(event_id, block)| {
+                let mut event = encode_block_event_with_task(block, None);
+                event.id = Some(event_id.to_string());
+                event
+            })
+            .collect();
+        let client = Arc::new(FlakyCalendarClient {
+            events,
+            ..FlakyCalendarClient::default()
+        });
+        let sync_service = CalendarSyncService::new(
+            Arc::clone(&client),
+            Arc::new(InMemorySyncStateRepository::default()),
+            Arc::new(InMemoryCalendarCacheRepository::default()),
+        );
+
+        let removed = delete_orphaned_block_events(
+            &sync_service,
+            "access-token",
+            "blocks-calendar",
+            (kept.start_at, orphaned.end_at),
+            &HashSet::from([kept.instance.clone()]),
+            &HashSet::from([orphaned.instance.clone()]),
+        )
+        .await
+        .expect("cleanup orphaned events");
+
+        assert_eq!(removed, 1);
+        assert_eq!(
+            *client.deleted_event_ids.lock().expect("deleted event ids lock"),
+            vec!["evt-orphan".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn orphan_cleanup_after_a_restart_only_removes_user_deleted_blocks() {
+        let workspace = TempWorkspace::new();
+        let mut live = block_with_pomodoros(1);
+        live.instance = "tpl:focus:2026-02-16".to_string();
+        let mut deleted = block_with_pomodoros(2);
+        deleted.instance = "tpl:focus:2026-02-17".to_string();
+        let events = [("evt-live", &live), ("evt-deleted", &deleted)]
+            .into_iter()
+            .map(|(event_id, block)| {
+                let mut event = encode_block_event_with_task(block, None);
+                event.id = Some(event_id.to_string());
+                event
+            })
+            .collect::<Vec<_>>();
+        let client = Arc::new(FlakyCalendarClient {
+            events,
+            ..FlakyCalendarClient::default()
+        });
+        let sync_service = CalendarSyncService::new(
+            Arc::clone(&client),
+            Arc::new(InMemorySyncStateRepository::default()),
+            Arc::new(InMemoryCalendarCacheRepository::default()),
+        );
+
+        // A fresh state over the workspace has no blocks in memory yet.
+        let restarted = workspace.app_state();
+        assert!(lock_runtime(&restarted).expect("runtime lock").blocks.is_empty());
+        let removed = cleanup_orphaned_events_with(
+            &restarted,
+            DEFAULT_ACCOUNT_ID,
+            &sync_service,
+            "access-token",
+            "blocks-calendar",
+            live.start_at,
+        )
+        .await
+        .expect("cleanup without deletions");
+        assert_eq!(removed, 0);
+        assert!(client
+            .deleted_event_ids
+            .lock()
+            .expect("deleted event ids lock")
+            .is_empty());
+
+        save_suppression(
+            restarted.database_path(),
+            &deleted.instance,
+            Some("user_deleted"),
+            None,
+        )
+        .expect("record deleted block");
+        let removed = cleanup_orphaned_events_with(
+            &workspace.app_state(),
+            DEFAULT_ACCOUNT_ID,
+            &sync_service,
+            "access-token",
+            "blocks-calendar",
+            live.start_at,
+        )
+        .await
+        .expect("cleanup deleted block event");
+        assert_eq!(removed, 1);
+        assert_eq!(
+            *client.deleted_event_ids.lock().expect("deleted event ids lock"),
+            vec!["evt-deleted".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn cleared_block_events_are_deleted_from_the_calendar() {
+        let client = Arc::new(FlakyCalendarClient::default());
+        let sync_service = CalendarSyncService::new(
+            Arc::clone(&client),
+            Arc::new(InMemorySyncStateRepository::default()),
+            Arc::new(InMemoryCalendarCacheRepository::default()),
+        );
+        let event_ids = vec!["evt-1".to_string(), "evt-2".to_string()];
+
+        let (deleted, result) =
+            delete_block_events(&sync_service, "access-token", "blocks-calendar", &event_ids)
+                .await;
+
+        assert!(result.is_ok());
+        assert_eq!(deleted, 2);
+        assert_eq!(
+            *client.deleted_event_ids.lock().expect("deleted event ids lock"),
+            event_ids
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use crate::application::calendar_runtime::is_non_blocking_event;
+use crate::application::commands::{lock_runtime, normalize_account_id, AppState};
+use crate::application::policy_service::load_runtime_policy;
+use crate::application::time_slots::{event_to_interval, parse_date_input};
+use crate::domain::models::Block;
+use crate::infrastructure::error::InfraError;
+use crate::infrastructure::event_mapper::decode_block_event;
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+use std::fs;
+use std::path::Path;
+
+const ICS_LINE_LIMIT: usize = 75;
+
+pub fn export_blocks_ics(
+    state: &AppState,
+    start_date: String,
+    end_date: String,
+    path: String,
+) -> Result<usize, InfraError> {
+    let start_date = parse_date_input(&start_date, "start_date")?;
+    let end_date = parse_date_input(&end_date, "end_date")?;
+    if end_date < start_date {
+        return Err(InfraError::InvalidConfig(
+            "end_date must not be before start_date".to_string(),
+        ));
+    }
+    let path = path.trim();
+    if path.is_empty() {
+        return Err(InfraError::InvalidConfig(
+            "path must not be empty".to_string(),
+        ));
+    }
+
+    let entries = {
+        let runtime = lock_runtime(state)?;
+        let mut entries = runtime
+            .blocks
+            .values()
+            .filter(|stored| {
+                NaiveDate::parse_from_str(&stored.block.date, "%Y-%m-%d")
+                    .map(|date| date >= start_date && date <= end_date)
+                    .unwrap_or(false)
+            })
+            .map(|stored| {
+                let task_title = runtime
+                    .task_assignments_by_block
+                    .get(&stored.block.id)
+                    .and_then(|task_id| runtime.tasks.get(task_id))
+                    .map(|task| task.title.clone());
+                (stored.block.clone(), task_title)
+            })
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|(block, _)| block.start_at);
+        entries
+    };
+
+    let document = render_calendar(&entries, Utc::now());
+    fs::write(Path::new(path), document)?;
+    state.log_info(
+        "export_blocks_ics",
+        &format!(
+            "exported block_count={} start_date={} end_date={}",
+            entries.len(),
+            start_date,
+            end_date
+        ),
+    );
+    Ok(entries.len())
+}
+
+/// Markdown agenda of the day's blocks with the account's synced meetings interleaved,
+/// times shown in the policy timezone.
+pub fn export_day_markdown(
+    state: &AppState,
+    date: String,
+    account_id: Option<String>,
+) -> Result<String, InfraError> {
+    let date = parse_date_input(&date, "date")?;
+    let default_account_id = normalize_account_id(state.config_dir(), None);
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    let policy = load_runtime_policy(state.config_dir());
+    let timezone = policy.timezone;
+
+    let mut entries = {
+        let runtime = lock_runtime(state)?;
+        let date_key = date.to_string();
+        let blocks = runtime
+            .blocks
+            .values()
+            .filter(|stored| {
+                stored.calendar_account_id.as_deref().unwrap_or(&default_account_id) == account_id
+            })
+            .filter(|stored| stored.block.date == date_key)
+            .map(|stored| {
+                let block = &stored.block;
+                let task_title = runtime
+                    .task_assignments_by_block
+                    .get(&block.id)
+                    .and_then(|task_id| runtime.tasks.get(task_id))
+                    .map(|task| task.title.as_str());
+                let block_type = block
+                    .title
+                    .as_deref()
+                    .or(block.source_id.as_deref())
+                    .unwrap_or(&block.source);
+                let mut line = format!(
+                    "{} {} block, {} pomodoros",
+                    format_local_range(block.start_at, block.end_at, timezone),
+                    block_type,
+                    block.planned_pomodoros
+                );
+                if let Some(task_title) = task_title {
+                    line.push_str(&format!(", task: {task_title}"));
+                }
+                (block.start_at, line)
+            })
+            .collect::<Vec<_>>();
+        let meetings = runtime
+            .synced_events_by_account
+            .get(&account_id)
+            .into_iter()
+            .flatten()
+            .filter(|event| decode_block_event(event).is_none())
+            .filter(|event| !is_non_blocking_event(event, &policy))
+            .filter_map(|event| event_to_interval(event).map(|interval| (event, interval)))
+            .filter(|(_, interval)| interval.start.with_timezone(&timezone).date_naive() == date)
+            .map(|(event, interval)| {
+                let summary = event
+                    .summary
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|summary| !summary.is_empty())
+                    .unwrap_or("(no title)");
+                (
+                    interval.start,
+                    format!(
+                        "{} meeting: {summary}",
+                        format_local_range(interval.start, interval.end, timezone)
+                    ),
+                )
+            })
+            .collect::<Vec<_>>();
+        blocks.into_iter().chain(meetings).collect::<Vec<_>>()
+    };
+    entries.sort_by_key(|(start_at, _)| *start_at);
+
+    let mut document = format!("# Agenda {date}\n\n");
+    for (_, line) in &entries {
+        document.push_str(&format!("- {line}\n"));
+    }
+    Ok(document)
+}
+
+fn format_local_range(start_at: DateTime<Utc>, end_at: DateTime<Utc>, timezone: Tz) -> String {
+    format!(
+        "{}-{}",
+        start_at.with_timezone(&timezone).format("%H:%M"),
+        end_at.with_timezone(&timezone).format("%H:%M")
+    )
+}
+
+fn render_calendar(entries: &[(Block, Option<String>)], stamped_at: DateTime<Utc>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//PomoBlock//Blocks Export//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+    ];
+    for (block, task_title) in entries {
+        let summary = task_title
+            .as_deref()
+            .map(str::trim)
+            .filter(|title| !title.is_empty())
+            .map(ToOwned::to_owned)
+            .unwrap_or_else(|| format!("PomoBlock {} block", block.source));
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}@pomoblock", escape_text(&block.id)));
+        lines.push(format!("DTSTAMP:{}", format_utc(stamped_at)));
+        lines.push(format!("DTSTART:{}", format_utc(block.start_at)));
+        lines.push(format!("DTEND:{}", format_utc(block.end_at)));
+        lines.push(format!("SUMMARY:{}", escape_text(&summary)));
+        lines.push(format!(
+            "DESCRIPTION:{}",
+            escape_text(&format!(
+                "instance: {}\nplanned pomodoros: {}",
+                block.instance, block.planned_pomodoros
+            ))
+        ));
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .map(|line| format!("{line}\r\n"))
+        .collect()
+}
+
+fn format_utc(value: DateTime<Utc>) -> String {
+    value.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for character in value.chars() {
+        match character {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            other => escaped.push(other),
+        }
+    }
+    escaped
+}
+
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut line_length = 0usize;
+    for character in line.chars() {
+        let width = character.len_utf8();
+        if line_length + width > ICS_LINE_LIMIT {
+            folded.push_str("\r\n ");
+            line_length = 1;
+        }
+        folded.push(character);
+        line_length += width;
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_text_escapes_reserved_characters() {
+        assert_eq!(escape_text("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+    }
+
+    #[test]
+    fn fold_line_keeps_each_physical_line_within_limit() {
+        let line = format!("SUMMARY:{}", "あ".repeat(40));
+        let folded = fold_line(&line);
+
+        assert!(folded.split("\r\n").all(|part| part.len() <= ICS_LINE_LIMIT));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+}
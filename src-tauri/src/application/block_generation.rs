@@ -1,38 +1,54 @@
 use crate::application::block_calendar_events::{
-    create_calendar_events_for_generated_blocks, planned_pomodoros,
+    create_calendar_events_for_generated_blocks, planned_pomodoros, FOCUS_MINUTES_PER_POMODORO,
 };
+use crate::application::block_operations;
 use crate::application::calendar_services::{
     build_reqwest_calendar_sync_service, resolve_cached_blocks_calendar_id,
 };
 use crate::application::calendar_runtime::{
-    clear_user_deleted_suppressions_for_date, load_suppressions,
+    clear_user_deleted_suppressions_for_date, is_non_blocking_event, load_suppressions,
 };
 use crate::application::commands::{
-    block_runtime_snapshot, normalize_account_id, persist_generated_blocks, try_access_token,
-    AppState, StoredBlock,
+    block_runtime_snapshot, lock_runtime, normalize_account_id, persist_generated_blocks,
+    try_access_token, AppState, MetricsCounters, StoredBlock,
 };
 use crate::application::configured_block_plans;
+use crate::application::configured_holidays::load_holidays;
 use crate::application::configured_recipes;
 use crate::application::id_factory::next_id;
-use crate::application::policy_service::load_runtime_policy;
+use crate::application::policy_service::{load_runtime_policy, QuietHours};
+use crate::application::recurring_tasks::materialize_recurring_tasks;
 use crate::application::time_slots::{
     clip_interval, event_to_interval, free_slots, intervals_overlap, local_datetime_to_utc,
-    merge_intervals, Interval,
+    merge_intervals, parse_date_input, round_up_to_minutes, Interval,
 };
 use crate::domain::models::{Block, BlockContents, Firmness};
 use crate::infrastructure::error::InfraError;
-use chrono::{Datelike, Duration, NaiveDate, Utc};
-use std::collections::HashSet;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc};
+use std::collections::{BTreeSet, HashSet};
 use std::time::Instant;
 
 const BLOCK_GENERATION_TARGET_MS: u128 = 30_000;
+/// Source of the short break blocks placed between auto-filled focus blocks;
+/// they carry no pomodoros and never host a session.
+pub(crate) const BREAK_BLOCK_SOURCE: &str = "break";
 
 pub async fn generate_blocks(
     state: &AppState,
     date: String,
     account_id: Option<String>,
 ) -> Result<Vec<Block>, InfraError> {
-    generate_blocks_with_limit(state, date, account_id, None, false).await
+    generate_blocks_with_limit(state, date, account_id, None, false, PlanScope::All, Utc::now())
+        .await
+}
+
+pub(crate) async fn generate_blocks_at(
+    state: &AppState,
+    date: String,
+    account_id: Option<String>,
+    now: DateTime<Utc>,
+) -> Result<Vec<Block>, InfraError> {
+    generate_blocks_with_limit(state, date, account_id, None, false, PlanScope::All, now).await
 }
 
 pub async fn generate_one_block(
@@ -40,7 +56,32 @@ pub async fn generate_one_block(
     date: String,
     account_id: Option<String>,
 ) -> Result<Vec<Block>, InfraError> {
-    generate_blocks_with_limit(state, date, account_id, Some(1), true).await
+    generate_blocks_with_limit(state, date, account_id, Some(1), true, PlanScope::All, Utc::now())
+        .await
+}
+
+pub async fn generate_from_source(
+    state: &AppState,
+    date: String,
+    source_id: String,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, InfraError> {
+    let source_id = source_id.trim();
+    if source_id.is_empty() {
+        return Err(InfraError::InvalidConfig(
+            "source_id must not be empty".to_string(),
+        ));
+    }
+    generate_blocks_with_limit(
+        state,
+        date,
+        account_id,
+        None,
+        false,
+        PlanScope::Source(source_id),
+        Utc::now(),
+    )
+    .await
 }
 
 pub async fn generate_today_blocks(
@@ -48,24 +89,178 @@ pub async fn generate_today_blocks(
     account_id: Option<String>,
 ) -> Result<Vec<Block>, InfraError> {
     let policy = load_runtime_policy(state.config_dir());
+    let today = Utc::now().with_timezone(&policy.timezone).date_naive().to_string();
+    if policy.catch_up_on_app_start {
+        materialize_recurring_tasks(state, today.clone())?;
+    }
     if !policy.auto_enabled {
         return Ok(Vec::new());
     }
-    let today = Utc::now().with_timezone(&policy.timezone).date_naive().to_string();
     generate_blocks(state, today, account_id).await
 }
 
+pub async fn run_startup_catch_up(
+    state: &AppState,
+    last_run_date: Option<String>,
+    account_id: Option<String>,
+    now: DateTime<Utc>,
+) -> Result<Vec<Block>, InfraError> {
+    let policy = load_runtime_policy(state.config_dir());
+    if !policy.catch_up_on_app_start || !policy.auto_enabled {
+        return Ok(Vec::new());
+    }
+    let today = now.with_timezone(&policy.timezone).date_naive();
+    let first_missed = match last_run_date
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        Some(value) => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map_err(|error| {
+                InfraError::InvalidConfig(format!("last_run_date must be YYYY-MM-DD: {error}"))
+            })?
+            .succ_opt()
+            .unwrap_or(today),
+        None => today,
+    };
+
+    let mut missed_work_days = first_missed
+        .iter_days()
+        .take_while(|date| *date <= today)
+        .filter(|date| policy.work_days.contains(&date.weekday()))
+        .collect::<Vec<_>>();
+    let skipped_count = missed_work_days
+        .len()
+        .saturating_sub(policy.catch_up_max_days as usize);
+    if skipped_count > 0 {
+        state.log_warn(
+            "run_startup_catch_up",
+            &format!(
+                "skipped {} work days older than catch_up_max_days={} from {} to {}",
+                skipped_count,
+                policy.catch_up_max_days,
+                missed_work_days[0],
+                missed_work_days[skipped_count - 1]
+            ),
+        );
+        missed_work_days.drain(..skipped_count);
+    }
+
+    let mut generated = Vec::new();
+    for date in &missed_work_days {
+        materialize_recurring_tasks(state, date.to_string())?;
+        generated.extend(
+            generate_blocks_with_limit(
+                state,
+                date.to_string(),
+                account_id.clone(),
+                None,
+                false,
+                PlanScope::All,
+                now,
+            )
+            .await?,
+        );
+    }
+    state.log_info(
+        "run_startup_catch_up",
+        &format!(
+            "caught_up_days={} generated_count={} skipped_days={}",
+            missed_work_days.len(),
+            generated.len(),
+            skipped_count
+        ),
+    );
+    Ok(generated)
+}
+
+/// Drops every block on the date, forgets the user's deletions there and generates the day afresh.
+pub async fn reset_day(
+    state: &AppState,
+    date: String,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, InfraError> {
+    let parsed_date = parse_date_input(&date, "date")?;
+    block_operations::clear_day(state, date.clone(), account_id.clone(), false).await?;
+    clear_user_deleted_suppressions_for_date(state.database_path(), parsed_date)?;
+    generate_blocks(state, date, account_id).await
+}
+
+pub async fn regenerate_auto_blocks(
+    state: &AppState,
+    date: String,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, InfraError> {
+    let parsed_date = parse_date_input(&date, "date")?;
+    let date_key = parsed_date.to_string();
+    let auto_instance_prefix = format!("rtn:auto:{}:", parsed_date);
+    let break_instance_prefix = format!("rtn:break:{}:", parsed_date);
+    let default_account_id = normalize_account_id(state.config_dir(), None);
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+
+    let mut candidates = lock_runtime(state)?
+        .blocks
+        .values()
+        .filter(|stored| stored.block.date == date_key)
+        .filter(|stored| {
+            stored.block.instance.starts_with(auto_instance_prefix.as_str())
+                || stored.block.instance.starts_with(break_instance_prefix.as_str())
+        })
+        .filter(|stored| {
+            stored.calendar_account_id.as_deref().unwrap_or(&default_account_id) == account_id
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|stored| stored.block.start_at);
+    let (removed, deletion) =
+        block_operations::remove_blocks_after_deleting_events(state, &account_id, &candidates)
+            .await?;
+
+    let regenerated = generate_blocks_with_limit(
+        state,
+        date,
+        Some(account_id),
+        None,
+        false,
+        PlanScope::Skip,
+        Utc::now(),
+    )
+    .await?;
+    state.log_info(
+        "regenerate_auto_blocks",
+        &format!(
+            "removed_count={} kept_count={} regenerated_count={} date={}",
+            removed.len(),
+            candidates.len() - removed.len(),
+            regenerated.len(),
+            parsed_date
+        ),
+    );
+    deletion?;
+    Ok(regenerated)
+}
+
+/// Which configured template/routine plans a generation run places.
+#[derive(Debug, Clone, Copy)]
+enum PlanScope<'a> {
+    Skip,
+    All,
+    /// Only plans from this template/routine id, without auto-fill.
+    Source(&'a str),
+}
+
 async fn generate_blocks_with_limit(
     state: &AppState,
     date: String,
     account_id: Option<String>,
     generation_limit: Option<usize>,
     allow_overlap: bool,
+    plan_scope: PlanScope<'_>,
+    now: DateTime<Utc>,
 ) -> Result<Vec<Block>, InfraError> {
     let started_at = Instant::now();
-    let account_id = normalize_account_id(account_id);
-    let date = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
-        .map_err(|error| InfraError::InvalidConfig(format!("date must be YYYY-MM-DD: {error}")))?;
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    let date = parse_date_input(&date, "date")?;
     let policy = load_runtime_policy(state.config_dir());
     let max_generated_blocks = generation_limit.unwrap_or(usize::MAX);
     if max_generated_blocks == 0 {
@@ -74,11 +269,41 @@ async fn generate_blocks_with_limit(
     if !policy.work_days.contains(&date.weekday()) || policy.work_end <= policy.work_start {
         return Ok(Vec::new());
     }
+    let holidays = match load_holidays(state.config_dir()) {
+        Ok(holidays) => {
+            for entry in &holidays.invalid_entries {
+                state.log_warn("generate_blocks", &format!("ignored invalid holiday {entry}"));
+            }
+            holidays.dates
+        }
+        Err(error) => {
+            state.log_warn("generate_blocks", &format!("ignored holidays: {error}"));
+            BTreeSet::new()
+        }
+    };
+    if holidays.contains(&date) {
+        return Ok(Vec::new());
+    }
 
     let window_start = local_datetime_to_utc(date, policy.work_start, policy.timezone)?;
     let window_end = local_datetime_to_utc(date, policy.work_end, policy.timezone)?;
     let block_duration = Duration::minutes(policy.block_duration_minutes as i64);
+    let min_auto_block_duration = Duration::minutes(policy.min_auto_block_minutes as i64);
     let gap = Duration::minutes(policy.min_block_gap_minutes as i64);
+    // On the current day nothing is placed before now; auto-fill resumes on the
+    // next block boundary counted from the start of the work window.
+    let skip_before = (policy.skip_past
+        && now.with_timezone(&policy.timezone).date_naive() == date)
+        .then_some(now);
+    let earliest_start = match skip_before {
+        Some(now) => {
+            let elapsed = (now - window_start).num_seconds().max(0);
+            let block_seconds = block_duration.num_seconds().max(1);
+            let boundaries = (elapsed + block_seconds - 1) / block_seconds;
+            window_start + Duration::seconds(boundaries * block_seconds)
+        }
+        None => window_start,
+    };
 
     let (existing_blocks, synced_events_by_account, mut blocks_calendar_ids) =
         block_runtime_snapshot(state, date)?;
@@ -94,10 +319,26 @@ async fn generate_blocks_with_limit(
         HashSet::new()
     };
 
-    let mut busy_intervals = Vec::new();
+    let quiet_intervals = match policy.quiet_hours {
+        Some(quiet_hours) => quiet_intervals_for_date(date, quiet_hours, policy.timezone)?
+            .into_iter()
+            .filter_map(|interval| clip_interval(interval, window_start, window_end))
+            .collect::<Vec<_>>(),
+        None => Vec::new(),
+    };
+
+    let mut busy_intervals = quiet_intervals.clone();
     for events in synced_events_by_account.values() {
         for event in events {
+            if is_non_blocking_event(event, &policy) {
+                continue;
+            }
+            // Synced events are padded by the block gap so blocks never butt against meetings.
             if let Some(interval) = event_to_interval(event)
+                .map(|interval| Interval {
+                    start: interval.start - gap,
+                    end: interval.end + gap,
+                })
                 .and_then(|interval| clip_interval(interval, window_start, window_end))
             {
                 busy_intervals.push(interval);
@@ -129,9 +370,46 @@ async fn generate_blocks_with_limit(
         .collect::<HashSet<_>>();
     let mut generated = Vec::new();
     let recipes = configured_recipes::load_configured_recipes(state.config_dir());
-    let candidate_plans =
-        configured_block_plans::load_configured_block_plans(state.config_dir(), date, &policy, &recipes);
+    let mut configured_plans = match plan_scope {
+        PlanScope::Skip => configured_block_plans::ConfiguredPlans::default(),
+        PlanScope::All | PlanScope::Source(_) => configured_block_plans::load_configured_plans(
+            state.config_dir(),
+            date,
+            &policy,
+            &recipes,
+        ),
+    };
+    for issue in &configured_plans.issues {
+        state.log_warn(
+            "generate_blocks",
+            &format!(
+                "{} {}: {}",
+                issue.file,
+                issue.id.as_deref().unwrap_or("-"),
+                issue.problem
+            ),
+        );
+    }
+    for dropped in &configured_plans.dropped {
+        state.log_warn(
+            "generate_blocks",
+            &format!(
+                "plan {} overlaps {}, dropping it",
+                dropped.instance, dropped.overlapped_instance
+            ),
+        );
+    }
+    if let PlanScope::Source(source_id) = plan_scope {
+        configured_plans
+            .pinned
+            .retain(|plan| plan.source_id.as_deref() == Some(source_id));
+        configured_plans
+            .floating
+            .retain(|plan| plan.source_id.as_deref() == Some(source_id));
+    }
+    let candidate_plans = configured_plans.pinned;
     let candidate_plan_count = candidate_plans.len();
+    let mut skipped_past_plan_count = 0usize;
 
     for plan in candidate_plans {
         if generated.len() >= max_generated_blocks
@@ -141,10 +419,20 @@ async fn generate_blocks_with_limit(
         {
             continue;
         }
+        if skip_before.is_some_and(|now| plan.start_at < now) {
+            skipped_past_plan_count += 1;
+            continue;
+        }
         let interval = Interval {
             start: plan.start_at,
             end: plan.end_at,
         };
+        if quiet_intervals
+            .iter()
+            .any(|quiet| intervals_overlap(quiet, &interval))
+        {
+            continue;
+        }
         if !allow_overlap
             && occupied_intervals
                 .iter()
@@ -177,6 +465,8 @@ async fn generate_blocks_with_limit(
                     planned_pomodoros: plan.planned_pomodoros,
                     source: plan.source,
                     source_id: plan.source_id,
+                    project: plan.project,
+                    title: None,
                     recipe_id: plan.recipe_id,
                     auto_drive_mode: plan.auto_drive_mode,
                     contents: BlockContents::default(),
@@ -191,16 +481,81 @@ async fn generate_blocks_with_limit(
         }
     }
 
+    // Start-less routines take the first free slot long enough for them,
+    // ahead of auto-fill so they keep their routine instance.
+    for plan in configured_plans.floating {
+        if generated.len() >= max_generated_blocks || existing_instances.contains(&plan.instance) {
+            continue;
+        }
+        if policy.respect_suppression && suppressed_instances.contains(plan.instance.as_str()) {
+            continue;
+        }
+        let duration = Duration::minutes(plan.duration_minutes as i64);
+        let merged = merge_intervals(occupied_intervals.clone());
+        let Some(slot) = free_slots(earliest_start, window_end, &merged)
+            .into_iter()
+            .find(|slot| slot.start + duration <= slot.end)
+        else {
+            continue;
+        };
+        let interval = Interval {
+            start: slot.start,
+            end: slot.start + duration,
+        };
+        existing_instances.insert(plan.instance.clone());
+        existing_ranges.insert((
+            interval.start.timestamp_millis(),
+            interval.end.timestamp_millis(),
+        ));
+        generated.push(StoredBlock {
+            block: Block {
+                id: next_id("blk"),
+                instance: plan.instance,
+                date: date.to_string(),
+                start_at: interval.start,
+                end_at: interval.end,
+                firmness: plan.firmness,
+                planned_pomodoros: plan.planned_pomodoros,
+                source: plan.source,
+                source_id: plan.source_id,
+                project: plan.project,
+                title: None,
+                recipe_id: plan.recipe_id,
+                auto_drive_mode: plan.auto_drive_mode,
+                contents: BlockContents::default(),
+            },
+            calendar_event_id: None,
+            calendar_account_id: Some(account_id.clone()),
+        });
+        occupied_intervals.push(interval);
+    }
+
     let occupied_intervals = merge_intervals(occupied_intervals);
     let max_auto_blocks_per_day = policy.max_auto_blocks_per_day as usize;
-    let used_capacity = existing_blocks.len().saturating_add(generated.len());
+    let auto_instance_prefix = format!("rtn:auto:{}:", date);
+    // Only auto-filled blocks count toward the daily cap; template and routine
+    // blocks are scheduled explicitly and must not shrink the auto budget.
+    let used_auto_capacity = existing_blocks
+        .iter()
+        .filter(|stored| stored.block.instance.starts_with(auto_instance_prefix.as_str()))
+        .count();
     let mut remaining_auto_capacity = if allow_overlap {
         max_generated_blocks.saturating_sub(generated.len())
     } else {
-        max_auto_blocks_per_day.saturating_sub(used_capacity)
+        max_auto_blocks_per_day.saturating_sub(used_auto_capacity)
     };
     let mut remaining_generation_capacity = max_generated_blocks.saturating_sub(generated.len());
-    let auto_instance_prefix = format!("rtn:auto:{}:", date);
+    let block_focus_minutes = |planned_pomodoros: i32| {
+        (planned_pomodoros.max(0) as u32).saturating_mul(FOCUS_MINUTES_PER_POMODORO)
+    };
+    let mut remaining_focus_minutes = policy.max_daily_focus_minutes.map(|max_focus_minutes| {
+        let used_focus_minutes = existing_blocks
+            .iter()
+            .chain(generated.iter())
+            .map(|stored| block_focus_minutes(stored.block.planned_pomodoros))
+            .fold(0u32, u32::saturating_add);
+        max_focus_minutes.saturating_sub(used_focus_minutes)
+    });
     let mut instance_index: u32 = existing_instances
         .iter()
         .filter_map(|instance| instance.strip_prefix(auto_instance_prefix.as_str()))
@@ -208,25 +563,50 @@ async fn generate_blocks_with_limit(
         .max()
         .map(|max_index| max_index.saturating_add(1))
         .unwrap_or(0);
-    let auto_slots = if allow_overlap {
-        vec![Interval {
-            start: window_start,
-            end: window_end,
-        }]
+    let insert_break_blocks =
+        policy.insert_break_blocks && !allow_overlap && policy.break_duration_minutes > 0;
+    let break_block_duration = Duration::minutes(policy.break_duration_minutes as i64);
+    let break_instance_prefix = format!("rtn:break:{}:", date);
+    let mut break_index: u32 = existing_instances
+        .iter()
+        .filter_map(|instance| instance.strip_prefix(break_instance_prefix.as_str()))
+        .filter_map(|suffix| suffix.parse::<u32>().ok())
+        .max()
+        .map(|max_index| max_index.saturating_add(1))
+        .unwrap_or(0);
+    let auto_slots = if matches!(plan_scope, PlanScope::Source(_)) {
+        Vec::new()
+    } else if allow_overlap {
+        free_slots(earliest_start, window_end, &quiet_intervals)
     } else {
-        free_slots(window_start, window_end, &occupied_intervals)
+        free_slots(earliest_start, window_end, &occupied_intervals)
     };
     let mut auto_generated_count = 0usize;
-    for slot in auto_slots {
+    'slots: for slot in auto_slots {
         if remaining_auto_capacity == 0 || remaining_generation_capacity == 0 {
             break;
         }
         let mut cursor = slot.start;
-        while cursor + block_duration <= slot.end
+        while cursor + min_auto_block_duration <= slot.end
             && remaining_auto_capacity > 0
             && remaining_generation_capacity > 0
         {
-            let candidate_end = cursor + block_duration;
+            if let Some(minutes) = policy.round_start_to_minutes {
+                cursor = round_up_to_minutes(cursor, minutes, policy.timezone);
+                if cursor + min_auto_block_duration > slot.end {
+                    break;
+                }
+            }
+            let candidate_end = (cursor + block_duration).min(slot.end);
+            let candidate_pomodoros = planned_pomodoros(
+                (candidate_end - cursor).num_minutes() as u32,
+                policy.break_duration_minutes,
+            );
+            if remaining_focus_minutes
+                .is_some_and(|remaining| block_focus_minutes(candidate_pomodoros) > remaining)
+            {
+                break 'slots;
+            }
             let (recipe_id, auto_drive_mode) =
                 configured_block_plans::resolve_recipe_for_plan(None, None, &recipes);
             let instance = format!("rtn:auto:{}:{}", date, instance_index);
@@ -240,11 +620,13 @@ async fn generate_blocks_with_limit(
                 && policy.respect_suppression
                 && suppressed_instances.contains(instance.as_str());
 
+            let mut placed_focus_block = false;
             if !is_suppressed
                 && (allow_overlap
                     || (existing_instances.insert(instance.clone())
                         && existing_ranges.insert(range_key)))
             {
+                placed_focus_block = true;
                 generated.push(StoredBlock {
                     block: Block {
                         id: next_id("blk"),
@@ -253,12 +635,11 @@ async fn generate_blocks_with_limit(
                         start_at: cursor,
                         end_at: candidate_end,
                         firmness: Firmness::Draft,
-                        planned_pomodoros: planned_pomodoros(
-                            policy.block_duration_minutes,
-                            policy.break_duration_minutes,
-                        ),
+                        planned_pomodoros: candidate_pomodoros,
                         source: "routine".to_string(),
                         source_id: Some("auto".to_string()),
+                        project: None,
+                        title: None,
                         recipe_id,
                         auto_drive_mode,
                         contents: BlockContents::default(),
@@ -269,17 +650,68 @@ async fn generate_blocks_with_limit(
                 auto_generated_count = auto_generated_count.saturating_add(1);
                 remaining_auto_capacity = remaining_auto_capacity.saturating_sub(1);
                 remaining_generation_capacity = remaining_generation_capacity.saturating_sub(1);
+                if let Some(remaining) = remaining_focus_minutes.as_mut() {
+                    *remaining = remaining.saturating_sub(block_focus_minutes(candidate_pomodoros));
+                }
             }
 
             cursor = candidate_end + gap;
+
+            // A break block only goes in when another focus block still fits after it.
+            let break_end = cursor + break_block_duration;
+            if insert_break_blocks
+                && placed_focus_block
+                && break_end + min_auto_block_duration <= slot.end
+                && remaining_auto_capacity > 0
+                && remaining_generation_capacity > 0
+                && remaining_focus_minutes.is_none_or(|remaining| remaining > 0)
+            {
+                let instance = format!("{}{}", break_instance_prefix, break_index);
+                break_index = break_index.saturating_add(1);
+                let is_suppressed = policy.respect_suppression
+                    && suppressed_instances.contains(instance.as_str());
+                if !is_suppressed && existing_instances.insert(instance.clone()) {
+                    let (recipe_id, auto_drive_mode) =
+                        configured_block_plans::resolve_recipe_for_plan(None, None, &recipes);
+                    generated.push(StoredBlock {
+                        block: Block {
+                            id: next_id("blk"),
+                            instance,
+                            date: date.to_string(),
+                            start_at: cursor,
+                            end_at: break_end,
+                            firmness: Firmness::Draft,
+                            planned_pomodoros: 0,
+                            source: BREAK_BLOCK_SOURCE.to_string(),
+                            source_id: None,
+                            project: None,
+                            title: None,
+                            recipe_id,
+                            auto_drive_mode,
+                            contents: BlockContents::default(),
+                        },
+                        calendar_event_id: None,
+                        calendar_account_id: Some(account_id.clone()),
+                    });
+                }
+                cursor = break_end + gap;
+            }
         }
     }
 
     if generated.is_empty() {
         return Ok(Vec::new());
     }
+    if policy.auto_approve {
+        for stored in generated
+            .iter_mut()
+            .filter(|stored| stored.block.firmness == Firmness::Draft)
+        {
+            stored.block.firmness = Firmness::Soft;
+        }
+    }
 
-    let access_token = try_access_token(Some(account_id.clone())).await?;
+    let access_token = try_access_token(&account_id).await?;
     let calendar_id = resolve_cached_blocks_calendar_id(
         state,
         access_token.as_deref(),
@@ -287,22 +719,43 @@ async fn generate_blocks_with_limit(
         &mut blocks_calendar_ids,
     )
     .await?;
+    let mut event_creation = Ok(());
     if let (Some(token), Some(calendar_id)) = (access_token.as_deref(), calendar_id.as_deref()) {
-        let sync_service = std::sync::Arc::new(build_reqwest_calendar_sync_service(state));
-        create_calendar_events_for_generated_blocks(sync_service, token, calendar_id, &mut generated)
-            .await?;
+        let sync_service =
+            std::sync::Arc::new(build_reqwest_calendar_sync_service(state, &account_id));
+        event_creation = create_calendar_events_for_generated_blocks(
+            sync_service,
+            token,
+            calendar_id,
+            &policy,
+            &mut generated,
+        )
+        .await;
     }
 
+    // Blocks are kept even when some events failed; sync_pending_block_events retries them.
     persist_generated_blocks(state, &account_id, &blocks_calendar_ids, &generated)?;
+    if let Err(error) = event_creation {
+        let pending_count = generated
+            .iter()
+            .filter(|stored| stored.calendar_event_id.is_none())
+            .count();
+        state.log_error(
+            "generate_blocks",
+            &format!("{pending_count} blocks are pending calendar events: {error}"),
+        );
+        return Err(error);
+    }
 
     let elapsed_ms = started_at.elapsed().as_millis();
     state.log_info(
         "generate_blocks",
         &format!(
-            "generated_count={} auto_generated_count={} candidate_plan_count={} busy_interval_count={} cleared_user_deleted_suppressions={} elapsed_ms={} date={} account_id={}",
+            "generated_count={} auto_generated_count={} candidate_plan_count={} skipped_past_plan_count={} busy_interval_count={} cleared_user_deleted_suppressions={} elapsed_ms={} date={} account_id={}",
             generated.len(),
             auto_generated_count,
             candidate_plan_count,
+            skipped_past_plan_count,
             busy_interval_count,
             cleared_user_deleted_suppressions,
             elapsed_ms,
@@ -311,6 +764,7 @@ async fn generate_blocks_with_limit(
         ),
     );
     if elapsed_ms > BLOCK_GENERATION_TARGET_MS {
+        MetricsCounters::add(&state.metrics().slow_generations, 1);
         state.log_error(
             "generate_blocks",
             &format!(
@@ -322,3 +776,28 @@ async fn generate_blocks_with_limit(
 
     Ok(generated.into_iter().map(|stored| stored.block).collect())
 }
+
+fn quiet_intervals_for_date(
+    date: NaiveDate,
+    quiet_hours: QuietHours,
+    timezone: chrono_tz::Tz,
+) -> Result<Vec<Interval>, InfraError> {
+    let next_date = date.succ_opt().unwrap_or(date);
+    let local_ranges = if quiet_hours.start < quiet_hours.end {
+        vec![((date, quiet_hours.start), (date, quiet_hours.end))]
+    } else {
+        vec![
+            ((date, NaiveTime::MIN), (date, quiet_hours.end)),
+            ((date, quiet_hours.start), (next_date, NaiveTime::MIN)),
+        ]
+    };
+    let mut intervals = Vec::new();
+    for ((start_date, start_time), (end_date, end_time)) in local_ranges {
+        let start = local_datetime_to_utc(start_date, start_time, timezone)?;
+        let end = local_datetime_to_utc(end_date, end_time, timezone)?;
+        if end > start {
+            intervals.push(Interval { start, end });
+        }
+    }
+    Ok(merge_intervals(intervals))
+}
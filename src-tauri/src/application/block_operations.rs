@@ -1,20 +1,87 @@
+use crate::application::block_calendar_events::{
+    create_calendar_events_for_generated_blocks, delete_block_events, delete_orphaned_block_events,
+    encode_block_event_for_policy, FOCUS_MINUTES_PER_POMODORO,
+};
+use crate::application::block_generation::BREAK_BLOCK_SOURCE;
 use crate::application::calendar_services::{
-    build_reqwest_calendar_sync_service, ReqwestCalendarSyncService,
+    build_reqwest_calendar_sync_service, resolve_cached_blocks_calendar_id,
+    ReqwestCalendarSyncService,
+};
+use crate::application::calendar_runtime::{
+    auto_relocate_after_sync, is_non_blocking_event, load_user_deleted_instances, save_suppression,
 };
-use crate::application::calendar_runtime::{is_cancelled_event, save_suppression};
+use crate::application::calendar_sync::CalendarSyncService;
 use crate::application::commands::{
-    lock_runtime, normalize_account_id, try_access_token, AppState, DEFAULT_ACCOUNT_ID,
+    lock_runtime, normalize_account_id, try_access_token, AppState, MetricsCounters, RuntimeState,
+    StoredBlock, DEFAULT_ACCOUNT_ID,
 };
-use crate::application::policy_service::load_runtime_policy;
+use crate::application::policy_service::{
+    load_runtime_policy, BlockDeleteBehavior, RuntimePolicy,
+};
+use crate::application::task_service::TaskService;
 use crate::application::time_slots::{
     clip_interval, event_to_interval, free_slots, intervals_overlap, local_datetime_to_utc,
-    merge_intervals, parse_rfc3339_input, Interval,
+    merge_intervals, parse_date_input, parse_rfc3339_input, Interval,
 };
 use crate::domain::models::{Block, Firmness};
+use crate::infrastructure::calendar_cache::CalendarCacheRepository;
 use crate::infrastructure::error::InfraError;
-use crate::infrastructure::event_mapper::encode_block_event;
-use chrono::NaiveDate;
-use std::collections::HashMap;
+use crate::infrastructure::event_mapper::GoogleCalendarEvent;
+use crate::infrastructure::google_calendar_client::GoogleCalendarClient;
+use crate::infrastructure::sync_state_repository::SyncStateRepository;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+const ORPHAN_SCAN_DAYS: i64 = 60;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BusySummary {
+    pub work_minutes: i64,
+    pub busy_minutes: i64,
+    pub block_minutes: i64,
+    pub free_minutes: i64,
+    pub occupancy_ratio: f64,
+}
+
+/// A block plus its start and end as RFC3339 in the policy timezone, for display.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct LocalizedBlock {
+    #[serde(flatten)]
+    pub block: Block,
+    pub start_local: String,
+    pub end_local: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct DayCapacity {
+    pub date: String,
+    pub planned_pomodoros: u32,
+    pub available_pomodoros: u32,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct WeekCapacity {
+    pub week_start: String,
+    pub days: Vec<DayCapacity>,
+    pub planned_pomodoros: u32,
+    pub available_pomodoros: u32,
+}
+
+/// Outcome of a batch relocation: blocks that moved, blocks with no free slot left, and
+/// blocks whose calendar update failed and were left where they were.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct RelocateBlocksResult {
+    pub relocated: Vec<Block>,
+    pub unplaced: Vec<String>,
+    pub failed: Vec<RelocationFailure>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct RelocationFailure {
+    pub block_id: String,
+    pub error: String,
+}
 
 pub async fn approve_blocks(
     state: &AppState,
@@ -61,11 +128,10 @@ pub async fn approve_blocks(
             if access_tokens_by_account.contains_key(account_id) {
                 continue;
             }
-            if let Some(token) = try_access_token(Some(account_id.clone())).await? {
+            if let Some(token) = try_access_token(account_id).await? {
                 access_tokens_by_account.insert(account_id.clone(), token);
             }
         }
-        let sync_service = build_sync_service(state);
         for (event_id, account_id, block) in &calendar_updates {
             let Some(token) = access_tokens_by_account.get(account_id).map(String::as_str) else {
                 continue;
@@ -73,8 +139,8 @@ pub async fn approve_blocks(
             let Some(calendar_id) = calendar_ids.get(account_id).map(String::as_str) else {
                 continue;
             };
-            let event = encode_block_event(block);
-            sync_service
+            let event = encode_block_event_for(state, block)?;
+            build_sync_service(state, account_id)
                 .update_event(token, calendar_id, event_id, &event)
                 .await?;
         }
@@ -87,13 +153,49 @@ pub async fn approve_blocks(
     Ok(approved_blocks)
 }
 
-pub async fn delete_block(state: &AppState, block_id: String) -> Result<bool, InfraError> {
+fn encode_block_event_for(state: &AppState, block: &Block) -> Result<GoogleCalendarEvent, InfraError> {
+    let task_id = lock_runtime(state)?
+        .task_assignments_by_block
+        .get(block.id.as_str())
+        .cloned();
+    let policy = load_runtime_policy(state.config_dir());
+    Ok(encode_block_event_for_policy(block, task_id.as_deref(), &policy))
+}
+
+pub async fn delete_block(
+    state: &AppState,
+    block_id: String,
+    expires_at: Option<String>,
+) -> Result<bool, InfraError> {
     let block_id = block_id.trim();
     if block_id.is_empty() {
         return Err(InfraError::InvalidConfig(
             "block_id must not be empty".to_string(),
         ));
     }
+    let expires_at = expires_at
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| parse_rfc3339_input(value, "expires_at"))
+        .transpose()?;
+
+    // Carry-over moves the assignment off this block, so the removal below leaves the task alone.
+    let policy = load_runtime_policy(state.config_dir());
+    let carried_task_id = lock_runtime(state)?
+        .task_assignments_by_block
+        .get(block_id)
+        .cloned()
+        .filter(|_| policy.on_block_delete == BlockDeleteBehavior::CarryOver);
+    if let Err(error) = carried_task_id
+        .map(|task_id| TaskService::new(state).carry_over_task(task_id, block_id.to_string(), None))
+        .transpose()
+    {
+        state.log_warn(
+            "delete_block",
+            &format!("unassigning task of block_id={block_id}: {error}"),
+        );
+    }
 
     let removed = {
         let mut runtime = lock_runtime(state)?;
@@ -113,6 +215,7 @@ pub async fn delete_block(state: &AppState, block_id: String) -> Result<bool, In
         state.database_path(),
         &removed.block.instance,
         Some("user_deleted"),
+        expires_at,
     )?;
 
     if let Some(calendar_event_id) = removed.calendar_event_id {
@@ -123,7 +226,7 @@ pub async fn delete_block(state: &AppState, block_id: String) -> Result<bool, In
             .filter(|value| !value.is_empty())
             .unwrap_or(DEFAULT_ACCOUNT_ID)
             .to_string();
-        let access_token = try_access_token(Some(account_id.clone())).await?;
+        let access_token = try_access_token(&account_id).await?;
         let calendar_id = {
             let runtime = lock_runtime(state)?;
             runtime.blocks_calendar_ids.get(&account_id).cloned()
@@ -131,7 +234,7 @@ pub async fn delete_block(state: &AppState, block_id: String) -> Result<bool, In
 
         if let (Some(token), Some(calendar_id)) = (access_token.as_deref(), calendar_id.as_deref())
         {
-            build_sync_service(state)
+            build_sync_service(state, &account_id)
                 .delete_event(token, calendar_id, &calendar_event_id)
                 .await?;
         }
@@ -141,6 +244,110 @@ pub async fn delete_block(state: &AppState, block_id: String) -> Result<bool, In
     Ok(true)
 }
 
+/// Removes the account's blocks on `date`; with `suppress` the day stays empty on regeneration.
+/// Blocks with a calendar event are only dropped once that event was deleted.
+pub async fn clear_day(
+    state: &AppState,
+    date: String,
+    account_id: Option<String>,
+    suppress: bool,
+) -> Result<usize, InfraError> {
+    let date = parse_date_input(&date, "date")?;
+    let date_key = date.to_string();
+    let default_account_id = normalize_account_id(state.config_dir(), None);
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+
+    let mut candidates = lock_runtime(state)?
+        .blocks
+        .values()
+        .filter(|stored| stored.block.date == date_key)
+        .filter(|stored| {
+            stored.calendar_account_id.as_deref().unwrap_or(&default_account_id) == account_id
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|stored| stored.block.start_at);
+
+    let (removed, deletion) =
+        remove_blocks_after_deleting_events(state, &account_id, &candidates).await?;
+    if suppress {
+        for stored in &removed {
+            save_suppression(
+                state.database_path(),
+                &stored.block.instance,
+                Some("day_cleared"),
+                None,
+            )?;
+        }
+    }
+
+    let kept_count = candidates.len() - removed.len();
+    state.log_info(
+        "clear_day",
+        &format!(
+            "date={date_key} removed_count={} kept_count={kept_count} suppress={suppress}",
+            removed.len()
+        ),
+    );
+    deletion?;
+    Ok(removed.len())
+}
+
+/// Deletes the candidates' calendar events, stopping at the first failure, then drops the
+/// blocks that have no event or whose event was deleted. The deletion outcome is returned
+/// alongside the removed blocks so callers can finish their work before surfacing it.
+pub(crate) async fn remove_blocks_after_deleting_events(
+    state: &AppState,
+    account_id: &str,
+    candidates: &[StoredBlock],
+) -> Result<(Vec<StoredBlock>, Result<(), InfraError>), InfraError> {
+    let event_ids = candidates
+        .iter()
+        .filter_map(|stored| stored.calendar_event_id.clone())
+        .collect::<Vec<_>>();
+    let mut deleted_count = 0;
+    let mut deletion = Ok(());
+    if !event_ids.is_empty() {
+        let mut blocks_calendar_ids = lock_runtime(state)?.blocks_calendar_ids.clone();
+        let access_token = try_access_token(account_id).await?;
+        let calendar_id = resolve_cached_blocks_calendar_id(
+            state,
+            access_token.as_deref(),
+            account_id,
+            &mut blocks_calendar_ids,
+        )
+        .await?;
+        if let (Some(token), Some(calendar_id)) = (access_token.as_deref(), calendar_id.as_deref())
+        {
+            let sync_service = build_sync_service(state, account_id);
+            (deleted_count, deletion) =
+                delete_block_events(&sync_service, token, calendar_id, &event_ids).await;
+        }
+    }
+    let deleted_event_ids = event_ids[..deleted_count].iter().collect::<HashSet<_>>();
+
+    let mut runtime = lock_runtime(state)?;
+    let mut removed = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        let event_deleted = candidate
+            .calendar_event_id
+            .as_ref()
+            .is_none_or(|event_id| deleted_event_ids.contains(event_id));
+        if !event_deleted {
+            continue;
+        }
+        let block_id = candidate.block.id.as_str();
+        if let Some(task_id) = runtime.task_assignments_by_block.remove(block_id) {
+            runtime.task_assignments_by_task.remove(task_id.as_str());
+            if runtime.pomodoro.current_task_id.as_deref() == Some(task_id.as_str()) {
+                runtime.pomodoro.current_task_id = None;
+            }
+        }
+        removed.extend(runtime.blocks.remove(block_id));
+    }
+    Ok((removed, deletion))
+}
+
 pub async fn adjust_block_time(
     state: &AppState,
     block_id: String,
@@ -177,27 +384,7 @@ pub async fn adjust_block_time(
             stored.calendar_account_id.clone(),
         )
     };
-
-    if let Some(calendar_event_id) = calendar_event_id {
-        let account_id = calendar_account_id
-            .as_deref()
-            .map(str::trim)
-            .filter(|value| !value.is_empty())
-            .unwrap_or(DEFAULT_ACCOUNT_ID)
-            .to_string();
-        let access_token = try_access_token(Some(account_id.clone())).await?;
-        let calendar_id = {
-            let runtime = lock_runtime(state)?;
-            runtime.blocks_calendar_ids.get(&account_id).cloned()
-        };
-        if let (Some(token), Some(calendar_id)) = (access_token.as_deref(), calendar_id.as_deref())
-        {
-            let event = encode_block_event(&updated_block);
-            build_sync_service(state)
-                .update_event(token, calendar_id, &calendar_event_id, &event)
-                .await?;
-        }
-    }
+    update_block_event(state, &updated_block, calendar_event_id, calendar_account_id).await?;
 
     state.log_info(
         "adjust_block_time",
@@ -206,6 +393,83 @@ pub async fn adjust_block_time(
     Ok(updated_block)
 }
 
+pub async fn set_block_project(
+    state: &AppState,
+    block_id: String,
+    project: Option<String>,
+) -> Result<Block, InfraError> {
+    let block_id = block_id.trim();
+    if block_id.is_empty() {
+        return Err(InfraError::InvalidConfig(
+            "block_id must not be empty".to_string(),
+        ));
+    }
+    let project = normalize_project(project);
+
+    let (updated_block, calendar_event_id, calendar_account_id) = {
+        let mut runtime = lock_runtime(state)?;
+        let Some(stored) = runtime.blocks.get_mut(block_id) else {
+            return Err(InfraError::InvalidConfig(format!(
+                "block not found: {}",
+                block_id
+            )));
+        };
+        stored.block.project = project;
+        (
+            stored.block.clone(),
+            stored.calendar_event_id.clone(),
+            stored.calendar_account_id.clone(),
+        )
+    };
+    update_block_event(state, &updated_block, calendar_event_id, calendar_account_id).await?;
+
+    state.log_info(
+        "set_block_project",
+        &format!(
+            "updated block_id={block_id} project={}",
+            updated_block.project.as_deref().unwrap_or("none")
+        ),
+    );
+    Ok(updated_block)
+}
+
+async fn update_block_event(
+    state: &AppState,
+    block: &Block,
+    calendar_event_id: Option<String>,
+    calendar_account_id: Option<String>,
+) -> Result<(), InfraError> {
+    let Some(calendar_event_id) = calendar_event_id else {
+        return Ok(());
+    };
+    let account_id = calendar_account_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .unwrap_or(DEFAULT_ACCOUNT_ID)
+        .to_string();
+    let access_token = try_access_token(&account_id).await?;
+    let calendar_id = {
+        let runtime = lock_runtime(state)?;
+        runtime.blocks_calendar_ids.get(&account_id).cloned()
+    };
+    if let (Some(token), Some(calendar_id)) = (access_token.as_deref(), calendar_id.as_deref()) {
+        let event = encode_block_event_for(state, block)?;
+        build_sync_service(state, &account_id)
+            .update_event(token, calendar_id, &calendar_event_id, &event)
+            .await?;
+    }
+    Ok(())
+}
+
+fn normalize_project(project: Option<String>) -> Option<String> {
+    project
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(ToOwned::to_owned)
+}
+
 pub async fn relocate_if_needed(
     state: &AppState,
     block_id: String,
@@ -218,7 +482,7 @@ pub async fn relocate_if_needed(
         ));
     }
 
-    let requested_account_id = normalize_account_id(account_id);
+    let requested_account_id = normalize_account_id(state.config_dir(), account_id);
     let policy = load_runtime_policy(state.config_dir());
     let (
         target_stored_block,
@@ -250,7 +514,7 @@ pub async fn relocate_if_needed(
             .blocks
             .values()
             .filter(|candidate| candidate.block.id != stored_block.block.id)
-            .cloned()
+            .map(|candidate| candidate.block.clone())
             .collect::<Vec<_>>();
         (
             stored_block,
@@ -261,10 +525,183 @@ pub async fn relocate_if_needed(
         )
     };
 
-    let block = target_stored_block.block.clone();
-    let date = NaiveDate::parse_from_str(block.date.trim(), "%Y-%m-%d").map_err(|error| {
-        InfraError::InvalidConfig(format!("block date must be YYYY-MM-DD: {error}"))
-    })?;
+    let (new_start, new_end) =
+        match plan_relocation(&policy, &target_stored_block, &account_events, &other_blocks)? {
+            RelocationPlan::Keep => return Ok(None),
+            RelocationPlan::Move(new_start, new_end) => (new_start, new_end),
+            RelocationPlan::NoSlot => {
+                state.log_info(
+                    "relocate_if_needed",
+                    &format!("manual adjustment required for block_id={block_id}"),
+                );
+                return Ok(None);
+            }
+        };
+
+    let updated_block = move_block(
+        state,
+        block_id,
+        new_start,
+        new_end,
+        &effective_account_id,
+        &blocks_calendar_ids,
+    )
+    .await?;
+
+    MetricsCounters::add(&state.metrics().relocations, 1);
+    state.log_info(
+        "relocate_if_needed",
+        &format!(
+            "relocated block_id={} start={} end={} account_id={}",
+            updated_block.id, updated_block.start_at, updated_block.end_at, effective_account_id
+        ),
+    );
+    Ok(Some(updated_block))
+}
+
+pub async fn relocate_blocks(
+    state: &AppState,
+    block_ids: Vec<String>,
+    account_id: Option<String>,
+) -> Result<RelocateBlocksResult, InfraError> {
+    let mut seen = HashSet::new();
+    let block_ids = block_ids
+        .iter()
+        .map(|block_id| block_id.trim())
+        .filter(|block_id| !block_id.is_empty() && seen.insert(*block_id))
+        .map(ToOwned::to_owned)
+        .collect::<Vec<_>>();
+    if block_ids.is_empty() {
+        return Ok(RelocateBlocksResult {
+            relocated: Vec::new(),
+            unplaced: Vec::new(),
+            failed: Vec::new(),
+        });
+    }
+
+    let requested_account_id = normalize_account_id(state.config_dir(), account_id);
+    let policy = load_runtime_policy(state.config_dir());
+    let (targets, mut placements, synced_events_by_account, blocks_calendar_ids) = {
+        let runtime = lock_runtime(state)?;
+        let mut targets = Vec::with_capacity(block_ids.len());
+        for block_id in &block_ids {
+            let Some(stored_block) = runtime.blocks.get(block_id).cloned() else {
+                return Err(InfraError::InvalidConfig(format!(
+                    "block not found: {}",
+                    block_id
+                )));
+            };
+            targets.push(stored_block);
+        }
+        let placements = runtime
+            .blocks
+            .iter()
+            .map(|(block_id, stored)| (block_id.clone(), stored.block.clone()))
+            .collect::<HashMap<_, _>>();
+        (
+            targets,
+            placements,
+            runtime.synced_events_by_account.clone(),
+            runtime.blocks_calendar_ids.clone(),
+        )
+    };
+
+    // Placements are planned against each other before any calendar write so
+    // that blocks moved earlier in the batch are already treated as busy.
+    let mut moves = Vec::new();
+    let mut unplaced = Vec::new();
+    for target in targets {
+        let effective_account_id = target
+            .calendar_account_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .unwrap_or(requested_account_id.as_str())
+            .to_string();
+        let account_events = synced_events_by_account
+            .get(&effective_account_id)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let other_blocks = placements
+            .values()
+            .filter(|candidate| candidate.id != target.block.id)
+            .cloned()
+            .collect::<Vec<_>>();
+        match plan_relocation(&policy, &target, account_events, &other_blocks)? {
+            RelocationPlan::Keep => {}
+            RelocationPlan::NoSlot => unplaced.push(target.block.id.clone()),
+            RelocationPlan::Move(new_start, new_end) => {
+                if let Some(placed) = placements.get_mut(&target.block.id) {
+                    placed.start_at = new_start;
+                    placed.end_at = new_end;
+                }
+                moves.push((target.block.id.clone(), new_start, new_end, effective_account_id));
+            }
+        }
+    }
+
+    let mut relocated = Vec::with_capacity(moves.len());
+    let mut failed = Vec::new();
+    for (block_id, new_start, new_end, account_id) in moves {
+        match move_block(
+            state,
+            &block_id,
+            new_start,
+            new_end,
+            &account_id,
+            &blocks_calendar_ids,
+        )
+        .await
+        {
+            Ok(block) => relocated.push(block),
+            Err(error) => {
+                state.log_warn(
+                    "relocate_blocks",
+                    &format!("failed to move block_id={block_id}: {error}"),
+                );
+                failed.push(RelocationFailure {
+                    block_id,
+                    error: error.to_string(),
+                });
+            }
+        }
+    }
+
+    MetricsCounters::add(&state.metrics().relocations, relocated.len());
+    state.log_info(
+        "relocate_blocks",
+        &format!(
+            "relocated_count={} unplaced_count={} failed_count={} requested_count={}",
+            relocated.len(),
+            unplaced.len(),
+            failed.len(),
+            block_ids.len()
+        ),
+    );
+    Ok(RelocateBlocksResult {
+        relocated,
+        unplaced,
+        failed,
+    })
+}
+
+enum RelocationPlan {
+    Keep,
+    NoSlot,
+    Move(DateTime<Utc>, DateTime<Utc>),
+}
+
+fn plan_relocation(
+    policy: &RuntimePolicy,
+    target: &StoredBlock,
+    account_events: &[GoogleCalendarEvent],
+    other_blocks: &[Block],
+) -> Result<RelocationPlan, InfraError> {
+    let block = &target.block;
+    if block.firmness == Firmness::Hard {
+        return Ok(RelocationPlan::Keep);
+    }
+    let date = parse_date_input(&block.date, "block date")?;
     let window_start = local_datetime_to_utc(date, policy.work_start, policy.timezone)?;
     let window_end = local_datetime_to_utc(date, policy.work_end, policy.timezone)?;
     let current_interval = Interval {
@@ -274,8 +711,8 @@ pub async fn relocate_if_needed(
 
     let mut busy_intervals = Vec::new();
     let mut collides_with_synced_events = false;
-    for event in &account_events {
-        if is_cancelled_event(event) {
+    for event in account_events {
+        if is_non_blocking_event(event, policy) {
             continue;
         }
         let event_id = event
@@ -283,7 +720,7 @@ pub async fn relocate_if_needed(
             .as_deref()
             .map(str::trim)
             .filter(|value| !value.is_empty());
-        if event_id == target_stored_block.calendar_event_id.as_deref() {
+        if event_id == target.calendar_event_id.as_deref() {
             continue;
         }
         let Some(interval) = event_to_interval(event)
@@ -298,25 +735,22 @@ pub async fn relocate_if_needed(
     }
 
     if !collides_with_synced_events {
-        return Ok(None);
+        return Ok(RelocationPlan::Keep);
     }
 
-    for other in &other_blocks {
-        if other.block.date != block.date {
+    for other in other_blocks {
+        if other.date != block.date {
             continue;
         }
         busy_intervals.push(Interval {
-            start: other.block.start_at,
-            end: other.block.end_at,
+            start: other.start_at,
+            end: other.end_at,
         });
     }
 
     let busy_intervals = merge_intervals(busy_intervals);
-    let slots = free_slots(window_start, window_end, &busy_intervals);
     let duration = current_interval.end - current_interval.start;
-
-    let mut relocated_range = None;
-    for slot in slots {
+    for slot in free_slots(window_start, window_end, &busy_intervals) {
         let candidate_end = slot.start + duration;
         if candidate_end > slot.end {
             continue;
@@ -324,18 +758,214 @@ pub async fn relocate_if_needed(
         if slot.start == current_interval.start && candidate_end == current_interval.end {
             continue;
         }
-        relocated_range = Some((slot.start, candidate_end));
-        break;
+        return Ok(RelocationPlan::Move(slot.start, candidate_end));
     }
+    Ok(RelocationPlan::NoSlot)
+}
 
-    let Some((new_start, new_end)) = relocated_range else {
+pub async fn reschedule_block(
+    state: &AppState,
+    block_id: String,
+    account_id: Option<String>,
+) -> Result<Option<Block>, InfraError> {
+    let block_id = block_id.trim();
+    if block_id.is_empty() {
+        return Err(InfraError::InvalidConfig(
+            "block_id must not be empty".to_string(),
+        ));
+    }
+
+    let requested_account_id = normalize_account_id(state.config_dir(), account_id);
+    let policy = load_runtime_policy(state.config_dir());
+    let (
+        target_stored_block,
+        effective_account_id,
+        account_events,
+        other_blocks,
+        blocks_calendar_ids,
+    ) = {
+        let runtime = lock_runtime(state)?;
+        let Some(stored_block) = runtime.blocks.get(block_id).cloned() else {
+            return Err(InfraError::InvalidConfig(format!(
+                "block not found: {}",
+                block_id
+            )));
+        };
+        let effective_account_id = stored_block
+            .calendar_account_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .unwrap_or(requested_account_id.as_str())
+            .to_string();
+        let account_events = runtime
+            .synced_events_by_account
+            .get(&effective_account_id)
+            .cloned()
+            .unwrap_or_default();
+        let other_blocks = runtime
+            .blocks
+            .values()
+            .filter(|candidate| candidate.block.id != stored_block.block.id)
+            .filter(|candidate| candidate.block.date == stored_block.block.date)
+            .map(|candidate| Interval {
+                start: candidate.block.start_at,
+                end: candidate.block.end_at,
+            })
+            .collect::<Vec<_>>();
+        (
+            stored_block,
+            effective_account_id,
+            account_events,
+            other_blocks,
+            runtime.blocks_calendar_ids.clone(),
+        )
+    };
+
+    let block = &target_stored_block.block;
+    let date = parse_date_input(&block.date, "block date")?;
+    let window_start =
+        local_datetime_to_utc(date, policy.work_start, policy.timezone)?.max(block.end_at);
+    let window_end = local_datetime_to_utc(date, policy.work_end, policy.timezone)?;
+    if window_end <= window_start {
+        return Ok(None);
+    }
+
+    let mut busy_intervals = other_blocks;
+    for event in &account_events {
+        if is_non_blocking_event(event, &policy) {
+            continue;
+        }
+        let event_id = event
+            .id
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
+        if event_id == target_stored_block.calendar_event_id.as_deref() {
+            continue;
+        }
+        if let Some(interval) = event_to_interval(event) {
+            busy_intervals.push(interval);
+        }
+    }
+    let busy_intervals = merge_intervals(
+        busy_intervals
+            .into_iter()
+            .filter_map(|interval| clip_interval(interval, window_start, window_end))
+            .collect(),
+    );
+
+    let duration = block.end_at - block.start_at;
+    let Some(slot) = free_slots(window_start, window_end, &busy_intervals)
+        .into_iter()
+        .find(|slot| slot.end - slot.start >= duration)
+    else {
         state.log_info(
-            "relocate_if_needed",
-            &format!("manual adjustment required for block_id={block_id}"),
+            "reschedule_block",
+            &format!("no later slot available for block_id={block_id}"),
         );
         return Ok(None);
     };
 
+    let updated_block = move_block(
+        state,
+        block_id,
+        slot.start,
+        slot.start + duration,
+        &effective_account_id,
+        &blocks_calendar_ids,
+    )
+    .await?;
+    state.log_info(
+        "reschedule_block",
+        &format!(
+            "rescheduled block_id={} start={} end={}",
+            updated_block.id, updated_block.start_at, updated_block.end_at
+        ),
+    );
+    Ok(Some(updated_block))
+}
+
+pub async fn snooze_block(
+    state: &AppState,
+    block_id: String,
+    minutes: i64,
+    account_id: Option<String>,
+    allow_outside_window: bool,
+) -> Result<Block, InfraError> {
+    let block_id = block_id.trim();
+    if block_id.is_empty() {
+        return Err(InfraError::InvalidConfig(
+            "block_id must not be empty".to_string(),
+        ));
+    }
+
+    let requested_account_id = normalize_account_id(state.config_dir(), account_id);
+    let (block, effective_account_id, blocks_calendar_ids) = {
+        let runtime = lock_runtime(state)?;
+        let Some(stored_block) = runtime.blocks.get(block_id) else {
+            return Err(InfraError::InvalidConfig(format!(
+                "block not found: {}",
+                block_id
+            )));
+        };
+        let effective_account_id = stored_block
+            .calendar_account_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .unwrap_or(requested_account_id.as_str())
+            .to_string();
+        (
+            stored_block.block.clone(),
+            effective_account_id,
+            runtime.blocks_calendar_ids.clone(),
+        )
+    };
+
+    let offset = Duration::minutes(minutes);
+    let new_start = block.start_at + offset;
+    let new_end = block.end_at + offset;
+    if !allow_outside_window {
+        let policy = load_runtime_policy(state.config_dir());
+        let date = parse_date_input(&block.date, "block date")?;
+        let window_start = local_datetime_to_utc(date, policy.work_start, policy.timezone)?;
+        let window_end = local_datetime_to_utc(date, policy.work_end, policy.timezone)?;
+        if new_start < window_start || new_end > window_end {
+            return Err(InfraError::InvalidConfig(format!(
+                "snoozed block would leave the work window: start={} end={}",
+                new_start, new_end
+            )));
+        }
+    }
+
+    let updated_block = move_block(
+        state,
+        block_id,
+        new_start,
+        new_end,
+        &effective_account_id,
+        &blocks_calendar_ids,
+    )
+    .await?;
+    state.log_info(
+        "snooze_block",
+        &format!(
+            "snoozed block_id={} minutes={} start={} end={}",
+            updated_block.id, minutes, updated_block.start_at, updated_block.end_at
+        ),
+    );
+    Ok(updated_block)
+}
+
+async fn move_block(
+    state: &AppState,
+    block_id: &str,
+    new_start: DateTime<Utc>,
+    new_end: DateTime<Utc>,
+    account_id: &str,
+    blocks_calendar_ids: &HashMap<String, String>,
+) -> Result<Block, InfraError> {
     let (updated_block, calendar_event_id) = {
         let mut runtime = lock_runtime(state)?;
         let Some(stored) = runtime.blocks.get_mut(block_id) else {
@@ -350,33 +980,76 @@ pub async fn relocate_if_needed(
     };
 
     if let Some(calendar_event_id) = calendar_event_id {
-        let access_token = try_access_token(Some(effective_account_id.clone())).await?;
-        let calendar_id = blocks_calendar_ids.get(&effective_account_id).cloned();
+        let access_token = try_access_token(account_id).await?;
+        let calendar_id = blocks_calendar_ids.get(account_id).cloned();
         if let (Some(token), Some(calendar_id)) = (access_token.as_deref(), calendar_id.as_deref())
         {
-            let event = encode_block_event(&updated_block);
-            build_sync_service(state)
+            let event = encode_block_event_for(state, &updated_block)?;
+            build_sync_service(state, account_id)
                 .update_event(token, calendar_id, &calendar_event_id, &event)
                 .await?;
         }
     }
+    Ok(updated_block)
+}
 
-    state.log_info(
-        "relocate_if_needed",
-        &format!(
-            "relocated block_id={} start={} end={} account_id={}",
-            updated_block.id, updated_block.start_at, updated_block.end_at, effective_account_id
-        ),
-    );
-    Ok(Some(updated_block))
+pub async fn reflow_day(
+    state: &AppState,
+    date: String,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, InfraError> {
+    let date = parse_date_input(&date, "date")?;
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    let policy = load_runtime_policy(state.config_dir());
+    let day_start = local_datetime_to_utc(date, NaiveTime::MIN, policy.timezone)?;
+    let day_end = local_datetime_to_utc(
+        date.succ_opt().unwrap_or(date),
+        NaiveTime::MIN,
+        policy.timezone,
+    )?;
+
+    let busy_intervals = {
+        let runtime = lock_runtime(state)?;
+        runtime
+            .synced_events_by_account
+            .get(&account_id)
+            .map(|events| {
+                events
+                    .iter()
+                    .filter(|event| !is_non_blocking_event(event, &policy))
+                    .filter_map(event_to_interval)
+                    .filter_map(|interval| clip_interval(interval, day_start, day_end))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+    };
+    let relocated = auto_relocate_after_sync(
+        state,
+        &account_id,
+        &merge_intervals(busy_intervals),
+        policy.max_relocations_per_sync,
+    )
+    .await?;
+
+    let runtime = lock_runtime(state)?;
+    Ok(relocated
+        .iter()
+        .filter_map(|entry| runtime.blocks.get(&entry.block_id))
+        .map(|stored| stored.block.clone())
+        .collect())
 }
 
-pub fn list_blocks(state: &AppState, date: Option<String>) -> Result<Vec<Block>, InfraError> {
+pub fn list_blocks(
+    state: &AppState,
+    date: Option<String>,
+    project: Option<String>,
+) -> Result<Vec<Block>, InfraError> {
     let normalized_date = date
         .as_deref()
         .map(str::trim)
         .filter(|value| !value.is_empty())
         .map(ToOwned::to_owned);
+    let normalized_project = normalize_project(project);
 
     let runtime = lock_runtime(state)?;
     let mut blocks = runtime
@@ -389,11 +1062,465 @@ pub fn list_blocks(state: &AppState, date: Option<String>) -> Result<Vec<Block>,
                 .map(|date| block.date == date)
                 .unwrap_or(true)
         })
+        .filter(|block| {
+            normalized_project.is_none() || block.project.as_deref() == normalized_project.as_deref()
+        })
         .collect::<Vec<_>>();
     blocks.sort_by(|left, right| left.start_at.cmp(&right.start_at));
     Ok(blocks)
 }
 
-fn build_sync_service(state: &AppState) -> ReqwestCalendarSyncService {
-    build_reqwest_calendar_sync_service(state)
+pub fn get_blocks_localized(
+    state: &AppState,
+    date: Option<String>,
+    project: Option<String>,
+) -> Result<Vec<LocalizedBlock>, InfraError> {
+    let timezone = load_runtime_policy(state.config_dir()).timezone;
+    Ok(list_blocks(state, date, project)?
+        .into_iter()
+        .map(|block| LocalizedBlock {
+            start_local: block.start_at.with_timezone(&timezone).to_rfc3339(),
+            end_local: block.end_at.with_timezone(&timezone).to_rfc3339(),
+            block,
+        })
+        .collect())
+}
+
+pub fn get_overlapping_blocks(
+    state: &AppState,
+    date: String,
+) -> Result<Vec<(String, String)>, InfraError> {
+    let date = parse_date_input(&date, "date")?.to_string();
+
+    let runtime = lock_runtime(state)?;
+    let mut intervals = Vec::new();
+    for stored in runtime.blocks.values() {
+        if stored.block.date != date {
+            continue;
+        }
+        intervals.push((
+            stored.block.id.clone(),
+            Interval {
+                start: stored.block.start_at,
+                end: stored.block.end_at,
+            },
+        ));
+    }
+    intervals.sort_by(|left, right| {
+        left.1
+            .start
+            .cmp(&right.1.start)
+            .then_with(|| left.0.cmp(&right.0))
+    });
+
+    let mut overlaps = Vec::new();
+    for (index, (left_id, left)) in intervals.iter().enumerate() {
+        for (right_id, right) in &intervals[index + 1..] {
+            if right.start >= left.end {
+                break;
+            }
+            if intervals_overlap(left, right) {
+                overlaps.push((left_id.clone(), right_id.clone()));
+            }
+        }
+    }
+    Ok(overlaps)
+}
+
+pub fn get_busy_summary(
+    state: &AppState,
+    date: String,
+    account_id: Option<String>,
+) -> Result<BusySummary, InfraError> {
+    let date = parse_date_input(&date, "date")?;
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    let policy = load_runtime_policy(state.config_dir());
+    let window_start = local_datetime_to_utc(date, policy.work_start, policy.timezone)?;
+    let window_end = local_datetime_to_utc(date, policy.work_end, policy.timezone)?.max(window_start);
+
+    let (event_intervals, block_intervals) = {
+        let runtime = lock_runtime(state)?;
+        let event_intervals = runtime
+            .synced_events_by_account
+            .get(&account_id)
+            .into_iter()
+            .flatten()
+            .filter(|event| !is_non_blocking_event(event, &policy))
+            .filter_map(event_to_interval)
+            .filter_map(|interval| clip_interval(interval, window_start, window_end))
+            .collect::<Vec<_>>();
+        let date = date.to_string();
+        let block_intervals = runtime
+            .blocks
+            .values()
+            .filter(|stored| stored.block.date == date)
+            .filter_map(|stored| {
+                clip_interval(
+                    Interval {
+                        start: stored.block.start_at,
+                        end: stored.block.end_at,
+                    },
+                    window_start,
+                    window_end,
+                )
+            })
+            .collect::<Vec<_>>();
+        (event_intervals, block_intervals)
+    };
+
+    let total_minutes = |intervals: &[Interval]| {
+        intervals
+            .iter()
+            .map(|interval| (interval.end - interval.start).num_minutes())
+            .sum::<i64>()
+    };
+    let work_minutes = (window_end - window_start).num_minutes();
+    let busy_minutes = total_minutes(&merge_intervals(event_intervals.clone()));
+    let block_minutes = total_minutes(&merge_intervals(block_intervals.clone()));
+    let occupied = merge_intervals(event_intervals.into_iter().chain(block_intervals).collect());
+    let free_minutes = total_minutes(&free_slots(window_start, window_end, &occupied));
+    let occupancy_ratio = if work_minutes > 0 {
+        (work_minutes - free_minutes) as f64 / work_minutes as f64
+    } else {
+        0.0
+    };
+
+    Ok(BusySummary {
+        work_minutes,
+        busy_minutes,
+        block_minutes,
+        free_minutes,
+        occupancy_ratio,
+    })
+}
+
+/// Whole focus+break cycles that still fit between calendar events in the
+/// rest of the work window; existing blocks count as capacity, not busy time.
+pub fn remaining_capacity(
+    state: &AppState,
+    date: String,
+    account_id: Option<String>,
+    now: DateTime<Utc>,
+) -> Result<u32, InfraError> {
+    let date = parse_date_input(&date, "date")?;
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    let policy = load_runtime_policy(state.config_dir());
+    cycle_capacity(state, &policy, date, &account_id, now)
+}
+
+fn cycle_capacity(
+    state: &AppState,
+    policy: &RuntimePolicy,
+    date: NaiveDate,
+    account_id: &str,
+    now: DateTime<Utc>,
+) -> Result<u32, InfraError> {
+    if !policy.work_days.contains(&date.weekday()) {
+        return Ok(0);
+    }
+    let window_start = local_datetime_to_utc(date, policy.work_start, policy.timezone)?.max(now);
+    let window_end = local_datetime_to_utc(date, policy.work_end, policy.timezone)?;
+    if window_end <= window_start {
+        return Ok(0);
+    }
+
+    let event_intervals = {
+        let runtime = lock_runtime(state)?;
+        runtime
+            .synced_events_by_account
+            .get(account_id)
+            .into_iter()
+            .flatten()
+            .filter(|event| !is_non_blocking_event(event, policy))
+            .filter_map(event_to_interval)
+            .filter_map(|interval| clip_interval(interval, window_start, window_end))
+            .collect::<Vec<_>>()
+    };
+    let cycle_minutes =
+        i64::from(FOCUS_MINUTES_PER_POMODORO.saturating_add(policy.break_duration_minutes.max(1)));
+    let capacity = free_slots(window_start, window_end, &merge_intervals(event_intervals))
+        .iter()
+        .map(|slot| (slot.end - slot.start).num_minutes() / cycle_minutes)
+        .sum::<i64>();
+    Ok(capacity as u32)
+}
+
+/// Planned pomodoros from blocks next to the full-day cycle capacity for the
+/// seven days starting at `week_start`.
+pub fn get_week_capacity(
+    state: &AppState,
+    week_start: String,
+    account_id: Option<String>,
+) -> Result<WeekCapacity, InfraError> {
+    let week_start = parse_date_input(&week_start, "week_start")?;
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    let policy = load_runtime_policy(state.config_dir());
+    let planned_by_date = {
+        let runtime = lock_runtime(state)?;
+        let mut planned_by_date = HashMap::<String, u32>::new();
+        for stored in runtime.blocks.values() {
+            let block_account_id =
+                stored.calendar_account_id.as_deref().unwrap_or(DEFAULT_ACCOUNT_ID);
+            if block_account_id != account_id || stored.block.source == BREAK_BLOCK_SOURCE {
+                continue;
+            }
+            *planned_by_date.entry(stored.block.date.clone()).or_default() +=
+                stored.block.planned_pomodoros.max(0) as u32;
+        }
+        planned_by_date
+    };
+
+    let mut days = Vec::with_capacity(7);
+    for date in week_start.iter_days().take(7) {
+        let date_key = date.to_string();
+        days.push(DayCapacity {
+            planned_pomodoros: planned_by_date.get(&date_key).copied().unwrap_or(0),
+            available_pomodoros: cycle_capacity(
+                state,
+                &policy,
+                date,
+                &account_id,
+                DateTime::<Utc>::MIN_UTC,
+            )?,
+            date: date_key,
+        });
+    }
+    Ok(WeekCapacity {
+        week_start: week_start.to_string(),
+        planned_pomodoros: days.iter().map(|day| day.planned_pomodoros).sum(),
+        available_pomodoros: days.iter().map(|day| day.available_pomodoros).sum(),
+        days,
+    })
+}
+
+pub async fn sync_pending_block_events(
+    state: &AppState,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, InfraError> {
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    let mut blocks_calendar_ids = {
+        let runtime = lock_runtime(state)?;
+        if pending_block_events(&runtime, &account_id).is_empty() {
+            return Ok(Vec::new());
+        }
+        runtime.blocks_calendar_ids.clone()
+    };
+
+    let access_token = try_access_token(&account_id).await?;
+    let calendar_id = resolve_cached_blocks_calendar_id(
+        state,
+        access_token.as_deref(),
+        &account_id,
+        &mut blocks_calendar_ids,
+    )
+    .await?;
+    let (Some(token), Some(calendar_id)) = (access_token.as_deref(), calendar_id.as_deref())
+    else {
+        return Ok(Vec::new());
+    };
+    let sync_service = std::sync::Arc::new(build_sync_service(state, &account_id));
+    sync_pending_block_events_with(state, &account_id, sync_service, token, calendar_id).await
+}
+
+/// Creates events for the account's blocks that still have no `calendar_event_id` and
+/// stores the ids that were created, even when another creation failed.
+pub(crate) async fn sync_pending_block_events_with<C, S, R>(
+    state: &AppState,
+    account_id: &str,
+    sync_service: std::sync::Arc<CalendarSyncService<C, S, R>>,
+    access_token: &str,
+    calendar_id: &str,
+) -> Result<Vec<Block>, InfraError>
+where
+    C: GoogleCalendarClient + 'static,
+    S: SyncStateRepository + 'static,
+    R: CalendarCacheRepository + 'static,
+{
+    let mut pending = pending_block_events(&*lock_runtime(state)?, account_id);
+    if pending.is_empty() {
+        return Ok(Vec::new());
+    }
+    let policy = load_runtime_policy(state.config_dir());
+    let event_creation = create_calendar_events_for_generated_blocks(
+        sync_service,
+        access_token,
+        calendar_id,
+        &policy,
+        &mut pending,
+    )
+    .await;
+
+    let mut synced_blocks = Vec::new();
+    {
+        let mut runtime = lock_runtime(state)?;
+        runtime
+            .blocks_calendar_ids
+            .insert(account_id.to_string(), calendar_id.to_string());
+        for created in &pending {
+            let Some(event_id) = created.calendar_event_id.clone() else {
+                continue;
+            };
+            if let Some(stored) = runtime.blocks.get_mut(created.block.id.as_str()) {
+                stored.calendar_event_id = Some(event_id);
+                stored.calendar_account_id = Some(account_id.to_string());
+                synced_blocks.push(stored.block.clone());
+            }
+        }
+    }
+
+    state.log_info(
+        "sync_pending_block_events",
+        &format!(
+            "synced {} of {} pending blocks account_id={account_id}",
+            synced_blocks.len(),
+            pending.len()
+        ),
+    );
+    event_creation?;
+    Ok(synced_blocks)
+}
+
+fn pending_block_events(runtime: &RuntimeState, account_id: &str) -> Vec<StoredBlock> {
+    let mut pending = runtime
+        .blocks
+        .values()
+        .filter(|stored| stored.calendar_event_id.is_none())
+        .filter(|stored| {
+            stored.calendar_account_id.as_deref().unwrap_or(DEFAULT_ACCOUNT_ID) == account_id
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    pending.sort_by_key(|stored| stored.block.start_at);
+    pending
+}
+
+/// Fetches the event Google currently holds for a block, for inspecting sync problems.
+#[cfg(any(test, feature = "debug-tools"))]
+pub async fn get_block_calendar_event(
+    state: &AppState,
+    block_id: String,
+    account_id: Option<String>,
+) -> Result<Option<GoogleCalendarEvent>, InfraError> {
+    let block_id = block_id.trim();
+    if block_id.is_empty() {
+        return Err(InfraError::InvalidConfig(
+            "block_id must not be empty".to_string(),
+        ));
+    }
+    let (calendar_event_id, calendar_account_id, mut blocks_calendar_ids) = {
+        let runtime = lock_runtime(state)?;
+        let Some(stored) = runtime.blocks.get(block_id) else {
+            return Err(InfraError::InvalidConfig(format!(
+                "block not found: {}",
+                block_id
+            )));
+        };
+        (
+            stored.calendar_event_id.clone(),
+            stored.calendar_account_id.clone(),
+            runtime.blocks_calendar_ids.clone(),
+        )
+    };
+    let Some(calendar_event_id) = calendar_event_id else {
+        return Ok(None);
+    };
+
+    let account_id = normalize_account_id(state.config_dir(), calendar_account_id.or(account_id));
+    let access_token = try_access_token(&account_id).await?;
+    let calendar_id = resolve_cached_blocks_calendar_id(
+        state,
+        access_token.as_deref(),
+        &account_id,
+        &mut blocks_calendar_ids,
+    )
+    .await?;
+    let (Some(token), Some(calendar_id)) = (access_token.as_deref(), calendar_id.as_deref())
+    else {
+        return Ok(None);
+    };
+    build_sync_service(state, &account_id)
+        .get_event(token, calendar_id, &calendar_event_id)
+        .await
+}
+
+/// Removes calendar events left behind by blocks that were deleted while offline.
+pub async fn cleanup_orphaned_events(
+    state: &AppState,
+    account_id: Option<String>,
+) -> Result<usize, InfraError> {
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    let mut blocks_calendar_ids = lock_runtime(state)?.blocks_calendar_ids.clone();
+    let access_token = try_access_token(&account_id).await?;
+    let calendar_id = resolve_cached_blocks_calendar_id(
+        state,
+        access_token.as_deref(),
+        &account_id,
+        &mut blocks_calendar_ids,
+    )
+    .await?;
+    let (Some(token), Some(calendar_id)) = (access_token.as_deref(), calendar_id.as_deref())
+    else {
+        return Ok(0);
+    };
+    cleanup_orphaned_events_with(
+        state,
+        &account_id,
+        &build_sync_service(state, &account_id),
+        token,
+        calendar_id,
+        Utc::now(),
+    )
+    .await
+}
+
+/// Only instances recorded as deleted by the user are treated as orphans, so an empty
+/// runtime after a restart never deletes anything.
+pub(crate) async fn cleanup_orphaned_events_with<C, S, R>(
+    state: &AppState,
+    account_id: &str,
+    sync_service: &CalendarSyncService<C, S, R>,
+    access_token: &str,
+    calendar_id: &str,
+    now: DateTime<Utc>,
+) -> Result<usize, InfraError>
+where
+    C: GoogleCalendarClient,
+    S: SyncStateRepository,
+    R: CalendarCacheRepository,
+{
+    let local_instances = lock_runtime(state)?
+        .blocks
+        .values()
+        .filter(|stored| {
+            stored.calendar_account_id.as_deref().unwrap_or(DEFAULT_ACCOUNT_ID) == account_id
+        })
+        .map(|stored| stored.block.instance.clone())
+        .collect::<HashSet<_>>();
+    let deleted_instances = load_user_deleted_instances(state.database_path())?;
+    if deleted_instances.is_empty() {
+        return Ok(0);
+    }
+    let window = (
+        now - Duration::days(ORPHAN_SCAN_DAYS),
+        now + Duration::days(ORPHAN_SCAN_DAYS),
+    );
+    let removed = delete_orphaned_block_events(
+        sync_service,
+        access_token,
+        calendar_id,
+        window,
+        &local_instances,
+        &deleted_instances,
+    )
+    .await?;
+
+    state.log_info(
+        "cleanup_orphaned_events",
+        &format!("removed_count={removed} account_id={account_id}"),
+    );
+    Ok(removed)
+}
+
+fn build_sync_service(state: &AppState, account_id: &str) -> ReqwestCalendarSyncService {
+    build_reqwest_calendar_sync_service(state, account_id)
 }
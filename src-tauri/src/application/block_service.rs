@@ -1,9 +1,15 @@
+use crate::application::block_export;
 use crate::application::block_generation;
-use crate::application::block_operations;
+use crate::application::block_operations::{
+    self, BusySummary, LocalizedBlock, RelocateBlocksResult, WeekCapacity,
+};
 use crate::application::commands::AppState;
 use crate::application::studio_template_application::{self, ApplyStudioResult};
 use crate::domain::models::Block;
 use crate::infrastructure::error::InfraError;
+#[cfg(any(test, feature = "debug-tools"))]
+use crate::infrastructure::event_mapper::GoogleCalendarEvent;
+use chrono::Utc;
 
 pub struct BlockService<'a> {
     state: &'a AppState,
@@ -30,6 +36,15 @@ impl<'a> BlockService<'a> {
         block_generation::generate_one_block(self.state, date, account_id).await
     }
 
+    pub async fn generate_from_source(
+        &self,
+        date: String,
+        source_id: String,
+        account_id: Option<String>,
+    ) -> Result<Vec<Block>, InfraError> {
+        block_generation::generate_from_source(self.state, date, source_id, account_id).await
+    }
+
     pub async fn generate_today_blocks(
         &self,
         account_id: Option<String>,
@@ -37,12 +52,50 @@ impl<'a> BlockService<'a> {
         block_generation::generate_today_blocks(self.state, account_id).await
     }
 
+    pub async fn run_startup_catch_up(
+        &self,
+        last_run_date: Option<String>,
+        account_id: Option<String>,
+    ) -> Result<Vec<Block>, InfraError> {
+        block_generation::run_startup_catch_up(self.state, last_run_date, account_id, Utc::now())
+            .await
+    }
+
+    pub async fn regenerate_auto_blocks(
+        &self,
+        date: String,
+        account_id: Option<String>,
+    ) -> Result<Vec<Block>, InfraError> {
+        block_generation::regenerate_auto_blocks(self.state, date, account_id).await
+    }
+
+    pub async fn reset_day(
+        &self,
+        date: String,
+        account_id: Option<String>,
+    ) -> Result<Vec<Block>, InfraError> {
+        block_generation::reset_day(self.state, date, account_id).await
+    }
+
     pub async fn approve_blocks(&self, block_ids: Vec<String>) -> Result<Vec<Block>, InfraError> {
         block_operations::approve_blocks(self.state, block_ids).await
     }
 
-    pub async fn delete_block(&self, block_id: String) -> Result<bool, InfraError> {
-        block_operations::delete_block(self.state, block_id).await
+    pub async fn delete_block(
+        &self,
+        block_id: String,
+        expires_at: Option<String>,
+    ) -> Result<bool, InfraError> {
+        block_operations::delete_block(self.state, block_id, expires_at).await
+    }
+
+    pub async fn clear_day(
+        &self,
+        date: String,
+        account_id: Option<String>,
+        suppress: bool,
+    ) -> Result<usize, InfraError> {
+        block_operations::clear_day(self.state, date, account_id, suppress).await
     }
 
     pub async fn adjust_block_time(
@@ -62,8 +115,137 @@ impl<'a> BlockService<'a> {
         block_operations::relocate_if_needed(self.state, block_id, account_id).await
     }
 
-    pub fn list_blocks(&self, date: Option<String>) -> Result<Vec<Block>, InfraError> {
-        block_operations::list_blocks(self.state, date)
+    pub async fn relocate_blocks(
+        &self,
+        block_ids: Vec<String>,
+        account_id: Option<String>,
+    ) -> Result<RelocateBlocksResult, InfraError> {
+        block_operations::relocate_blocks(self.state, block_ids, account_id).await
+    }
+
+    pub fn get_busy_summary(
+        &self,
+        date: String,
+        account_id: Option<String>,
+    ) -> Result<BusySummary, InfraError> {
+        block_operations::get_busy_summary(self.state, date, account_id)
+    }
+
+    pub fn remaining_capacity(
+        &self,
+        date: String,
+        account_id: Option<String>,
+    ) -> Result<u32, InfraError> {
+        block_operations::remaining_capacity(self.state, date, account_id, Utc::now())
+    }
+
+    pub fn get_week_capacity(
+        &self,
+        week_start: String,
+        account_id: Option<String>,
+    ) -> Result<WeekCapacity, InfraError> {
+        block_operations::get_week_capacity(self.state, week_start, account_id)
+    }
+
+    pub async fn sync_pending_block_events(
+        &self,
+        account_id: Option<String>,
+    ) -> Result<Vec<Block>, InfraError> {
+        block_operations::sync_pending_block_events(self.state, account_id).await
+    }
+
+    #[cfg(any(test, feature = "debug-tools"))]
+    pub async fn get_block_calendar_event(
+        &self,
+        block_id: String,
+        account_id: Option<String>,
+    ) -> Result<Option<GoogleCalendarEvent>, InfraError> {
+        block_operations::get_block_calendar_event(self.state, block_id, account_id).await
+    }
+
+    pub async fn cleanup_orphaned_events(
+        &self,
+        account_id: Option<String>,
+    ) -> Result<usize, InfraError> {
+        block_operations::cleanup_orphaned_events(self.state, account_id).await
+    }
+
+    pub async fn reschedule_block(
+        &self,
+        block_id: String,
+        account_id: Option<String>,
+    ) -> Result<Option<Block>, InfraError> {
+        block_operations::reschedule_block(self.state, block_id, account_id).await
+    }
+
+    pub async fn snooze_block(
+        &self,
+        block_id: String,
+        minutes: i64,
+        account_id: Option<String>,
+        allow_outside_window: bool,
+    ) -> Result<Block, InfraError> {
+        block_operations::snooze_block(
+            self.state,
+            block_id,
+            minutes,
+            account_id,
+            allow_outside_window,
+        )
+        .await
+    }
+
+    pub async fn set_block_project(
+        &self,
+        block_id: String,
+        project: Option<String>,
+    ) -> Result<Block, InfraError> {
+        block_operations::set_block_project(self.state, block_id, project).await
+    }
+
+    pub async fn reflow_day(
+        &self,
+        date: String,
+        account_id: Option<String>,
+    ) -> Result<Vec<Block>, InfraError> {
+        block_operations::reflow_day(self.state, date, account_id).await
+    }
+
+    pub fn list_blocks(
+        &self,
+        date: Option<String>,
+        project: Option<String>,
+    ) -> Result<Vec<Block>, InfraError> {
+        block_operations::list_blocks(self.state, date, project)
+    }
+
+    pub fn get_blocks_localized(
+        &self,
+        date: Option<String>,
+        project: Option<String>,
+    ) -> Result<Vec<LocalizedBlock>, InfraError> {
+        block_operations::get_blocks_localized(self.state, date, project)
+    }
+
+    pub fn get_overlapping_blocks(&self, date: String) -> Result<Vec<(String, String)>, InfraError> {
+        block_operations::get_overlapping_blocks(self.state, date)
+    }
+
+    pub fn export_day_markdown(
+        &self,
+        date: String,
+        account_id: Option<String>,
+    ) -> Result<String, InfraError> {
+        block_export::export_day_markdown(self.state, date, account_id)
+    }
+
+    pub fn export_blocks_ics(
+        &self,
+        start_date: String,
+        end_date: String,
+        path: String,
+    ) -> Result<usize, InfraError> {
+        block_export::export_blocks_ics(self.state, start_date, end_date, path)
     }
 
     pub async fn apply_studio_template_to_today(
@@ -94,7 +276,7 @@ mod tests {
     use crate::application::test_support::runtime_seed::seed_synced_events;
     use crate::application::test_support::workspace::TempWorkspace;
     use crate::infrastructure::calendar_cache::{CalendarCacheRepository, InMemoryCalendarCacheRepository};
-    use crate::infrastructure::event_mapper::{encode_block_event, CalendarEventExtendedProperties, GoogleCalendarEvent};
+    use crate::infrastructure::event_mapper::{encode_block_event, CalendarEventExtendedProperties};
     use crate::infrastructure::google_calendar_client::{
         GoogleCalendarClient, GoogleCalendarSummary, ListEventsRequest, ListEventsResponse,
     };
@@ -108,6 +290,7 @@ mod tests {
         created_events: Mutex<Vec<GoogleCalendarEvent>>,
         updated_events: Mutex<Vec<(String, GoogleCalendarEvent)>>,
         deleted_events: Mutex<Vec<String>>,
+        stored_events: Vec<GoogleCalendarEvent>,
     }
 
     #[async_trait]
@@ -128,6 +311,14 @@ mod tests {
             Err(InfraError::OAuth("not implemented in fake".to_string()))
         }
 
+        async fn get_calendar_timezone(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+        ) -> Result<Option<String>, InfraError> {
+            Ok(None)
+        }
+
         async fn list_events(
             &self,
             _access_token: &str,
@@ -137,9 +328,23 @@ mod tests {
             Ok(ListEventsResponse {
                 events: Vec::new(),
                 next_sync_token: Some("fake-sync-token".to_string()),
+                next_page_token: None,
             })
         }
 
+        async fn get_event(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+            event_id: &str,
+        ) -> Result<Option<GoogleCalendarEvent>, InfraError> {
+            Ok(self
+                .stored_events
+                .iter()
+                .find(|event| event.id.as_deref() == Some(event_id))
+                .cloned())
+        }
+
         async fn create_event(
             &self,
             _access_token: &str,
@@ -197,6 +402,8 @@ mod tests {
             planned_pomodoros: 1,
             source: "routine".to_string(),
             source_id: Some("auto".to_string()),
+            project: None,
+            title: None,
             recipe_id: "rcp-default".to_string(),
             auto_drive_mode: crate::domain::models::AutoDriveMode::Manual,
             contents: crate::domain::models::BlockContents::default(),
@@ -249,7 +456,7 @@ mod tests {
             .await
             .expect("approve block");
         let listed = service
-            .list_blocks(Some("2026-02-16".to_string()))
+            .list_blocks(Some("2026-02-16".to_string()), None)
             .expect("list blocks");
 
         assert_eq!(approved[0].firmness, crate::domain::models::Firmness::Soft);
@@ -271,17 +478,52 @@ mod tests {
             .await
             .expect("generate blocks");
         let deleted = service
-            .delete_block(generated[0].id.clone())
+            .delete_block(generated[0].id.clone(), None)
             .await
             .expect("delete block");
         let listed = service
-            .list_blocks(Some("2026-02-16".to_string()))
+            .list_blocks(Some("2026-02-16".to_string()), None)
             .expect("list blocks");
 
         assert!(deleted);
         assert!(listed.iter().all(|block| block.id != generated[0].id));
     }
 
+    #[tokio::test]
+    async fn clearing_a_day_removes_its_blocks_and_keeps_them_suppressed() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        let service = BlockService::new(&state);
+        let generated = service
+            .generate_blocks("2026-02-16".to_string(), None)
+            .await
+            .expect("generate blocks");
+        let other_day = service
+            .generate_blocks("2026-02-17".to_string(), None)
+            .await
+            .expect("generate other day");
+
+        let cleared = service
+            .clear_day("2026-02-16".to_string(), None, true)
+            .await
+            .expect("clear day");
+
+        assert_eq!(cleared, generated.len());
+        let listed = service
+            .list_blocks(Some("2026-02-16".to_string()), None)
+            .expect("list blocks");
+        assert!(listed.is_empty());
+        let kept = service
+            .list_blocks(Some("2026-02-17".to_string()), None)
+            .expect("list other day");
+        assert_eq!(kept.len(), other_day.len());
+        let regenerated = service
+            .generate_blocks("2026-02-16".to_string(), None)
+            .await
+            .expect("regenerate blocks");
+        assert!(regenerated.is_empty());
+    }
+
     #[tokio::test]
     async fn property_14_adjusting_block_time_updates_calendar_event_time_behavior() {
         let workspace = TempWorkspace::new();
@@ -301,7 +543,7 @@ mod tests {
             .await
             .expect("adjust block");
         let listed = service
-            .list_blocks(Some("2026-02-16".to_string()))
+            .list_blocks(Some("2026-02-16".to_string()), None)
             .expect("list blocks");
 
         assert_eq!(updated.start_at.to_rfc3339(), "2026-02-16T14:00:00+00:00");
@@ -344,6 +586,7 @@ mod tests {
                     time_zone: None,
                 },
                 extended_properties: None,
+                ..Default::default()
             }],
         )
         .expect("seed synced events");
@@ -389,6 +632,7 @@ mod tests {
                     time_zone: None,
                 },
                 extended_properties: Some(CalendarEventExtendedProperties::default()),
+                ..Default::default()
             }],
         )
         .expect("seed synced events");
@@ -398,10 +642,49 @@ mod tests {
             .await
             .expect("relocate");
         let summary = ReflectionService::new(&state)
-            .get_summary(None, None)
+            .get_summary(None, None, None)
             .expect("reflection summary");
 
         assert!(relocated.is_none());
         assert!(summary.logs.is_empty());
     }
+
+    #[tokio::test]
+    async fn block_calendar_event_is_read_back_from_the_calendar() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        let service = BlockService::new(&state);
+        let generated = service
+            .generate_blocks("2026-02-16".to_string(), None)
+            .await
+            .expect("generate blocks");
+        let unsynced = service
+            .get_block_calendar_event(generated[0].id.clone(), None)
+            .await
+            .expect("read unsynced block event");
+        assert_eq!(unsynced, None);
+
+        let mut known = encode_block_event(&generated[0]);
+        known.id = Some("evt-known".to_string());
+        let client = Arc::new(FakeGoogleCalendarClient {
+            stored_events: vec![known.clone()],
+            ..FakeGoogleCalendarClient::default()
+        });
+        let sync_service = CalendarSyncService::new(
+            client,
+            Arc::new(InMemorySyncStateRepository::default()),
+            Arc::new(InMemoryCalendarCacheRepository::default()),
+        );
+
+        let fetched = sync_service
+            .get_event("access-token", "blocks-calendar", "evt-known")
+            .await
+            .expect("get event");
+        assert_eq!(fetched, Some(known));
+        let missing = sync_service
+            .get_event("access-token", "blocks-calendar", "evt-missing")
+            .await
+            .expect("get missing event");
+        assert_eq!(missing, None);
+    }
 }
@@ -0,0 +1,369 @@
+use crate::application::commands::refreshed_access_token;
+use crate::infrastructure::error::InfraError;
+use crate::infrastructure::event_mapper::GoogleCalendarEvent;
+use crate::infrastructure::google_calendar_client::{
+    GoogleCalendarClient, GoogleCalendarSummary, ListEventsRequest, ListEventsResponse,
+};
+use async_trait::async_trait;
+use std::future::Future;
+use std::sync::{Mutex, MutexGuard};
+
+#[async_trait]
+pub(crate) trait AccessTokenRefresher: Send + Sync {
+    async fn refresh_access_token(&self) -> Result<Option<String>, InfraError>;
+}
+
+pub(crate) struct AccountTokenRefresher {
+    account_id: String,
+}
+
+impl AccountTokenRefresher {
+    pub(crate) fn new(account_id: impl Into<String>) -> Self {
+        Self {
+            account_id: account_id.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl AccessTokenRefresher for AccountTokenRefresher {
+    async fn refresh_access_token(&self) -> Result<Option<String>, InfraError> {
+        refreshed_access_token(&self.account_id).await
+    }
+}
+
+/// Calendar client that refreshes the access token and retries once when Google answers 401,
+/// so a token expiring between lookup and use does not fail the whole command. The refreshed
+/// token replaces the caller's token for later calls through the same client.
+pub(crate) struct ReauthCalendarClient<C, R> {
+    inner: C,
+    refresher: R,
+    refreshed_token: Mutex<Option<String>>,
+}
+
+impl<C, R> ReauthCalendarClient<C, R>
+where
+    C: GoogleCalendarClient,
+    R: AccessTokenRefresher,
+{
+    pub(crate) fn new(inner: C, refresher: R) -> Self {
+        Self {
+            inner,
+            refresher,
+            refreshed_token: Mutex::new(None),
+        }
+    }
+
+    fn refreshed_token(&self) -> Result<MutexGuard<'_, Option<String>>, InfraError> {
+        self.refreshed_token
+            .lock()
+            .map_err(|error| InfraError::OAuth(format!("refreshed token lock poisoned: {error}")))
+    }
+
+    async fn with_reauth<T, F, Fut>(
+        &self,
+        access_token: &str,
+        operation: F,
+    ) -> Result<T, InfraError>
+    where
+        F: Fn(String) -> Fut + Send,
+        Fut: Future<Output = Result<T, InfraError>> + Send,
+        T: Send,
+    {
+        let access_token = self
+            .refreshed_token()?
+            .clone()
+            .unwrap_or_else(|| access_token.to_string());
+        match operation(access_token).await {
+            Err(InfraError::Unauthorized(message)) => {
+                let Some(refreshed) = self.refresher.refresh_access_token().await? else {
+                    return Err(InfraError::Unauthorized(message));
+                };
+                *self.refreshed_token()? = Some(refreshed.clone());
+                operation(refreshed).await
+            }
+            result => result,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, R> GoogleCalendarClient for ReauthCalendarClient<C, R>
+where
+    C: GoogleCalendarClient,
+    R: AccessTokenRefresher,
+{
+    async fn list_calendars(
+        &self,
+        access_token: &str,
+    ) -> Result<Vec<GoogleCalendarSummary>, InfraError> {
+        self.with_reauth(access_token, |token| async move {
+            self.inner.list_calendars(&token).await
+        })
+        .await
+    }
+
+    async fn create_calendar(
+        &self,
+        access_token: &str,
+        summary: &str,
+        time_zone: Option<&str>,
+    ) -> Result<GoogleCalendarSummary, InfraError> {
+        self.with_reauth(access_token, |token| async move {
+            self.inner.create_calendar(&token, summary, time_zone).await
+        })
+        .await
+    }
+
+    async fn get_calendar_timezone(
+        &self,
+        access_token: &str,
+        calendar_id: &str,
+    ) -> Result<Option<String>, InfraError> {
+        self.with_reauth(access_token, |token| async move {
+            self.inner.get_calendar_timezone(&token, calendar_id).await
+        })
+        .await
+    }
+
+    async fn list_events(
+        &self,
+        access_token: &str,
+        calendar_id: &str,
+        request: ListEventsRequest,
+    ) -> Result<ListEventsResponse, InfraError> {
+        let request = &request;
+        self.with_reauth(access_token, |token| async move {
+            self.inner
+                .list_events(&token, calendar_id, request.clone())
+                .await
+        })
+        .await
+    }
+
+    #[cfg(any(test, feature = "debug-tools"))]
+    async fn get_event(
+        &self,
+        access_token: &str,
+        calendar_id: &str,
+        event_id: &str,
+    ) -> Result<Option<GoogleCalendarEvent>, InfraError> {
+        self.with_reauth(access_token, |token| async move {
+            self.inner.get_event(&token, calendar_id, event_id).await
+        })
+        .await
+    }
+
+    async fn create_event(
+        &self,
+        access_token: &str,
+        calendar_id: &str,
+        event: &GoogleCalendarEvent,
+    ) -> Result<String, InfraError> {
+        self.with_reauth(access_token, |token| async move {
+            self.inner.create_event(&token, calendar_id, event).await
+        })
+        .await
+    }
+
+    async fn update_event(
+        &self,
+        access_token: &str,
+        calendar_id: &str,
+        event_id: &str,
+        event: &GoogleCalendarEvent,
+    ) -> Result<(), InfraError> {
+        self.with_reauth(access_token, |token| async move {
+            self.inner
+                .update_event(&token, calendar_id, event_id, event)
+                .await
+        })
+        .await
+    }
+
+    async fn delete_event(
+        &self,
+        access_token: &str,
+        calendar_id: &str,
+        event_id: &str,
+    ) -> Result<(), InfraError> {
+        self.with_reauth(access_token, |token| async move {
+            self.inner.delete_event(&token, calendar_id, event_id).await
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::event_mapper::CalendarEventDateTime;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Rejects `expired-token` with 401 and records the tokens events were created with.
+    #[derive(Debug, Default)]
+    struct ExpiringTokenCalendarClient {
+        create_tokens: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl GoogleCalendarClient for ExpiringTokenCalendarClient {
+        async fn list_calendars(
+            &self,
+            _access_token: &str,
+        ) -> Result<Vec<GoogleCalendarSummary>, InfraError> {
+            Ok(Vec::new())
+        }
+
+        async fn create_calendar(
+            &self,
+            _access_token: &str,
+            _summary: &str,
+            _time_zone: Option<&str>,
+        ) -> Result<GoogleCalendarSummary, InfraError> {
+            Err(InfraError::OAuth("not implemented in fake".to_string()))
+        }
+
+        async fn get_calendar_timezone(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+        ) -> Result<Option<String>, InfraError> {
+            Ok(None)
+        }
+
+        async fn list_events(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+            _request: ListEventsRequest,
+        ) -> Result<ListEventsResponse, InfraError> {
+            Err(InfraError::OAuth("not implemented in fake".to_string()))
+        }
+
+        async fn get_event(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+            _event_id: &str,
+        ) -> Result<Option<GoogleCalendarEvent>, InfraError> {
+            Ok(None)
+        }
+
+        async fn create_event(
+            &self,
+            access_token: &str,
+            _calendar_id: &str,
+            _event: &GoogleCalendarEvent,
+        ) -> Result<String, InfraError> {
+            self.create_tokens
+                .lock()
+                .expect("create tokens lock")
+                .push(access_token.to_string());
+            if access_token == "expired-token" {
+                return Err(InfraError::Unauthorized(
+                    "google calendar api error: http 401".to_string(),
+                ));
+            }
+            Ok("evt-created".to_string())
+        }
+
+        async fn update_event(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+            _event_id: &str,
+            _event: &GoogleCalendarEvent,
+        ) -> Result<(), InfraError> {
+            Ok(())
+        }
+
+        async fn delete_event(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+            _event_id: &str,
+        ) -> Result<(), InfraError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct CountingRefresher {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl AccessTokenRefresher for CountingRefresher {
+        async fn refresh_access_token(&self) -> Result<Option<String>, InfraError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(Some("fresh-token".to_string()))
+        }
+    }
+
+    fn sample_event() -> GoogleCalendarEvent {
+        GoogleCalendarEvent {
+            id: None,
+            summary: Some("Focus".to_string()),
+            description: None,
+            status: None,
+            updated: None,
+            etag: None,
+            start: CalendarEventDateTime {
+                date_time: "2026-02-16T09:00:00Z".to_string(),
+                time_zone: None,
+            },
+            end: CalendarEventDateTime {
+                date_time: "2026-02-16T10:00:00Z".to_string(),
+                time_zone: None,
+            },
+            extended_properties: None,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn unauthorized_call_is_retried_once_with_a_refreshed_token() {
+        let client = ReauthCalendarClient::new(
+            ExpiringTokenCalendarClient::default(),
+            CountingRefresher::default(),
+        );
+
+        let event_id = client
+            .create_event("expired-token", "blocks-calendar", &sample_event())
+            .await
+            .expect("create event after refresh");
+
+        assert_eq!(event_id, "evt-created");
+        assert_eq!(client.refresher.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            *client.inner.create_tokens.lock().expect("create tokens lock"),
+            vec!["expired-token".to_string(), "fresh-token".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn refreshed_token_is_reused_for_later_calls() {
+        let client = ReauthCalendarClient::new(
+            ExpiringTokenCalendarClient::default(),
+            CountingRefresher::default(),
+        );
+
+        for _ in 0..2 {
+            client
+                .create_event("expired-token", "blocks-calendar", &sample_event())
+                .await
+                .expect("create event with expired token");
+        }
+
+        assert_eq!(client.refresher.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            *client.inner.create_tokens.lock().expect("create tokens lock"),
+            vec![
+                "expired-token".to_string(),
+                "fresh-token".to_string(),
+                "fresh-token".to_string(),
+            ]
+        );
+    }
+}
@@ -1,8 +1,12 @@
-use crate::application::commands::{lock_runtime, AppState, RuntimeState, DEFAULT_ACCOUNT_ID};
+use crate::application::commands::{
+    lock_runtime, AppState, RelocatedBlockResponse, RuntimeState, DEFAULT_ACCOUNT_ID,
+};
+use crate::application::policy_service::{load_runtime_policy, RuntimePolicy};
 use crate::application::time_slots::{intervals_overlap, Interval};
+use crate::domain::models::Firmness;
 use crate::infrastructure::error::InfraError;
 use crate::infrastructure::event_mapper::GoogleCalendarEvent;
-use chrono::{NaiveDate, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use rusqlite::{params, Connection};
 use std::collections::HashSet;
 use std::path::Path;
@@ -12,9 +16,10 @@ pub(crate) fn save_suppression(
     database_path: &Path,
     instance: &str,
     reason: Option<&str>,
+    expires_at: Option<DateTime<Utc>>,
 ) -> Result<(), InfraError> {
     let single = vec![instance.to_string()];
-    let _ = save_suppressions(database_path, &single, reason)?;
+    let _ = save_suppressions(database_path, &single, reason, expires_at)?;
     Ok(())
 }
 
@@ -63,6 +68,7 @@ pub(crate) fn save_suppressions(
     database_path: &Path,
     instances: &[String],
     reason: Option<&str>,
+    expires_at: Option<DateTime<Utc>>,
 ) -> Result<usize, InfraError> {
     let mut connection = Connection::open(database_path)?;
     let transaction = connection.transaction()?;
@@ -71,6 +77,7 @@ pub(crate) fn save_suppressions(
         .filter(|value| !value.is_empty())
         .map(ToOwned::to_owned);
     let suppressed_at = Utc::now().to_rfc3339();
+    let expires_at = expires_at.map(|value| value.to_rfc3339());
     let mut seen = HashSet::new();
     let mut saved = 0usize;
 
@@ -84,12 +91,18 @@ pub(crate) fn save_suppressions(
         }
 
         transaction.execute(
-            "INSERT INTO suppressions (instance, suppressed_at, reason)
-             VALUES (?1, ?2, ?3)
+            "INSERT INTO suppressions (instance, suppressed_at, reason, expires_at)
+             VALUES (?1, ?2, ?3, ?4)
              ON CONFLICT(instance) DO UPDATE SET
                suppressed_at = excluded.suppressed_at,
-               reason = excluded.reason",
-            params![normalized_instance, suppressed_at, normalized_reason.as_deref()],
+               reason = excluded.reason,
+               expires_at = excluded.expires_at",
+            params![
+                normalized_instance,
+                suppressed_at,
+                normalized_reason.as_deref(),
+                expires_at.as_deref()
+            ],
         )?;
         saved = saved.saturating_add(1);
     }
@@ -98,14 +111,55 @@ pub(crate) fn save_suppressions(
     Ok(saved)
 }
 
+/// Instances of blocks the user deleted, from live `user_deleted` suppressions.
+pub(crate) fn load_user_deleted_instances(
+    database_path: &Path,
+) -> Result<HashSet<String>, InfraError> {
+    let connection = Connection::open(database_path)?;
+    let mut statement =
+        connection.prepare("SELECT instance, reason, expires_at FROM suppressions")?;
+    let mut rows = statement.query([])?;
+    let mut instances = HashSet::new();
+    let now = Utc::now();
+
+    while let Some(row) = rows.next()? {
+        let instance: String = row.get(0)?;
+        let reason: Option<String> = row.get(1)?;
+        let expires_at: Option<String> = row.get(2)?;
+        if reason.as_deref().map(str::trim) != Some("user_deleted") {
+            continue;
+        }
+        let is_expired = expires_at
+            .as_deref()
+            .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+            .is_some_and(|value| value.with_timezone(&Utc) <= now);
+        let normalized = instance.trim();
+        if is_expired || normalized.is_empty() {
+            continue;
+        }
+        instances.insert(normalized.to_string());
+    }
+
+    Ok(instances)
+}
+
 pub(crate) fn load_suppressions(database_path: &Path) -> Result<HashSet<String>, InfraError> {
     let connection = Connection::open(database_path)?;
-    let mut statement = connection.prepare("SELECT instance FROM suppressions")?;
+    let mut statement = connection.prepare("SELECT instance, expires_at FROM suppressions")?;
     let mut rows = statement.query([])?;
     let mut suppressions = HashSet::new();
+    let now = Utc::now();
 
     while let Some(row) = rows.next()? {
         let instance: String = row.get(0)?;
+        let expires_at: Option<String> = row.get(1)?;
+        let is_expired = expires_at
+            .as_deref()
+            .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+            .is_some_and(|value| value.with_timezone(&Utc) <= now);
+        if is_expired {
+            continue;
+        }
         let normalized = instance.trim();
         if normalized.is_empty() {
             continue;
@@ -121,7 +175,7 @@ pub(crate) async fn auto_relocate_after_sync(
     account_id: &str,
     changed_intervals: &[Interval],
     max_relocations_per_sync: u32,
-) -> Result<usize, InfraError> {
+) -> Result<Vec<RelocatedBlockResponse>, InfraError> {
     let started_at = Instant::now();
     let account_id = account_id.trim();
     if account_id.is_empty() || changed_intervals.is_empty() || max_relocations_per_sync == 0 {
@@ -133,7 +187,7 @@ pub(crate) async fn auto_relocate_after_sync(
                 max_relocations_per_sync
             ),
         );
-        return Ok(0);
+        return Ok(Vec::new());
     }
 
     let block_ids = {
@@ -146,36 +200,60 @@ pub(crate) async fn auto_relocate_after_sync(
         )
     };
 
+    // Blocks about to start stay put so an event the user is still editing does not
+    // reshuffle the plan right in front of them.
+    let now = Utc::now();
+    let grace_minutes = load_runtime_policy(state.config_dir()).relocation_grace_minutes;
+    let imminent_until = now + Duration::minutes(i64::from(grace_minutes));
     let candidate_block_count = block_ids.len();
-    let mut relocated_count = 0usize;
+    let mut deferred_count = 0usize;
+    let mut relocated = Vec::new();
     for block_id in block_ids {
-        if relocated_count >= max_relocations_per_sync as usize {
+        if relocated.len() >= max_relocations_per_sync as usize {
             break;
         }
-        if crate::application::block_operations::relocate_if_needed(
+        let old_start = {
+            let runtime = lock_runtime(state)?;
+            runtime
+                .blocks
+                .get(block_id.as_str())
+                .map(|stored| stored.block.start_at)
+        };
+        let Some(old_start) = old_start else {
+            continue;
+        };
+        if old_start >= now && old_start < imminent_until {
+            deferred_count += 1;
+            continue;
+        }
+        if let Some(block) = crate::application::block_operations::relocate_if_needed(
             state,
             block_id,
             Some(account_id.to_string()),
         )
         .await?
-        .is_some()
         {
-            relocated_count = relocated_count.saturating_add(1);
+            relocated.push(RelocatedBlockResponse {
+                block_id: block.id,
+                old_start: old_start.to_rfc3339(),
+                new_start: block.start_at.to_rfc3339(),
+            });
         }
     }
 
     state.log_info(
         "auto_relocate_after_sync",
         &format!(
-            "candidate_block_count={} relocated_count={} elapsed_ms={} limit={}",
+            "candidate_block_count={} relocated_count={} deferred_imminent={} elapsed_ms={} limit={}",
             candidate_block_count,
-            relocated_count,
+            relocated.len(),
+            deferred_count,
             started_at.elapsed().as_millis(),
             max_relocations_per_sync
         ),
     );
 
-    Ok(relocated_count)
+    Ok(relocated)
 }
 
 pub(crate) fn is_cancelled_event(event: &GoogleCalendarEvent) -> bool {
@@ -186,6 +264,22 @@ pub(crate) fn is_cancelled_event(event: &GoogleCalendarEvent) -> bool {
         .unwrap_or(false)
 }
 
+/// Whether an event leaves its time free: cancelled, or declined/tentative when the
+/// policy says to ignore those responses.
+pub(crate) fn is_non_blocking_event(event: &GoogleCalendarEvent, policy: &RuntimePolicy) -> bool {
+    if is_cancelled_event(event) {
+        return true;
+    }
+    let response = event.self_response_status();
+    let declined = response.is_some_and(|status| status.eq_ignore_ascii_case("declined"));
+    let tentative = response.is_some_and(|status| status.eq_ignore_ascii_case("tentative"))
+        || event
+            .status
+            .as_deref()
+            .is_some_and(|status| status.eq_ignore_ascii_case("tentative"));
+    (policy.ignore_declined_events && declined) || (policy.ignore_tentative_events && tentative)
+}
+
 fn instance_matches_date(instance: &str, date_key: &str) -> bool {
     if instance.is_empty() || date_key.is_empty() {
         return false;
@@ -213,7 +307,7 @@ pub(crate) fn collect_relocation_target_block_ids(
                 .map(str::trim)
                 .filter(|value| !value.is_empty())
                 .unwrap_or(DEFAULT_ACCOUNT_ID);
-            if block_account != account_id {
+            if block_account != account_id || stored.block.firmness == Firmness::Hard {
                 return false;
             }
 
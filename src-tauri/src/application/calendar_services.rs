@@ -1,24 +1,37 @@
+use crate::application::calendar_reauth::{AccountTokenRefresher, ReauthCalendarClient};
 use crate::application::calendar_sync::CalendarSyncService;
 use crate::application::commands::{ensure_blocks_calendar_id, AppState};
-use crate::infrastructure::calendar_cache::InMemoryCalendarCacheRepository;
+use crate::infrastructure::calendar_cache::SqliteCalendarCacheRepository;
 use crate::infrastructure::error::InfraError;
 use crate::infrastructure::google_calendar_client::ReqwestGoogleCalendarClient;
 use crate::infrastructure::sync_state_repository::SqliteSyncStateRepository;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+pub(crate) type ReqwestReauthCalendarClient =
+    ReauthCalendarClient<ReqwestGoogleCalendarClient, AccountTokenRefresher>;
+
 pub(crate) type ReqwestCalendarSyncService = CalendarSyncService<
-    ReqwestGoogleCalendarClient,
+    ReqwestReauthCalendarClient,
     SqliteSyncStateRepository,
-    InMemoryCalendarCacheRepository,
+    SqliteCalendarCacheRepository,
 >;
 
+pub(crate) fn build_reqwest_calendar_client(account_id: &str) -> Arc<ReqwestReauthCalendarClient> {
+    Arc::new(ReauthCalendarClient::new(
+        ReqwestGoogleCalendarClient::new(),
+        AccountTokenRefresher::new(account_id),
+    ))
+}
+
 pub(crate) fn build_reqwest_calendar_sync_service(
     state: &AppState,
+    account_id: &str,
 ) -> ReqwestCalendarSyncService {
-    let calendar_client = Arc::new(ReqwestGoogleCalendarClient::new());
-    let sync_state_repo = Arc::new(SqliteSyncStateRepository::new(state.database_path()));
-    CalendarSyncService::new(calendar_client, sync_state_repo, state.calendar_cache())
+    let calendar_client = build_reqwest_calendar_client(account_id);
+    let sync_state_repo =
+        Arc::new(SqliteSyncStateRepository::new(state.database_path(), account_id));
+    CalendarSyncService::new(calendar_client, sync_state_repo, state.calendar_cache(account_id))
 }
 
 pub(crate) async fn ensure_blocks_calendar_for_account(
@@ -26,7 +39,7 @@ pub(crate) async fn ensure_blocks_calendar_for_account(
     access_token: &str,
     account_id: &str,
 ) -> Result<String, InfraError> {
-    let calendar_client = Arc::new(ReqwestGoogleCalendarClient::new());
+    let calendar_client = build_reqwest_calendar_client(account_id);
     ensure_blocks_calendar_id(state.config_dir(), access_token, calendar_client, account_id).await
 }
 
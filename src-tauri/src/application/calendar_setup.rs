@@ -1,12 +1,16 @@
+use crate::application::policy_service::canonical_timezone_name;
 use crate::infrastructure::config::{
     ensure_default_configs, read_blocks_calendar_id, read_blocks_calendar_name, read_timezone,
     save_blocks_calendar_id,
 };
 use crate::infrastructure::error::InfraError;
 use crate::infrastructure::google_calendar_client::GoogleCalendarClient;
+use chrono_tz::Tz;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+const PRIMARY_CALENDAR_ALIAS: &str = "primary";
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EnsureBlocksCalendarResult {
     Reused(String),
@@ -55,7 +59,7 @@ where
         let calendars = self.calendar_client.list_calendars(access_token).await?;
         if let Some(existing) = calendars
             .into_iter()
-            .find(|calendar| calendar.summary == calendar_name)
+            .find(|calendar| calendar.summary == calendar_name && calendar.is_writable())
         {
             save_blocks_calendar_id(&self.config_dir, &self.account_id, &existing.id)?;
             return Ok(EnsureBlocksCalendarResult::LinkedExisting(existing.id));
@@ -68,6 +72,56 @@ where
         save_blocks_calendar_id(&self.config_dir, &self.account_id, &created.id)?;
         Ok(EnsureBlocksCalendarResult::Created(created.id))
     }
+
+    pub async fn select_blocks_calendar(
+        &self,
+        access_token: &str,
+        calendar_id: &str,
+    ) -> Result<String, InfraError> {
+        let calendar_id = calendar_id.trim();
+        if calendar_id.is_empty() {
+            return Err(InfraError::InvalidConfig(
+                "calendar_id must not be empty".to_string(),
+            ));
+        }
+        ensure_default_configs(&self.config_dir)?;
+
+        if calendar_id != PRIMARY_CALENDAR_ALIAS {
+            let calendars = self.calendar_client.list_calendars(access_token).await?;
+            let Some(selected) = calendars
+                .into_iter()
+                .find(|calendar| calendar.id == calendar_id)
+            else {
+                return Err(InfraError::InvalidConfig(format!(
+                    "calendar not found: {calendar_id}"
+                )));
+            };
+            if !selected.is_writable() {
+                return Err(InfraError::InvalidConfig(format!(
+                    "calendar {calendar_id} is not writable (access role: {})",
+                    selected.access_role.as_deref().unwrap_or("unknown")
+                )));
+            }
+        }
+
+        save_blocks_calendar_id(&self.config_dir, &self.account_id, calendar_id)?;
+        Ok(calendar_id.to_string())
+    }
+
+    pub async fn calendar_timezone(
+        &self,
+        access_token: &str,
+        calendar_id: &str,
+    ) -> Result<Option<String>, InfraError> {
+        self.calendar_client
+            .get_calendar_timezone(access_token, calendar_id)
+            .await
+    }
+}
+
+/// Whether a calendar's timezone resolves to a different zone than the policy timezone.
+pub(crate) fn timezone_differs(calendar_timezone: &str, policy_timezone: Tz) -> bool {
+    canonical_timezone_name(calendar_timezone).as_deref() != Some(policy_timezone.name())
 }
 
 #[cfg(test)]
@@ -91,6 +145,7 @@ mod tests {
         create_calls: AtomicUsize,
         last_create_summary: Mutex<Option<String>>,
         last_create_timezone: Mutex<Option<Option<String>>>,
+        calendar_timezone: Mutex<Option<String>>,
     }
 
     impl FakeGoogleCalendarClient {
@@ -149,10 +204,23 @@ mod tests {
                 .unwrap_or_else(|| GoogleCalendarSummary {
                     id: "created-id".to_string(),
                     summary: summary.to_string(),
+                    access_role: None,
                 });
             Ok(created)
         }
 
+        async fn get_calendar_timezone(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+        ) -> Result<Option<String>, InfraError> {
+            Ok(self
+                .calendar_timezone
+                .lock()
+                .expect("calendar timezone mutex poisoned")
+                .clone())
+        }
+
         async fn list_events(
             &self,
             _access_token: &str,
@@ -162,6 +230,15 @@ mod tests {
             Err(InfraError::OAuth("not used in calendar_setup tests".to_string()))
         }
 
+        async fn get_event(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+            _event_id: &str,
+        ) -> Result<Option<GoogleCalendarEvent>, InfraError> {
+            Ok(None)
+        }
+
         async fn create_event(
             &self,
             _access_token: &str,
@@ -215,10 +292,12 @@ mod tests {
             GoogleCalendarSummary {
                 id: "other".to_string(),
                 summary: "Personal".to_string(),
+                access_role: None,
             },
             GoogleCalendarSummary {
                 id: "blocks-existing".to_string(),
                 summary: "Blocks".to_string(),
+                access_role: None,
             },
         ]));
         let initializer = BlocksCalendarInitializer::new(temp.path(), "default", Arc::clone(&client));
@@ -246,6 +325,7 @@ mod tests {
         client.set_create_response(GoogleCalendarSummary {
             id: "new-blocks-id".to_string(),
             summary: "Blocks".to_string(),
+            access_role: None,
         });
 
         let initializer = BlocksCalendarInitializer::new(temp.path(), "default", Arc::clone(&client));
@@ -272,4 +352,77 @@ mod tests {
             Some("Blocks".to_string())
         );
     }
+
+    #[tokio::test]
+    async fn select_blocks_calendar_rejects_read_only_calendar() {
+        let temp = TempConfigDir::with_default_configs("calendar", "select-reader");
+        let client = Arc::new(FakeGoogleCalendarClient::with_list_response(vec![
+            GoogleCalendarSummary {
+                id: "team-shared".to_string(),
+                summary: "Team".to_string(),
+                access_role: Some("reader".to_string()),
+            },
+            GoogleCalendarSummary {
+                id: "writable".to_string(),
+                summary: "Work".to_string(),
+                access_role: Some("writer".to_string()),
+            },
+        ]));
+        let initializer = BlocksCalendarInitializer::new(temp.path(), "default", Arc::clone(&client));
+
+        let rejected = initializer
+            .select_blocks_calendar("access-token", "team-shared")
+            .await;
+        assert!(matches!(rejected, Err(InfraError::InvalidConfig(message)) if message.contains("reader")));
+        assert_eq!(
+            read_blocks_calendar_id(temp.path(), "default").expect("read id"),
+            None
+        );
+
+        let selected = initializer
+            .select_blocks_calendar("access-token", "writable")
+            .await
+            .expect("select writable calendar");
+        assert_eq!(selected, "writable");
+        assert_eq!(
+            read_blocks_calendar_id(temp.path(), "default").expect("read id"),
+            Some("writable".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn select_blocks_calendar_accepts_primary_alias_without_listing() {
+        let temp = TempConfigDir::with_default_configs("calendar", "select-primary");
+        let client = Arc::new(FakeGoogleCalendarClient::default());
+        let initializer = BlocksCalendarInitializer::new(temp.path(), "default", Arc::clone(&client));
+
+        let selected = initializer
+            .select_blocks_calendar("access-token", "primary")
+            .await
+            .expect("select primary");
+
+        assert_eq!(selected, "primary");
+        assert_eq!(client.list_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn calendar_timezone_is_read_from_the_calendar_resource() {
+        let temp = TempConfigDir::with_default_configs("calendar", "timezone");
+        let client = Arc::new(FakeGoogleCalendarClient {
+            calendar_timezone: Mutex::new(Some("America/New_York".to_string())),
+            ..FakeGoogleCalendarClient::default()
+        });
+        let initializer = BlocksCalendarInitializer::new(temp.path(), "default", Arc::clone(&client));
+
+        let timezone = initializer
+            .calendar_timezone("access-token", "blocks")
+            .await
+            .expect("calendar timezone")
+            .expect("timezone present");
+
+        assert_eq!(timezone, "America/New_York");
+        assert!(timezone_differs(&timezone, Tz::Asia__Tokyo));
+        assert!(!timezone_differs(&timezone, Tz::America__New_York));
+        assert!(!timezone_differs("US/Eastern", Tz::America__New_York));
+    }
 }
@@ -34,6 +34,14 @@ pub struct SyncResult {
     pub deleted: Vec<String>,
     pub suppressed_instances: Vec<String>,
     pub next_sync_token: Option<String>,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone)]
+struct CollectedEvents {
+    events: Vec<GoogleCalendarEvent>,
+    next_sync_token: Option<String>,
+    truncated: bool,
 }
 
 pub struct CalendarSyncService<C, S, R>
@@ -75,6 +83,7 @@ where
         calendar_id: &str,
         time_min: DateTime<Utc>,
         time_max: DateTime<Utc>,
+        max_events: Option<usize>,
     ) -> Result<SyncResult, InfraError> {
         let previous_state = self.sync_state_repository.load()?;
         let previous_sync_token = previous_state.and_then(|state| state.sync_token);
@@ -83,22 +92,25 @@ where
             time_min: Some(time_min),
             time_max: Some(time_max),
             sync_token: previous_sync_token.clone(),
+            page_token: None,
         };
 
         let response = match self
-            .list_events_with_retry(access_token, calendar_id, initial_request)
+            .collect_events(access_token, calendar_id, initial_request, max_events)
             .await
         {
             Ok(response) => response,
             Err(InfraError::SyncTokenExpired) if previous_sync_token.is_some() => {
-                self.list_events_with_retry(
+                self.collect_events(
                     access_token,
                     calendar_id,
                     ListEventsRequest {
                         time_min: Some(time_min),
                         time_max: Some(time_max),
                         sync_token: None,
+                        page_token: None,
                     },
+                    max_events,
                 )
                 .await?
             }
@@ -115,6 +127,7 @@ where
             deleted: sync_result.deleted,
             suppressed_instances: sync_result.suppressed_instances,
             next_sync_token: response.next_sync_token,
+            truncated: response.truncated,
         })
     }
 
@@ -124,21 +137,36 @@ where
         calendar_id: &str,
         time_min: DateTime<Utc>,
         time_max: DateTime<Utc>,
+        max_events: Option<usize>,
     ) -> Result<Vec<GoogleCalendarEvent>, InfraError> {
         let response = self
-            .list_events_with_retry(
+            .collect_events(
                 access_token,
                 calendar_id,
                 ListEventsRequest {
                     time_min: Some(time_min),
                     time_max: Some(time_max),
                     sync_token: None,
+                    page_token: None,
                 },
+                max_events,
             )
             .await?;
         Ok(response.events)
     }
 
+    #[cfg(any(test, feature = "debug-tools"))]
+    pub async fn get_event(
+        &self,
+        access_token: &str,
+        calendar_id: &str,
+        event_id: &str,
+    ) -> Result<Option<GoogleCalendarEvent>, InfraError> {
+        self.calendar_client
+            .get_event(access_token, calendar_id, event_id)
+            .await
+    }
+
     pub async fn create_event(
         &self,
         access_token: &str,
@@ -186,6 +214,53 @@ where
         Ok(())
     }
 
+    async fn collect_events(
+        &self,
+        access_token: &str,
+        calendar_id: &str,
+        mut request: ListEventsRequest,
+        max_events: Option<usize>,
+    ) -> Result<CollectedEvents, InfraError> {
+        let mut collected = CollectedEvents {
+            events: Vec::new(),
+            next_sync_token: None,
+            truncated: false,
+        };
+
+        let mut page_count = 0usize;
+        loop {
+            let response = self
+                .list_events_with_retry(access_token, calendar_id, request.clone())
+                .await?;
+            page_count += 1;
+            collected.events.extend(response.events);
+            // Google only hands out nextSyncToken on the last page of a listing.
+            if response.next_page_token.is_none() {
+                collected.next_sync_token = response.next_sync_token;
+            }
+
+            if let Some(limit) = max_events.filter(|limit| collected.events.len() >= *limit) {
+                collected.truncated =
+                    collected.events.len() > limit || response.next_page_token.is_some();
+                collected.events.truncate(limit);
+                if collected.truncated {
+                    collected.next_sync_token = None;
+                }
+                return Ok(collected);
+            }
+
+            match response.next_page_token {
+                Some(page_token) => request.page_token = Some(page_token),
+                None if page_count > 1 && collected.next_sync_token.is_none() => {
+                    return Err(InfraError::OAuth(format!(
+                        "events listing ended after {page_count} pages without nextSyncToken"
+                    )));
+                }
+                None => return Ok(collected),
+            }
+        }
+    }
+
     async fn list_events_with_retry(
         &self,
         access_token: &str,
@@ -305,6 +380,14 @@ mod tests {
             Err(InfraError::OAuth("not implemented in fake".to_string()))
         }
 
+        async fn get_calendar_timezone(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+        ) -> Result<Option<String>, InfraError> {
+            Ok(None)
+        }
+
         async fn list_events(
             &self,
             _access_token: &str,
@@ -322,6 +405,7 @@ mod tests {
                     FakeListResponse::Success(ListEventsResponse {
                         events: Vec::new(),
                         next_sync_token: Some("default-token".to_string()),
+                        next_page_token: None,
                     })
                 });
 
@@ -334,6 +418,15 @@ mod tests {
             }
         }
 
+        async fn get_event(
+            &self,
+            _access_token: &str,
+            _calendar_id: &str,
+            _event_id: &str,
+        ) -> Result<Option<GoogleCalendarEvent>, InfraError> {
+            Ok(None)
+        }
+
         async fn create_event(
             &self,
             _access_token: &str,
@@ -386,6 +479,7 @@ mod tests {
                 time_zone: None,
             },
             extended_properties: None,
+            ..Default::default()
         }
     }
 
@@ -425,6 +519,7 @@ mod tests {
                             ),
                         ],
                         next_sync_token: Some("next-sync".to_string()),
+                        next_page_token: None,
                     })
                 ]));
                 let sync_repo = Arc::new(InMemorySyncStateRepository::default());
@@ -443,7 +538,7 @@ mod tests {
                     RetryPolicy { max_attempts: 1, base_delay_ms: 1 },
                 );
 
-                let result = service.sync("access-token", "primary", fixed_time(), fixed_time()).await.expect("sync success");
+                let result = service.sync("access-token", "primary", fixed_time(), fixed_time(), None).await.expect("sync success");
 
                 assert_eq!(result.updated.len(), 1);
                 assert_eq!(result.deleted, vec!["evt-deleted".to_string()]);
@@ -469,6 +564,7 @@ mod tests {
                     FakeListResponse::Success(ListEventsResponse {
                         events: vec![],
                         next_sync_token: Some(sync_token.clone()),
+                        next_page_token: None,
                     })
                 ]));
                 let sync_repo = Arc::new(InMemorySyncStateRepository::default());
@@ -480,7 +576,7 @@ mod tests {
                     RetryPolicy { max_attempts: 1, base_delay_ms: 1 },
                 );
 
-                let _ = service.sync("access-token", "primary", fixed_time(), fixed_time()).await.expect("sync success");
+                let _ = service.sync("access-token", "primary", fixed_time(), fixed_time(), None).await.expect("sync success");
                 let saved = sync_repo.load().expect("load state").expect("state exists");
 
                 assert_eq!(saved.sync_token, Some(sync_token));
@@ -499,6 +595,7 @@ mod tests {
                     FakeListResponse::Success(ListEventsResponse {
                         events: vec![remote.clone()],
                         next_sync_token: Some("next-sync".to_string()),
+                        next_page_token: None,
                     })
                 ]));
                 let sync_repo = Arc::new(InMemorySyncStateRepository::default());
@@ -510,7 +607,7 @@ mod tests {
                     RetryPolicy { max_attempts: 1, base_delay_ms: 1 },
                 );
 
-                let _ = service.sync("access-token", "primary", fixed_time(), fixed_time()).await.expect("sync success");
+                let _ = service.sync("access-token", "primary", fixed_time(), fixed_time(), None).await.expect("sync success");
                 let cached = cache.get_by_id(&event_id).expect("cache read").expect("cached event exists");
                 assert_eq!(cached, remote);
             });
@@ -524,6 +621,7 @@ mod tests {
             FakeListResponse::Success(ListEventsResponse {
                 events: vec![sample_event("evt-1", "Recovered", "confirmed")],
                 next_sync_token: Some("sync-after-retry".to_string()),
+                next_page_token: None,
             }),
         ]));
         let sync_repo = Arc::new(InMemorySyncStateRepository::default());
@@ -539,7 +637,7 @@ mod tests {
         );
 
         let result = service
-            .sync("access-token", "primary", fixed_time(), fixed_time())
+            .sync("access-token", "primary", fixed_time(), fixed_time(), None)
             .await
             .expect("sync after retry");
 
@@ -554,6 +652,7 @@ mod tests {
             FakeListResponse::Success(ListEventsResponse {
                 events: vec![sample_event("evt-1", "Full Sync", "confirmed")],
                 next_sync_token: Some("fresh-sync-token".to_string()),
+                next_page_token: None,
             }),
         ]));
         let sync_repo = Arc::new(InMemorySyncStateRepository::default());
@@ -572,7 +671,7 @@ mod tests {
         );
 
         let result = service
-            .sync("access-token", "primary", fixed_time(), fixed_time())
+            .sync("access-token", "primary", fixed_time(), fixed_time(), None)
             .await
             .expect("sync should recover");
 
@@ -581,4 +680,127 @@ mod tests {
         let saved_state = sync_repo.load().expect("load state").expect("state exists");
         assert_eq!(saved_state.sync_token, Some("fresh-sync-token".to_string()));
     }
+
+    #[tokio::test]
+    async fn sync_stops_paging_once_max_events_is_reached() {
+        let page = |ids: &[&str], next_page_token: Option<&str>, next_sync_token: Option<&str>| {
+            FakeListResponse::Success(ListEventsResponse {
+                events: ids
+                    .iter()
+                    .map(|id| sample_event(id, "Busy", "confirmed"))
+                    .collect(),
+                next_sync_token: next_sync_token.map(ToOwned::to_owned),
+                next_page_token: next_page_token.map(ToOwned::to_owned),
+            })
+        };
+        let client = Arc::new(FakeGoogleCalendarClient::with_list_responses(vec![
+            page(&["evt-1", "evt-2"], Some("page-2"), None),
+            page(&["evt-3", "evt-4"], Some("page-3"), None),
+            page(&["evt-5"], None, Some("final-sync-token")),
+        ]));
+        let sync_repo = Arc::new(InMemorySyncStateRepository::default());
+        let cache = Arc::new(InMemoryCalendarCacheRepository::default());
+        let service = test_service(
+            Arc::clone(&client),
+            Arc::clone(&sync_repo),
+            cache,
+            RetryPolicy {
+                max_attempts: 1,
+                base_delay_ms: 1,
+            },
+        );
+
+        let result = service
+            .sync("access-token", "primary", fixed_time(), fixed_time(), Some(3))
+            .await
+            .expect("capped sync");
+
+        assert!(result.truncated);
+        assert_eq!(result.added.len(), 3);
+        assert_eq!(result.next_sync_token, None);
+        assert_eq!(client.list_calls.load(Ordering::SeqCst), 2);
+        let saved_state = sync_repo.load().expect("load state").expect("state exists");
+        assert_eq!(saved_state.sync_token, None);
+    }
+
+    #[tokio::test]
+    async fn sync_follows_every_page_without_cap() {
+        let client = Arc::new(FakeGoogleCalendarClient::with_list_responses(vec![
+            FakeListResponse::Success(ListEventsResponse {
+                events: vec![sample_event("evt-1", "First", "confirmed")],
+                next_sync_token: None,
+                next_page_token: Some("page-2".to_string()),
+            }),
+            FakeListResponse::Success(ListEventsResponse {
+                events: vec![sample_event("evt-2", "Second", "confirmed")],
+                next_sync_token: Some("final-sync-token".to_string()),
+                next_page_token: None,
+            }),
+        ]));
+        let sync_repo = Arc::new(InMemorySyncStateRepository::default());
+        let cache = Arc::new(InMemoryCalendarCacheRepository::default());
+        let service = test_service(
+            Arc::clone(&client),
+            Arc::clone(&sync_repo),
+            cache,
+            RetryPolicy {
+                max_attempts: 1,
+                base_delay_ms: 1,
+            },
+        );
+
+        let result = service
+            .sync("access-token", "primary", fixed_time(), fixed_time(), None)
+            .await
+            .expect("full sync");
+
+        assert!(!result.truncated);
+        assert_eq!(result.added.len(), 2);
+        assert_eq!(result.next_sync_token.as_deref(), Some("final-sync-token"));
+        assert_eq!(client.list_calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn sync_saves_only_the_final_page_sync_token() {
+        let page = |id: &str, next_page_token: Option<&str>, next_sync_token: Option<&str>| {
+            FakeListResponse::Success(ListEventsResponse {
+                events: vec![sample_event(id, "Busy", "confirmed")],
+                next_sync_token: next_sync_token.map(ToOwned::to_owned),
+                next_page_token: next_page_token.map(ToOwned::to_owned),
+            })
+        };
+        let client = Arc::new(FakeGoogleCalendarClient::with_list_responses(vec![
+            page("evt-1", Some("page-2"), Some("intermediate-token")),
+            page("evt-2", Some("page-3"), None),
+            page("evt-3", None, Some("final-sync-token")),
+            page("evt-4", Some("page-2"), Some("intermediate-token")),
+            page("evt-5", None, None),
+        ]));
+        let sync_repo = Arc::new(InMemorySyncStateRepository::default());
+        let service = test_service(
+            Arc::clone(&client),
+            Arc::clone(&sync_repo),
+            Arc::new(InMemoryCalendarCacheRepository::default()),
+            RetryPolicy {
+                max_attempts: 1,
+                base_delay_ms: 1,
+            },
+        );
+
+        let result = service
+            .sync("access-token", "primary", fixed_time(), fixed_time(), None)
+            .await
+            .expect("full sync");
+        assert_eq!(result.added.len(), 3);
+        assert_eq!(result.next_sync_token.as_deref(), Some("final-sync-token"));
+        let saved_state = sync_repo.load().expect("load state").expect("state exists");
+        assert_eq!(saved_state.sync_token.as_deref(), Some("final-sync-token"));
+
+        let missing_token = service
+            .sync("access-token", "primary", fixed_time(), fixed_time(), None)
+            .await;
+        assert!(missing_token.is_err());
+        let saved_state = sync_repo.load().expect("load state").expect("state exists");
+        assert_eq!(saved_state.sync_token.as_deref(), Some("final-sync-token"));
+    }
 }
@@ -3,21 +3,26 @@ use crate::application::calendar_setup::{BlocksCalendarInitializer, EnsureBlocks
 use crate::application::id_factory::next_id;
 use crate::application::oauth::{EnsureTokenResult, OAuthConfig, OAuthManager};
 pub(crate) use crate::infrastructure::config::DEFAULT_ACCOUNT_ID;
-use crate::infrastructure::credential_store::WindowsCredentialManagerStore;
+use crate::infrastructure::config::{read_default_account_id, read_sso_timeout_seconds};
+use crate::infrastructure::credential_store::{CredentialStore, WindowsCredentialManagerStore};
 use crate::infrastructure::error::InfraError;
-use crate::infrastructure::google_calendar_client::ReqwestGoogleCalendarClient;
-use crate::infrastructure::oauth_client::ReqwestOAuthClient;
+use crate::infrastructure::google_calendar_client::GoogleCalendarClient;
+use crate::infrastructure::oauth_client::{OAuthHttpClient, ReqwestOAuthClient};
 use serde::Serialize;
 use std::io::{BufRead, BufReader, Write};
 use std::net::TcpListener;
 use std::path::Path;
 use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::{Duration as StdDuration, Instant};
 use url::Url;
 
 const DEFAULT_REDIRECT_URI: &str = "http://127.0.0.1:8080/oauth2/callback";
 const DEFAULT_SCOPE: &str = "https://www.googleapis.com/auth/calendar";
+const READONLY_SCOPE: &str = "https://www.googleapis.com/auth/calendar.readonly";
+const EVENTS_SCOPE: &str = "https://www.googleapis.com/auth/calendar.events";
+const DEFAULT_SSO_TIMEOUT_SECONDS: u64 = 180;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct AuthenticateGoogleResponse {
@@ -29,11 +34,19 @@ pub struct AuthenticateGoogleResponse {
     pub expires_at: Option<String>,
 }
 
-pub(crate) fn normalize_account_id(raw: Option<String>) -> String {
+#[derive(Debug, Clone, Serialize)]
+pub struct AuthorizationUrlResponse {
+    pub account_id: String,
+    pub authorization_url: String,
+    pub state: String,
+}
+
+pub(crate) fn normalize_account_id(config_dir: &Path, raw: Option<String>) -> String {
     raw.as_deref()
         .map(str::trim)
         .filter(|value| !value.is_empty())
         .map(ToOwned::to_owned)
+        .or_else(|| read_default_account_id(config_dir).ok().flatten())
         .unwrap_or_else(|| DEFAULT_ACCOUNT_ID.to_string())
 }
 
@@ -41,10 +54,9 @@ pub async fn authenticate_google_impl(
     state: &AppState,
     account_id: Option<String>,
     authorization_code: Option<String>,
+    oauth_state: Option<String>,
 ) -> Result<AuthenticateGoogleResponse, InfraError> {
-    let account_id = normalize_account_id(account_id);
-    let oauth_config = load_oauth_config_from_env()?;
-    let manager = oauth_manager(oauth_config, &account_id);
+    let account_id = normalize_account_id(state.config_dir(), account_id);
 
     if let Some(raw_code) = authorization_code {
         let code = raw_code.trim();
@@ -53,6 +65,12 @@ pub async fn authenticate_google_impl(
                 "authorization_code must not be empty".to_string(),
             ));
         }
+        let expected_state = state.take_oauth_state(&account_id)?;
+        let returned_state = oauth_state.as_deref().map(str::trim);
+        if expected_state.is_none() || expected_state.as_deref() != returned_state {
+            return Err(InfraError::OAuth("state mismatch".to_string()));
+        }
+        let manager = oauth_manager(load_oauth_config_from_env()?, &account_id);
         let token = manager.authenticate_with_code(code).await?;
         state.log_info(
             "authenticate_google",
@@ -68,6 +86,7 @@ pub async fn authenticate_google_impl(
         });
     }
 
+    let manager = oauth_manager(load_oauth_config_from_env()?, &account_id);
     match manager.ensure_access_token().await? {
         EnsureTokenResult::Existing(token) => Ok(AuthenticateGoogleResponse {
             account_id: account_id.clone(),
@@ -84,6 +103,7 @@ pub async fn authenticate_google_impl(
         EnsureTokenResult::ReauthenticationRequired => {
             let auth_state = next_id("oauth-state");
             let authorization_url = manager.build_authorization_url(&auth_state)?;
+            state.remember_oauth_state(&account_id, &auth_state)?;
             Ok(AuthenticateGoogleResponse {
                 account_id,
                 status: "reauthentication_required".to_string(),
@@ -94,13 +114,41 @@ pub async fn authenticate_google_impl(
     }
 }
 
+pub fn build_authorization_url_impl(
+    state: &AppState,
+    account_id: Option<String>,
+) -> Result<AuthorizationUrlResponse, InfraError> {
+    build_authorization_url_with_config(state, account_id, load_oauth_config_from_env()?)
+}
+
+pub(crate) fn build_authorization_url_with_config(
+    state: &AppState,
+    account_id: Option<String>,
+    oauth_config: OAuthConfig,
+) -> Result<AuthorizationUrlResponse, InfraError> {
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    let manager = oauth_manager(oauth_config, &account_id);
+    let auth_state = next_id("oauth-state");
+    let authorization_url = manager.build_authorization_url(&auth_state)?;
+    state.remember_oauth_state(&account_id, &auth_state)?;
+    state.log_info(
+        "build_authorization_url",
+        &format!("prepared authorization url for account_id={account_id}"),
+    );
+    Ok(AuthorizationUrlResponse {
+        account_id,
+        authorization_url,
+        state: auth_state,
+    })
+}
+
 pub async fn authenticate_google_sso_impl(
     state: &AppState,
     account_id: Option<String>,
     force_reauth: bool,
 ) -> Result<AuthenticateGoogleResponse, InfraError> {
-    let account_id = normalize_account_id(account_id);
-    let oauth_config = load_oauth_config_from_env()?;
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    let mut oauth_config = load_oauth_config_from_env()?;
     let manager = oauth_manager(oauth_config.clone(), &account_id);
 
     if !force_reauth {
@@ -125,22 +173,57 @@ pub async fn authenticate_google_sso_impl(
         }
     }
 
+    let mut redirect = parse_loopback_redirect(&oauth_config.redirect_uri)?;
+    let listener = bind_loopback_listener(&redirect)?;
+    if redirect.port == 0 {
+        redirect.port = listener
+            .local_addr()
+            .map_err(|error| InfraError::OAuth(format!("failed to read callback listener port: {error}")))?
+            .port();
+        oauth_config.redirect_uri = rewrite_redirect_port(&oauth_config.redirect_uri, redirect.port)?;
+    }
+    let manager = oauth_manager(oauth_config, &account_id);
+
+    let timeout_seconds = read_sso_timeout_seconds(state.config_dir())?
+        .unwrap_or(DEFAULT_SSO_TIMEOUT_SECONDS);
+
     let auth_state = next_id("oauth-state");
     let authorization_url = manager.build_authorization_url(&auth_state)?;
+    let cancel_flag = state.begin_sso_attempt()?;
     let callback_task = tokio::task::spawn_blocking(wait_for_loopback_callback(
-        oauth_config.redirect_uri.clone(),
+        listener,
+        redirect,
         auth_state.clone(),
-        StdDuration::from_secs(180),
+        StdDuration::from_secs(timeout_seconds),
+        Arc::clone(&cancel_flag),
     ));
     if let Err(error) = open_system_browser(&authorization_url) {
+        cancel_flag.store(true, Ordering::SeqCst);
         callback_task.abort();
+        state.finish_sso_attempt(&cancel_flag)?;
         return Err(error);
     }
-    let authorization_code = callback_task
+    let callback_result = callback_task
         .await
-        .map_err(|error| InfraError::OAuth(format!("oauth callback task failed: {error}")))??;
+        .map_err(|error| InfraError::OAuth(format!("oauth callback task failed: {error}")));
+    state.finish_sso_attempt(&cancel_flag)?;
+    let authorization_code = callback_result??;
+    complete_sso_sign_in(state, &manager, account_id, &authorization_code).await
+}
 
-    let token = manager.authenticate_with_code(&authorization_code).await?;
+/// Exchanges the code from a finished browser sign-in; the stored token records when the
+/// account was last authenticated.
+pub(crate) async fn complete_sso_sign_in<S, C>(
+    state: &AppState,
+    manager: &OAuthManager<S, C>,
+    account_id: String,
+    authorization_code: &str,
+) -> Result<AuthenticateGoogleResponse, InfraError>
+where
+    S: CredentialStore,
+    C: OAuthHttpClient,
+{
+    let token = manager.authenticate_with_code(authorization_code).await?;
     state.log_info(
         "authenticate_google_sso",
         &format!("completed browser sign-in and stored oauth token for account_id={account_id}"),
@@ -153,14 +236,27 @@ pub async fn authenticate_google_sso_impl(
     })
 }
 
+pub async fn reauthenticate_account_impl(
+    state: &AppState,
+    account_id: String,
+) -> Result<AuthenticateGoogleResponse, InfraError> {
+    let account_id = account_id.trim();
+    if account_id.is_empty() {
+        return Err(InfraError::InvalidConfig(
+            "account_id must not be empty".to_string(),
+        ));
+    }
+    authenticate_google_sso_impl(state, Some(account_id.to_string()), true).await
+}
+
 #[derive(Debug, Clone)]
-struct LoopbackRedirect {
+pub(crate) struct LoopbackRedirect {
     host: String,
     port: u16,
     path: String,
 }
 
-fn parse_loopback_redirect(redirect_uri: &str) -> Result<LoopbackRedirect, InfraError> {
+pub(crate) fn parse_loopback_redirect(redirect_uri: &str) -> Result<LoopbackRedirect, InfraError> {
     let parsed = Url::parse(redirect_uri)
         .map_err(|error| InfraError::InvalidConfig(format!("invalid redirect URI: {error}")))?;
     if parsed.scheme() != "http" {
@@ -196,40 +292,77 @@ fn parse_loopback_redirect(redirect_uri: &str) -> Result<LoopbackRedirect, Infra
     })
 }
 
+fn rewrite_redirect_port(redirect_uri: &str, port: u16) -> Result<String, InfraError> {
+    let mut parsed = Url::parse(redirect_uri)
+        .map_err(|error| InfraError::InvalidConfig(format!("invalid redirect URI: {error}")))?;
+    parsed
+        .set_port(Some(port))
+        .map_err(|_| InfraError::InvalidConfig("redirect URI cannot carry a port".to_string()))?;
+    Ok(parsed.to_string())
+}
+
+pub fn cancel_sso_impl(state: &AppState) -> Result<bool, InfraError> {
+    let cancelled = state.cancel_sso_attempt()?;
+    if cancelled {
+        state.log_info("cancel_sso", "cancelled pending browser sign-in");
+    }
+    Ok(cancelled)
+}
+
 fn wait_for_loopback_callback(
-    redirect_uri: String,
+    listener: TcpListener,
+    redirect: LoopbackRedirect,
     expected_state: String,
     timeout: StdDuration,
+    cancel_flag: Arc<AtomicBool>,
 ) -> impl FnOnce() -> Result<String, InfraError> + Send + 'static {
-    let redirect = parse_loopback_redirect(&redirect_uri);
     move || {
-        let redirect = redirect?;
-        wait_for_loopback_callback_blocking(&redirect, &expected_state, timeout)
+        wait_for_loopback_callback_blocking(
+            listener,
+            &redirect,
+            &expected_state,
+            timeout,
+            &cancel_flag,
+        )
     }
 }
 
-fn wait_for_loopback_callback_blocking(
-    redirect: &LoopbackRedirect,
-    expected_state: &str,
-    timeout: StdDuration,
-) -> Result<String, InfraError> {
+pub(crate) fn bind_loopback_listener(redirect: &LoopbackRedirect) -> Result<TcpListener, InfraError> {
     let bind_host = if redirect.host == "localhost" {
         "127.0.0.1"
     } else {
         redirect.host.as_str()
     };
-    let listener = TcpListener::bind((bind_host, redirect.port)).map_err(|error| {
+    TcpListener::bind((bind_host, redirect.port)).map_err(|error| {
+        if error.kind() == std::io::ErrorKind::AddrInUse {
+            return InfraError::OAuth(format!(
+                "loopback port {} in use; close the other instance",
+                redirect.port
+            ));
+        }
         InfraError::OAuth(format!(
             "failed to bind oauth callback listener at {}:{}: {error}",
             bind_host, redirect.port
         ))
-    })?;
+    })
+}
+
+pub(crate) fn wait_for_loopback_callback_blocking(
+    listener: TcpListener,
+    redirect: &LoopbackRedirect,
+    expected_state: &str,
+    timeout: StdDuration,
+    cancel_flag: &AtomicBool,
+) -> Result<String, InfraError> {
     listener
         .set_nonblocking(true)
         .map_err(|error| InfraError::OAuth(format!("failed to configure callback listener: {error}")))?;
     let deadline = Instant::now() + timeout;
 
     loop {
+        if cancel_flag.load(Ordering::SeqCst) {
+            return Err(InfraError::OAuth("browser sign-in was cancelled".to_string()));
+        }
         match listener.accept() {
             Ok((stream, _)) => {
                 if let Some(result) = parse_callback_request(stream, redirect, expected_state)? {
@@ -450,10 +583,9 @@ fn oauth_manager(
     OAuthManager::new(config, credential_store, oauth_client)
 }
 
-pub(crate) async fn required_access_token(account_id: Option<String>) -> Result<String, InfraError> {
-    let account_id = normalize_account_id(account_id);
+pub(crate) async fn required_access_token(account_id: &str) -> Result<String, InfraError> {
     let oauth_config = load_oauth_config_from_env()?;
-    let manager = oauth_manager(oauth_config, &account_id);
+    let manager = oauth_manager(oauth_config, account_id);
     match manager.ensure_access_token().await? {
         EnsureTokenResult::Existing(token) | EnsureTokenResult::Refreshed(token) => {
             Ok(token.access_token)
@@ -467,15 +599,18 @@ pub(crate) async fn required_access_token(account_id: Option<String>) -> Result<
     }
 }
 
-pub(crate) async fn try_access_token(account_id: Option<String>) -> Result<Option<String>, InfraError> {
-    let account_id = normalize_account_id(account_id);
+pub(crate) async fn try_access_token(account_id: &str) -> Result<Option<String>, InfraError> {
     let oauth_config = match load_oauth_config_from_env() {
         Ok(config) => config,
         Err(InfraError::InvalidConfig(_)) => return Ok(None),
         Err(error) => return Err(error),
     };
 
-    let manager = oauth_manager(oauth_config, &account_id);
+    if !scopes_allow_calendar_writes(&oauth_config.scopes) {
+        return Ok(None);
+    }
+
+    let manager = oauth_manager(oauth_config, account_id);
     match manager.ensure_access_token().await? {
         EnsureTokenResult::Existing(token) | EnsureTokenResult::Refreshed(token) => {
             Ok(Some(token.access_token))
@@ -484,10 +619,25 @@ pub(crate) async fn try_access_token(account_id: Option<String>) -> Result<Optio
     }
 }
 
-pub(crate) async fn ensure_blocks_calendar_id(
+/// Forces a refresh after Google rejected the current token; `None` when that is not possible.
+pub(crate) async fn refreshed_access_token(account_id: &str) -> Result<Option<String>, InfraError> {
+    let oauth_config = match load_oauth_config_from_env() {
+        Ok(config) => config,
+        Err(InfraError::InvalidConfig(_)) => return Ok(None),
+        Err(error) => return Err(error),
+    };
+    match oauth_manager(oauth_config, account_id).force_refresh().await? {
+        EnsureTokenResult::Existing(token) | EnsureTokenResult::Refreshed(token) => {
+            Ok(Some(token.access_token))
+        }
+        EnsureTokenResult::ReauthenticationRequired => Ok(None),
+    }
+}
+
+pub(crate) async fn ensure_blocks_calendar_id<C: GoogleCalendarClient>(
     config_dir: &Path,
     access_token: &str,
-    calendar_client: Arc<ReqwestGoogleCalendarClient>,
+    calendar_client: Arc<C>,
     account_id: &str,
 ) -> Result<String, InfraError> {
     let initializer = BlocksCalendarInitializer::new(config_dir, account_id, calendar_client);
@@ -499,6 +649,21 @@ pub(crate) async fn ensure_blocks_calendar_id(
     })
 }
 
+pub(crate) fn ensure_calendar_write_scopes() -> Result<(), InfraError> {
+    if scopes_allow_calendar_writes(&load_oauth_config_from_env()?.scopes) {
+        return Ok(());
+    }
+    Err(InfraError::OAuth(
+        "google scopes are read-only; use the manage scope profile to write calendars".to_string(),
+    ))
+}
+
+pub(crate) fn scopes_allow_calendar_writes(scopes: &[String]) -> bool {
+    scopes
+        .iter()
+        .any(|scope| scope == DEFAULT_SCOPE || scope == EVENTS_SCOPE)
+}
+
 fn load_oauth_config_from_env() -> Result<OAuthConfig, InfraError> {
     load_oauth_config_from_lookup(|key| std::env::var(key).ok())
 }
@@ -522,10 +687,17 @@ where
         &["POMBLOCK_GOOGLE_REDIRECT_URI", "GOOGLE_REDIRECT_URI"],
     )
     .unwrap_or_else(|| DEFAULT_REDIRECT_URI.to_string());
+    let profile_scopes = optional_lookup_value(
+        &lookup,
+        &["POMBLOCK_GOOGLE_SCOPE_PROFILE", "GOOGLE_SCOPE_PROFILE"],
+    )
+    .map(|profile| scopes_for_profile(&profile))
+    .transpose()?
+    .unwrap_or_else(|| vec![DEFAULT_SCOPE.to_string()]);
     let scopes = optional_lookup_value(&lookup, &["POMBLOCK_GOOGLE_SCOPES", "GOOGLE_SCOPES"])
         .map(|raw| parse_scope_list(&raw))
         .filter(|scopes| !scopes.is_empty())
-        .unwrap_or_else(|| vec![DEFAULT_SCOPE.to_string()]);
+        .unwrap_or(profile_scopes);
 
     Ok(OAuthConfig::new(
         client_id,
@@ -567,6 +739,16 @@ where
     None
 }
 
+fn scopes_for_profile(profile: &str) -> Result<Vec<String>, InfraError> {
+    match profile.trim().to_ascii_lowercase().as_str() {
+        "readonly" => Ok(vec![READONLY_SCOPE.to_string()]),
+        "manage" => Ok(vec![DEFAULT_SCOPE.to_string()]),
+        other => Err(InfraError::InvalidConfig(format!(
+            "unknown google scope profile: {other} (expected readonly or manage)"
+        ))),
+    }
+}
+
 fn parse_scope_list(raw: &str) -> Vec<String> {
     raw.split([',', ' ', '\n', '\t'])
         .map(str::trim)
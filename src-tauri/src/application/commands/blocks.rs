@@ -1,3 +1,6 @@
+pub use crate::application::block_operations::{
+    BusySummary, LocalizedBlock, RelocateBlocksResult, WeekCapacity,
+};
 use crate::application::block_service::BlockService;
 use crate::domain::models::Block;
 use crate::infrastructure::error::InfraError;
@@ -20,6 +23,17 @@ pub async fn generate_one_block_impl(
         .await
 }
 
+pub async fn generate_from_source_impl(
+    state: &super::bootstrap::AppState,
+    date: String,
+    source_id: String,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, InfraError> {
+    BlockService::new(state)
+        .generate_from_source(date, source_id, account_id)
+        .await
+}
+
 pub async fn generate_today_blocks_impl(
     state: &super::bootstrap::AppState,
     account_id: Option<String>,
@@ -27,6 +41,26 @@ pub async fn generate_today_blocks_impl(
     BlockService::new(state).generate_today_blocks(account_id).await
 }
 
+pub async fn run_startup_catch_up_impl(
+    state: &super::bootstrap::AppState,
+    last_run_date: Option<String>,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, InfraError> {
+    BlockService::new(state)
+        .run_startup_catch_up(last_run_date, account_id)
+        .await
+}
+
+pub async fn regenerate_auto_blocks_impl(
+    state: &super::bootstrap::AppState,
+    date: String,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, InfraError> {
+    BlockService::new(state)
+        .regenerate_auto_blocks(date, account_id)
+        .await
+}
+
 pub async fn approve_blocks_impl(
     state: &super::bootstrap::AppState,
     block_ids: Vec<String>,
@@ -37,8 +71,28 @@ pub async fn approve_blocks_impl(
 pub async fn delete_block_impl(
     state: &super::bootstrap::AppState,
     block_id: String,
+    expires_at: Option<String>,
 ) -> Result<bool, InfraError> {
-    BlockService::new(state).delete_block(block_id).await
+    BlockService::new(state).delete_block(block_id, expires_at).await
+}
+
+pub async fn clear_day_impl(
+    state: &super::bootstrap::AppState,
+    date: String,
+    account_id: Option<String>,
+    suppress: bool,
+) -> Result<usize, InfraError> {
+    BlockService::new(state)
+        .clear_day(date, account_id, suppress)
+        .await
+}
+
+pub async fn reset_day_impl(
+    state: &super::bootstrap::AppState,
+    date: String,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, InfraError> {
+    BlockService::new(state).reset_day(date, account_id).await
 }
 
 pub async fn adjust_block_time_impl(
@@ -62,11 +116,135 @@ pub async fn relocate_if_needed_impl(
         .await
 }
 
+pub async fn relocate_blocks_impl(
+    state: &super::bootstrap::AppState,
+    block_ids: Vec<String>,
+    account_id: Option<String>,
+) -> Result<RelocateBlocksResult, InfraError> {
+    BlockService::new(state)
+        .relocate_blocks(block_ids, account_id)
+        .await
+}
+
+pub async fn reschedule_block_impl(
+    state: &super::bootstrap::AppState,
+    block_id: String,
+    account_id: Option<String>,
+) -> Result<Option<Block>, InfraError> {
+    BlockService::new(state)
+        .reschedule_block(block_id, account_id)
+        .await
+}
+
+pub async fn reflow_day_impl(
+    state: &super::bootstrap::AppState,
+    date: String,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, InfraError> {
+    BlockService::new(state).reflow_day(date, account_id).await
+}
+
 pub fn list_blocks_impl(
     state: &super::bootstrap::AppState,
     date: Option<String>,
+    project: Option<String>,
 ) -> Result<Vec<Block>, InfraError> {
-    BlockService::new(state).list_blocks(date)
+    BlockService::new(state).list_blocks(date, project)
+}
+
+pub fn get_blocks_localized_impl(
+    state: &super::bootstrap::AppState,
+    date: Option<String>,
+    project: Option<String>,
+) -> Result<Vec<LocalizedBlock>, InfraError> {
+    BlockService::new(state).get_blocks_localized(date, project)
+}
+
+pub async fn snooze_block_impl(
+    state: &super::bootstrap::AppState,
+    block_id: String,
+    minutes: i64,
+    account_id: Option<String>,
+    allow_outside_window: Option<bool>,
+) -> Result<Block, InfraError> {
+    BlockService::new(state)
+        .snooze_block(
+            block_id,
+            minutes,
+            account_id,
+            allow_outside_window.unwrap_or(false),
+        )
+        .await
+}
+
+pub async fn set_block_project_impl(
+    state: &super::bootstrap::AppState,
+    block_id: String,
+    project: Option<String>,
+) -> Result<Block, InfraError> {
+    BlockService::new(state).set_block_project(block_id, project).await
+}
+
+pub fn get_overlapping_blocks_impl(
+    state: &super::bootstrap::AppState,
+    date: String,
+) -> Result<Vec<(String, String)>, InfraError> {
+    BlockService::new(state).get_overlapping_blocks(date)
+}
+
+pub fn get_busy_summary_impl(
+    state: &super::bootstrap::AppState,
+    date: String,
+    account_id: Option<String>,
+) -> Result<BusySummary, InfraError> {
+    BlockService::new(state).get_busy_summary(date, account_id)
+}
+
+pub fn remaining_capacity_impl(
+    state: &super::bootstrap::AppState,
+    date: String,
+    account_id: Option<String>,
+) -> Result<u32, InfraError> {
+    BlockService::new(state).remaining_capacity(date, account_id)
+}
+
+pub fn get_week_capacity_impl(
+    state: &super::bootstrap::AppState,
+    week_start: String,
+    account_id: Option<String>,
+) -> Result<WeekCapacity, InfraError> {
+    BlockService::new(state).get_week_capacity(week_start, account_id)
+}
+
+pub async fn sync_pending_block_events_impl(
+    state: &super::bootstrap::AppState,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, InfraError> {
+    BlockService::new(state).sync_pending_block_events(account_id).await
+}
+
+pub async fn cleanup_orphaned_events_impl(
+    state: &super::bootstrap::AppState,
+    account_id: Option<String>,
+) -> Result<usize, InfraError> {
+    BlockService::new(state).cleanup_orphaned_events(account_id).await
+}
+
+pub fn export_day_markdown_impl(
+    state: &super::bootstrap::AppState,
+    date: String,
+    account_id: Option<String>,
+) -> Result<String, InfraError> {
+    BlockService::new(state).export_day_markdown(date, account_id)
+}
+
+pub fn export_blocks_ics_impl(
+    state: &super::bootstrap::AppState,
+    start_date: String,
+    end_date: String,
+    path: String,
+) -> Result<usize, InfraError> {
+    BlockService::new(state).export_blocks_ics(start_date, end_date, path)
 }
 
 pub async fn apply_studio_template_to_today_impl(
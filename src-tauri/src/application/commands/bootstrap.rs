@@ -1 +1,19 @@
+use crate::infrastructure::error::InfraError;
+use serde::Serialize;
+
 pub use super::state::AppState;
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct WorkspacePaths {
+    pub config_dir: String,
+    pub database_path: String,
+    pub logs_dir: String,
+}
+
+pub fn get_workspace_paths_impl(state: &AppState) -> Result<WorkspacePaths, InfraError> {
+    Ok(WorkspacePaths {
+        config_dir: state.config_dir().display().to_string(),
+        database_path: state.database_path().display().to_string(),
+        logs_dir: state.logs_dir().display().to_string(),
+    })
+}
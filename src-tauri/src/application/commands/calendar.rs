@@ -1,21 +1,35 @@
 use super::bootstrap::AppState;
+use super::metrics::MetricsCounters;
+use super::state::lock_runtime;
 use super::auth::{
-    normalize_account_id, required_access_token,
+    ensure_calendar_write_scopes, normalize_account_id, required_access_token,
 };
 use crate::application::calendar_services::{
-    build_reqwest_calendar_sync_service, ensure_blocks_calendar_for_account,
+    build_reqwest_calendar_client, build_reqwest_calendar_sync_service,
+    ensure_blocks_calendar_for_account,
 };
+use crate::application::calendar_setup::{timezone_differs, BlocksCalendarInitializer};
 use crate::application::calendar_runtime::{auto_relocate_after_sync, save_suppressions};
 use crate::application::calendar_window::resolve_sync_window;
 use crate::application::policy_service::load_runtime_policy;
-use crate::application::time_slots::{clip_interval, event_to_interval, merge_intervals};
+use crate::application::task_runtime::restore_task_assignments_from_events;
+use crate::application::time_slots::{
+    clip_interval, event_to_interval, local_datetime_to_utc, merge_intervals, parse_date_input,
+};
+use crate::infrastructure::calendar_cache::CalendarCacheRepository;
 use crate::infrastructure::error::InfraError;
+use crate::infrastructure::event_mapper::GoogleCalendarEvent;
+use crate::infrastructure::sync_state_repository::{SqliteSyncStateRepository, SyncStateRepository};
+use chrono::{DateTime, Duration, NaiveTime, Utc};
 use serde::Serialize;
 use std::collections::HashSet;
+use std::future::Future;
 use std::time::Instant;
 
 pub use super::auth::{
-    authenticate_google_impl, authenticate_google_sso_impl, AuthenticateGoogleResponse,
+    authenticate_google_impl, authenticate_google_sso_impl, build_authorization_url_impl,
+    cancel_sso_impl, reauthenticate_account_impl, AuthenticateGoogleResponse,
+    AuthorizationUrlResponse,
 };
 
 #[derive(Debug, Clone, Serialize)]
@@ -24,11 +38,21 @@ pub struct SyncCalendarResponse {
     pub added: usize,
     pub updated: usize,
     pub deleted: usize,
+    pub relocated: usize,
+    pub relocated_blocks: Vec<RelocatedBlockResponse>,
+    pub truncated: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_sync_token: Option<String>,
     pub calendar_id: String,
 }
 
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct RelocatedBlockResponse {
+    pub block_id: String,
+    pub old_start: String,
+    pub new_start: String,
+}
+
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 pub struct SyncedEventSlotResponse {
     pub account_id: String,
@@ -38,6 +62,65 @@ pub struct SyncedEventSlotResponse {
     pub end_at: String,
 }
 
+pub async fn set_blocks_calendar_impl(
+    state: &AppState,
+    account_id: Option<String>,
+    calendar_id: String,
+) -> Result<String, InfraError> {
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    ensure_calendar_write_scopes()?;
+    let access_token = required_access_token(&account_id).await?;
+    let initializer = BlocksCalendarInitializer::new(
+        state.config_dir(),
+        account_id.clone(),
+        build_reqwest_calendar_client(&account_id),
+    );
+    let calendar_id = initializer
+        .select_blocks_calendar(&access_token, &calendar_id)
+        .await?;
+    lock_runtime(state)?
+        .blocks_calendar_ids
+        .insert(account_id.clone(), calendar_id.clone());
+    state.log_info(
+        "set_blocks_calendar",
+        &format!("selected account_id={account_id} calendar_id={calendar_id}"),
+    );
+    Ok(calendar_id)
+}
+
+pub async fn get_calendar_timezone_impl(
+    state: &AppState,
+    account_id: Option<String>,
+) -> Result<Option<String>, InfraError> {
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    let access_token = required_access_token(&account_id).await?;
+    let calendar_id = ensure_blocks_calendar_for_account(state, &access_token, &account_id).await?;
+    cached_calendar_timezone(state, &access_token, &account_id, &calendar_id).await
+}
+
+async fn cached_calendar_timezone(
+    state: &AppState,
+    access_token: &str,
+    account_id: &str,
+    calendar_id: &str,
+) -> Result<Option<String>, InfraError> {
+    if let Some(timezone) = lock_runtime(state)?.calendar_timezones.get(calendar_id).cloned() {
+        return Ok(Some(timezone));
+    }
+    let initializer = BlocksCalendarInitializer::new(
+        state.config_dir(),
+        account_id,
+        build_reqwest_calendar_client(account_id),
+    );
+    let timezone = initializer.calendar_timezone(access_token, calendar_id).await?;
+    if let Some(timezone) = timezone.as_ref() {
+        lock_runtime(state)?
+            .calendar_timezones
+            .insert(calendar_id.to_string(), timezone.clone());
+    }
+    Ok(timezone)
+}
+
 pub async fn sync_calendar_impl(
     state: &AppState,
     account_id: Option<String>,
@@ -45,28 +128,88 @@ pub async fn sync_calendar_impl(
     time_max: Option<String>,
 ) -> Result<SyncCalendarResponse, InfraError> {
     let started_at = Instant::now();
-    let account_id = normalize_account_id(account_id);
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    with_account_sync_lock(state, &account_id, || {
+        sync_account_calendar(state, account_id.clone(), started_at, time_min, time_max)
+    })
+    .await
+}
+
+/// Runs `sync` while holding the account's sync lock, so the auto-generation timer and a
+/// manual sync of the same account never interleave their cache writes.
+pub(crate) async fn with_account_sync_lock<T, F, Fut>(
+    state: &AppState,
+    account_id: &str,
+    sync: F,
+) -> Result<T, InfraError>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T, InfraError>>,
+{
+    let sync_lock = state.account_sync_lock(account_id)?;
+    let _sync_guard = sync_lock.lock().await;
+    sync().await
+}
+
+async fn sync_account_calendar(
+    state: &AppState,
+    account_id: String,
+    started_at: Instant,
+    time_min: Option<String>,
+    time_max: Option<String>,
+) -> Result<SyncCalendarResponse, InfraError> {
     let policy = load_runtime_policy(state.config_dir());
-    let access_token = required_access_token(Some(account_id.clone())).await?;
+    let access_token = required_access_token(&account_id).await?;
     let (window_start, window_end) = resolve_sync_window(time_min, time_max)?;
     let calendar_id = ensure_blocks_calendar_for_account(state, &access_token, &account_id).await?;
-    let sync_service = build_reqwest_calendar_sync_service(state);
+    let sync_service = build_reqwest_calendar_sync_service(state, &account_id);
     let sync_result = sync_service
-        .sync(&access_token, &calendar_id, window_start, window_end)
+        .sync(
+            &access_token,
+            &calendar_id,
+            window_start,
+            window_end,
+            policy.sync_max_events,
+        )
         .await?;
+    match cached_calendar_timezone(state, &access_token, &account_id, &calendar_id).await {
+        Ok(Some(calendar_timezone)) if timezone_differs(&calendar_timezone, policy.timezone) => {
+            state.log_warn(
+                "sync_calendar",
+                &format!(
+                    "calendar_id={calendar_id} uses timezone {calendar_timezone} but policy timezone is {}",
+                    policy.timezone.name()
+                ),
+            );
+        }
+        Ok(_) => {}
+        Err(error) => state.log_warn(
+            "sync_calendar",
+            &format!("failed to read timezone of calendar_id={calendar_id}: {error}"),
+        ),
+    }
     if !sync_result.suppressed_instances.is_empty() {
         save_suppressions(
             state.database_path(),
             &sync_result.suppressed_instances,
             Some("calendar_cancelled"),
+            None,
         )?;
     }
     let latest_events = sync_service
-        .fetch_events(&access_token, &calendar_id, window_start, window_end)
+        .fetch_events(
+            &access_token,
+            &calendar_id,
+            window_start,
+            window_end,
+            policy.sync_max_events,
+        )
         .await?;
 
-    let previous_account_events =
-        state.replace_synced_events(&account_id, latest_events, &calendar_id)?;
+    MetricsCounters::add(&state.metrics().events_fetched, latest_events.len());
+
+    let (previous_account_events, restored_assignments) =
+        import_synced_events(state, &account_id, latest_events, &calendar_id)?;
 
     let mut changed_intervals = Vec::new();
     for event in sync_result.added.iter().chain(sync_result.updated.iter()) {
@@ -103,29 +246,38 @@ pub async fn sync_calendar_impl(
     }
 
     let changed_intervals = merge_intervals(changed_intervals);
-    let relocated_count = auto_relocate_after_sync(
+    let relocated_blocks = auto_relocate_after_sync(
         state,
         account_id.as_str(),
         &changed_intervals,
         policy.max_relocations_per_sync,
     )
     .await?;
-    if relocated_count > 0 {
+    if !relocated_blocks.is_empty() {
         let refreshed_events = sync_service
-            .fetch_events(&access_token, &calendar_id, window_start, window_end)
+            .fetch_events(
+                &access_token,
+                &calendar_id,
+                window_start,
+                window_end,
+                policy.sync_max_events,
+            )
             .await?;
         let _ = state.replace_synced_events(&account_id, refreshed_events, &calendar_id)?;
     }
 
+    MetricsCounters::add(&state.metrics().syncs_run, 1);
     state.log_info(
         "sync_calendar",
         &format!(
-            "synchronized account_id={account_id} calendar_id={calendar_id} added={} updated={} deleted={} suppressed={} relocated={} elapsed_ms={}",
+            "synchronized account_id={account_id} calendar_id={calendar_id} added={} updated={} deleted={} suppressed={} relocated={} restored_assignments={} truncated={} elapsed_ms={}",
             sync_result.added.len(),
             sync_result.updated.len(),
             sync_result.deleted.len(),
             sync_result.suppressed_instances.len(),
-            relocated_count,
+            relocated_blocks.len(),
+            restored_assignments,
+            sync_result.truncated,
             started_at.elapsed().as_millis()
         ),
     );
@@ -135,11 +287,71 @@ pub async fn sync_calendar_impl(
         added: sync_result.added.len(),
         updated: sync_result.updated.len(),
         deleted: sync_result.deleted.len(),
+        relocated: relocated_blocks.len(),
+        relocated_blocks,
+        truncated: sync_result.truncated,
         next_sync_token: sync_result.next_sync_token,
         calendar_id,
     })
 }
 
+/// Stores the fetched events for the account and applies what block events carry back to
+/// the runtime blocks. Returns the previously stored events and the restored assignments.
+pub(crate) fn import_synced_events(
+    state: &AppState,
+    account_id: &str,
+    latest_events: Vec<GoogleCalendarEvent>,
+    calendar_id: &str,
+) -> Result<(Vec<GoogleCalendarEvent>, usize), InfraError> {
+    let restored_assignments = {
+        let mut runtime = lock_runtime(state)?;
+        restore_task_assignments_from_events(&mut runtime, &latest_events)
+    };
+    let previous_events = state.replace_synced_events(account_id, latest_events, calendar_id)?;
+    Ok((previous_events, restored_assignments))
+}
+
+/// Forgets the account's cached events and sync token so its next sync starts from scratch.
+/// Runs under the account's sync lock so an in-flight sync cannot write the cache back.
+pub async fn reset_sync_impl(
+    state: &AppState,
+    account_id: Option<String>,
+    calendar_id: String,
+) -> Result<(), InfraError> {
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    let calendar_id = calendar_id.trim();
+    if calendar_id.is_empty() {
+        return Err(InfraError::InvalidConfig(
+            "calendar_id must not be empty".to_string(),
+        ));
+    }
+
+    with_account_sync_lock(state, &account_id, || async {
+        {
+            let mut runtime = lock_runtime(state)?;
+            if let Some(synced_calendar_id) = runtime
+                .blocks_calendar_ids
+                .get(&account_id)
+                .filter(|synced| synced.as_str() != calendar_id)
+            {
+                return Err(InfraError::InvalidConfig(format!(
+                    "calendar_id {calendar_id} does not match synced calendar {synced_calendar_id} for account_id={account_id}"
+                )));
+            }
+            runtime.synced_events_by_account.remove(&account_id);
+        }
+        state.calendar_cache(&account_id).clear()?;
+        SqliteSyncStateRepository::new(state.database_path(), account_id.as_str()).clear()
+    })
+    .await?;
+
+    state.log_info(
+        "reset_sync",
+        &format!("cleared sync state account_id={account_id} calendar_id={calendar_id}"),
+    );
+    Ok(())
+}
+
 pub fn list_synced_events_impl(
     state: &AppState,
     account_id: Option<String>,
@@ -147,11 +359,42 @@ pub fn list_synced_events_impl(
     time_max: Option<String>,
 ) -> Result<Vec<SyncedEventSlotResponse>, InfraError> {
     let (window_start, window_end) = resolve_sync_window(time_min, time_max)?;
+    collect_synced_event_slots(state, account_id, window_start, window_end)
+}
+
+/// Cached synced events for every local day from `start_date` through `end_date` inclusive,
+/// with day boundaries taken from the policy timezone.
+pub fn list_synced_events_range_impl(
+    state: &AppState,
+    account_id: Option<String>,
+    start_date: String,
+    end_date: String,
+) -> Result<Vec<SyncedEventSlotResponse>, InfraError> {
+    let start_date = parse_date_input(&start_date, "start_date")?;
+    let end_date = parse_date_input(&end_date, "end_date")?;
+    if end_date < start_date {
+        return Err(InfraError::InvalidConfig(
+            "end_date must not be before start_date".to_string(),
+        ));
+    }
+    let timezone = load_runtime_policy(state.config_dir()).timezone;
+    let window_start = local_datetime_to_utc(start_date, NaiveTime::MIN, timezone)?;
+    let window_end =
+        local_datetime_to_utc(end_date + Duration::days(1), NaiveTime::MIN, timezone)?;
+    collect_synced_event_slots(state, account_id, window_start, window_end)
+}
+
+fn collect_synced_event_slots(
+    state: &AppState,
+    account_id: Option<String>,
+    window_start: DateTime<Utc>,
+    window_end: DateTime<Utc>,
+) -> Result<Vec<SyncedEventSlotResponse>, InfraError> {
     let requested_account = account_id
         .as_deref()
         .map(str::trim)
         .filter(|value| !value.is_empty())
-        .map(|value| normalize_account_id(Some(value.to_string())));
+        .map(|value| normalize_account_id(state.config_dir(), Some(value.to_string())));
 
     let snapshots = state.synced_events_snapshot(requested_account.as_deref())?;
     let mut events = Vec::new();
@@ -0,0 +1,29 @@
+use crate::application::block_service::BlockService;
+use crate::domain::models::Block;
+use crate::infrastructure::error::InfraError;
+use crate::infrastructure::event_mapper::{decode_block, encode_block_event, GoogleCalendarEvent};
+
+pub fn debug_roundtrip_block_impl(
+    state: &super::bootstrap::AppState,
+    block: Block,
+) -> Result<Block, InfraError> {
+    let event = encode_block_event(&block);
+    let decoded = decode_block(&event).ok_or_else(|| {
+        InfraError::InvalidConfig(format!("encoded block could not be decoded: {}", block.id))
+    })?;
+    state.log_info(
+        "debug_roundtrip_block",
+        &format!("round-tripped block_id={}", decoded.id),
+    );
+    Ok(decoded)
+}
+
+pub async fn get_block_calendar_event_impl(
+    state: &super::bootstrap::AppState,
+    block_id: String,
+    account_id: Option<String>,
+) -> Result<Option<GoogleCalendarEvent>, InfraError> {
+    BlockService::new(state)
+        .get_block_calendar_event(block_id, account_id)
+        .await
+}
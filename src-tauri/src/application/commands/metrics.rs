@@ -0,0 +1,47 @@
+use crate::infrastructure::error::InfraError;
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct Metrics {
+    pub syncs_run: u64,
+    pub events_fetched: u64,
+    pub blocks_generated: u64,
+    pub slow_generations: u64,
+    pub relocations: u64,
+    pub tasks_created: u64,
+    pub command_errors: u64,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct MetricsCounters {
+    pub(crate) syncs_run: AtomicU64,
+    pub(crate) events_fetched: AtomicU64,
+    pub(crate) blocks_generated: AtomicU64,
+    pub(crate) slow_generations: AtomicU64,
+    pub(crate) relocations: AtomicU64,
+    pub(crate) tasks_created: AtomicU64,
+    pub(crate) command_errors: AtomicU64,
+}
+
+impl MetricsCounters {
+    pub(crate) fn add(counter: &AtomicU64, amount: usize) {
+        counter.fetch_add(amount as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> Metrics {
+        Metrics {
+            syncs_run: self.syncs_run.load(Ordering::Relaxed),
+            events_fetched: self.events_fetched.load(Ordering::Relaxed),
+            blocks_generated: self.blocks_generated.load(Ordering::Relaxed),
+            slow_generations: self.slow_generations.load(Ordering::Relaxed),
+            relocations: self.relocations.load(Ordering::Relaxed),
+            tasks_created: self.tasks_created.load(Ordering::Relaxed),
+            command_errors: self.command_errors.load(Ordering::Relaxed),
+        }
+    }
+}
+
+pub fn get_metrics_impl(state: &super::bootstrap::AppState) -> Result<Metrics, InfraError> {
+    Ok(state.metrics().snapshot())
+}
@@ -3,25 +3,43 @@ mod blocks;
 mod bootstrap;
 mod calendar;
 mod catalog;
+#[cfg(feature = "debug-tools")]
+mod debug;
+mod metrics;
 mod routines;
 #[cfg(test)]
 mod regression_tests;
 mod pomodoro;
+mod policy;
 mod reflection;
 mod state;
 mod tasks;
 
 pub use blocks::{
     adjust_block_time_impl, apply_studio_template_to_today_impl, approve_blocks_impl,
-    delete_block_impl, generate_blocks_impl, generate_one_block_impl, generate_today_blocks_impl,
-    list_blocks_impl, relocate_if_needed_impl,
+    cleanup_orphaned_events_impl, clear_day_impl, delete_block_impl, export_blocks_ics_impl,
+    export_day_markdown_impl,
+    generate_blocks_impl, generate_from_source_impl,
+    generate_one_block_impl, generate_today_blocks_impl, get_blocks_localized_impl,
+    get_busy_summary_impl,
+    get_overlapping_blocks_impl, get_week_capacity_impl, list_blocks_impl, reflow_day_impl,
+    regenerate_auto_blocks_impl, relocate_blocks_impl, relocate_if_needed_impl,
+    remaining_capacity_impl, reschedule_block_impl, reset_day_impl,
+    run_startup_catch_up_impl, set_block_project_impl, snooze_block_impl,
+    sync_pending_block_events_impl, BusySummary, LocalizedBlock, RelocateBlocksResult,
+    WeekCapacity,
 };
-pub use bootstrap::AppState;
+pub use bootstrap::{get_workspace_paths_impl, AppState, WorkspacePaths};
 pub use calendar::{
-    authenticate_google_impl, authenticate_google_sso_impl, list_synced_events_impl,
-    sync_calendar_impl, AuthenticateGoogleResponse, SyncedEventSlotResponse,
-    SyncCalendarResponse,
+    authenticate_google_impl, authenticate_google_sso_impl, build_authorization_url_impl,
+    cancel_sso_impl, get_calendar_timezone_impl, list_synced_events_impl,
+    list_synced_events_range_impl, reauthenticate_account_impl, reset_sync_impl,
+    set_blocks_calendar_impl, sync_calendar_impl,
+    AuthenticateGoogleResponse, AuthorizationUrlResponse, RelocatedBlockResponse,
+    SyncedEventSlotResponse, SyncCalendarResponse,
 };
+#[cfg(feature = "debug-tools")]
+pub use debug::{debug_roundtrip_block_impl, get_block_calendar_event_impl};
 pub use catalog::{
     create_module_folder_impl, create_module_impl, create_recipe_impl, delete_module_folder_impl,
     delete_module_impl, delete_recipe_impl, list_module_folders_impl, list_modules_impl,
@@ -29,23 +47,42 @@ pub use catalog::{
     update_recipe_impl,
 };
 pub use routines::{
-    delete_routine_schedule_impl, list_routine_schedules_impl, list_routines_impl,
+    add_holiday_impl, delete_routine_schedule_impl, list_holidays_impl,
+    list_routine_schedules_impl, list_routines_impl, remove_holiday_impl,
     save_routine_schedule_group_impl, save_routine_schedule_impl,
 };
 pub use pomodoro::{
-    advance_pomodoro_impl, complete_pomodoro_impl, get_pomodoro_state_impl, interrupt_timer_impl,
+    advance_pomodoro_impl, complete_pomodoro_impl, get_pomodoro_state_impl, get_session_logs_impl,
+    interrupt_timer_impl, log_pomodoro_impl,
     next_step_impl, pause_pomodoro_impl, pause_timer_impl, resume_pomodoro_impl,
-    resume_timer_impl, start_block_timer_impl, start_pomodoro_impl, PomodoroStateResponse,
+    report_activity_impl, resume_timer_impl, set_current_task_impl, start_block_timer_impl, start_pomodoro_impl,
+    tick_pomodoro_impl, PomodoroStateResponse,
+};
+pub use metrics::{get_metrics_impl, Metrics};
+pub use policy::{
+    get_effective_config_impl, get_timezone_impl, is_work_time_impl, lint_config_impl,
+    list_timezones_impl, next_auto_generation_impl, validate_rrule_impl, validate_timezone_impl,
+    ConfigIssue, EffectiveConfig, RrulePreview,
+    ValidateTimezoneResponse,
+};
+pub use reflection::{
+    get_block_reflection_impl, get_longest_focus_impl, get_reflection_summary_impl,
+    get_task_investment_impl, BlockReflection, LongestFocus, ReflectionLogItem,
+    ReflectionSummaryResponse, TaskInvestment,
 };
-pub use reflection::{get_reflection_summary_impl, ReflectionSummaryResponse};
 pub use crate::application::studio_template_application::ApplyStudioResult;
 pub use tasks::{
-    carry_over_task_impl, create_task_impl, delete_task_impl, list_tasks_impl, split_task_impl,
-    update_task_impl, CarryOverTaskResponse,
+    begin_task_impl, carry_over_candidates_impl, carry_over_task_impl, create_task_impl,
+    create_tasks_bulk_impl, delete_task_impl, get_estimated_completion_impl, list_tasks_impl,
+    list_unassigned_tasks_impl, materialize_recurring_tasks_impl, move_task_impl, split_task_impl,
+    suggest_block_for_task_impl, suggest_pomodoros_impl, update_task_impl,
+    CarryOverTaskResponse,
 };
 pub(crate) use auth::{
-    ensure_blocks_calendar_id, normalize_account_id, try_access_token, DEFAULT_ACCOUNT_ID,
+    ensure_blocks_calendar_id, normalize_account_id, refreshed_access_token, try_access_token,
+    DEFAULT_ACCOUNT_ID,
 };
+pub(crate) use metrics::MetricsCounters;
 pub(crate) use state::{
     block_runtime_snapshot, lock_runtime, persist_generated_block, persist_generated_blocks,
     studio_runtime_snapshot, RuntimeState, StoredBlock,
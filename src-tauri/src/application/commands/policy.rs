@@ -0,0 +1,226 @@
+use crate::application::configured_block_plans::{
+    lint_configured_block_plans, rrule_is_supported, upcoming_rrule_dates,
+};
+use crate::application::policy_service::{
+    canonical_timezone_name, invalid_timezone_warning, is_within_work_hours, load_runtime_policy,
+    next_auto_generation_at, BlockColorBy, BlockDeleteBehavior, BlockEndBehavior,
+};
+use crate::infrastructure::error::InfraError;
+use chrono::{NaiveTime, Utc, Weekday};
+use serde::Serialize;
+
+pub use crate::application::configured_block_plans::ConfigIssue;
+
+const RRULE_PREVIEW_DAYS: usize = 31;
+const RRULE_PREVIEW_LIMIT: usize = 10;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidateTimezoneResponse {
+    pub valid: bool,
+    pub canonical: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct RrulePreview {
+    pub supported: bool,
+    pub next_occurrences: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct EffectiveConfig {
+    pub timezone: String,
+    pub work_start: String,
+    pub work_end: String,
+    pub work_days: Vec<String>,
+    pub auto_enabled: bool,
+    pub auto_time: String,
+    pub catch_up_on_app_start: bool,
+    pub catch_up_max_days: u32,
+    pub block_duration_minutes: u32,
+    pub break_duration_minutes: u32,
+    pub min_block_gap_minutes: u32,
+    pub min_auto_block_minutes: u32,
+    pub max_auto_blocks_per_day: u32,
+    pub max_daily_focus_minutes: Option<u32>,
+    pub max_relocations_per_sync: u32,
+    pub relocation_grace_minutes: u32,
+    pub sync_max_events: Option<usize>,
+    pub quiet_hours: Option<EffectiveQuietHours>,
+    pub idle_timeout_minutes: Option<u32>,
+    pub break_reminder_seconds: Option<u32>,
+    pub setup_seconds: Option<u32>,
+    pub allow_out_of_date_start: bool,
+    pub on_block_end: Option<String>,
+    pub on_block_delete: String,
+    pub reminder_minutes: Option<u32>,
+    pub color_by: String,
+    pub load_color_ramp: Vec<String>,
+    pub ignore_declined_events: bool,
+    pub ignore_tentative_events: bool,
+    pub respect_suppression: bool,
+    pub skip_past: bool,
+    pub auto_approve: bool,
+    pub round_start_to_minutes: Option<u32>,
+    pub insert_break_blocks: bool,
+    pub auto_start_break: bool,
+    pub auto_start_focus: bool,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct EffectiveQuietHours {
+    pub start: String,
+    pub end: String,
+}
+
+pub fn get_effective_config_impl(
+    state: &super::bootstrap::AppState,
+) -> Result<EffectiveConfig, InfraError> {
+    let policy = load_runtime_policy(state.config_dir());
+    let mut work_days = policy.work_days.iter().copied().collect::<Vec<_>>();
+    work_days.sort_by_key(Weekday::num_days_from_monday);
+    Ok(EffectiveConfig {
+        timezone: policy.timezone.name().to_string(),
+        work_start: format_time(policy.work_start),
+        work_end: format_time(policy.work_end),
+        work_days: work_days.into_iter().map(weekday_name).map(str::to_string).collect(),
+        auto_enabled: policy.auto_enabled,
+        auto_time: format_time(policy.auto_time),
+        catch_up_on_app_start: policy.catch_up_on_app_start,
+        catch_up_max_days: policy.catch_up_max_days,
+        block_duration_minutes: policy.block_duration_minutes,
+        break_duration_minutes: policy.break_duration_minutes,
+        min_block_gap_minutes: policy.min_block_gap_minutes,
+        min_auto_block_minutes: policy.min_auto_block_minutes,
+        max_auto_blocks_per_day: policy.max_auto_blocks_per_day,
+        max_daily_focus_minutes: policy.max_daily_focus_minutes,
+        max_relocations_per_sync: policy.max_relocations_per_sync,
+        relocation_grace_minutes: policy.relocation_grace_minutes,
+        sync_max_events: policy.sync_max_events,
+        quiet_hours: policy.quiet_hours.map(|quiet_hours| EffectiveQuietHours {
+            start: format_time(quiet_hours.start),
+            end: format_time(quiet_hours.end),
+        }),
+        idle_timeout_minutes: policy.idle_timeout_minutes,
+        break_reminder_seconds: policy.break_reminder_seconds,
+        setup_seconds: policy.setup_seconds,
+        allow_out_of_date_start: policy.allow_out_of_date_start,
+        on_block_end: policy.on_block_end.map(|behavior| {
+            match behavior {
+                BlockEndBehavior::Stop => "stop",
+                BlockEndBehavior::Continue => "continue",
+            }
+            .to_string()
+        }),
+        on_block_delete: match policy.on_block_delete {
+            BlockDeleteBehavior::Unassign => "unassign",
+            BlockDeleteBehavior::CarryOver => "carry_over",
+        }
+        .to_string(),
+        reminder_minutes: policy.reminder_minutes,
+        color_by: match policy.color_by {
+            BlockColorBy::Type => "type",
+            BlockColorBy::Load => "load",
+        }
+        .to_string(),
+        load_color_ramp: policy.load_color_ramp,
+        ignore_declined_events: policy.ignore_declined_events,
+        ignore_tentative_events: policy.ignore_tentative_events,
+        respect_suppression: policy.respect_suppression,
+        skip_past: policy.skip_past,
+        auto_approve: policy.auto_approve,
+        round_start_to_minutes: policy.round_start_to_minutes,
+        insert_break_blocks: policy.insert_break_blocks,
+        auto_start_break: policy.auto_start_break,
+        auto_start_focus: policy.auto_start_focus,
+    })
+}
+
+pub fn lint_config_impl(
+    state: &super::bootstrap::AppState,
+) -> Result<Vec<ConfigIssue>, InfraError> {
+    let mut issues = lint_configured_block_plans(state.config_dir());
+    if let Some(problem) = invalid_timezone_warning(state.config_dir()) {
+        issues.insert(
+            0,
+            ConfigIssue {
+                file: "app.json".to_string(),
+                id: None,
+                problem,
+            },
+        );
+    }
+    Ok(issues)
+}
+
+pub fn next_auto_generation_impl(
+    state: &super::bootstrap::AppState,
+) -> Result<Option<String>, InfraError> {
+    let policy = load_runtime_policy(state.config_dir());
+    Ok(next_auto_generation_at(&policy, Utc::now()).map(|next| next.to_rfc3339()))
+}
+
+pub fn is_work_time_impl(state: &super::bootstrap::AppState) -> Result<bool, InfraError> {
+    let policy = load_runtime_policy(state.config_dir());
+    Ok(is_within_work_hours(&policy, Utc::now()))
+}
+
+fn format_time(time: NaiveTime) -> String {
+    time.format("%H:%M").to_string()
+}
+
+fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+pub fn validate_timezone_impl(
+    _state: &super::bootstrap::AppState,
+    timezone: String,
+) -> Result<ValidateTimezoneResponse, InfraError> {
+    let canonical = canonical_timezone_name(&timezone);
+    Ok(ValidateTimezoneResponse {
+        valid: canonical.is_some(),
+        canonical,
+    })
+}
+
+pub fn get_timezone_impl(state: &super::bootstrap::AppState) -> Result<String, InfraError> {
+    Ok(load_runtime_policy(state.config_dir()).timezone.name().to_string())
+}
+
+pub fn list_timezones_impl() -> Vec<String> {
+    chrono_tz::TZ_VARIANTS
+        .iter()
+        .map(|timezone| timezone.name().to_string())
+        .collect()
+}
+
+pub fn validate_rrule_impl(
+    state: &super::bootstrap::AppState,
+    rrule: String,
+) -> Result<RrulePreview, InfraError> {
+    if !rrule_is_supported(&rrule) {
+        return Ok(RrulePreview {
+            supported: false,
+            next_occurrences: Vec::new(),
+        });
+    }
+    let policy = load_runtime_policy(state.config_dir());
+    let today = Utc::now().with_timezone(&policy.timezone).date_naive();
+    let next_occurrences =
+        upcoming_rrule_dates(&rrule, today, RRULE_PREVIEW_DAYS, RRULE_PREVIEW_LIMIT)
+            .into_iter()
+            .map(|date| date.to_string())
+            .collect();
+    Ok(RrulePreview {
+        supported: true,
+        next_occurrences,
+    })
+}
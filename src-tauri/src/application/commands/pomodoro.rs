@@ -1,4 +1,7 @@
 use crate::application::pomodoro_service::PomodoroService;
+use crate::application::reflection_service::ReflectionLogItem;
+use crate::application::time_slots::parse_rfc3339_input;
+use crate::domain::models::PomodoroLog;
 use crate::infrastructure::error::InfraError;
 
 pub use crate::application::pomodoro_service::PomodoroStateResponse;
@@ -45,6 +48,19 @@ pub fn interrupt_timer_impl(
     PomodoroService::new(state).interrupt_timer(reason)
 }
 
+pub fn get_session_logs_impl(
+    state: &super::bootstrap::AppState,
+) -> Result<Vec<ReflectionLogItem>, InfraError> {
+    PomodoroService::new(state).get_session_logs()
+}
+
+pub fn set_current_task_impl(
+    state: &super::bootstrap::AppState,
+    task_id: Option<String>,
+) -> Result<PomodoroStateResponse, InfraError> {
+    PomodoroService::new(state).set_current_task(task_id)
+}
+
 pub fn pause_pomodoro_impl(
     state: &super::bootstrap::AppState,
     reason: Option<String>,
@@ -64,12 +80,43 @@ pub fn advance_pomodoro_impl(
     PomodoroService::new(state).advance_pomodoro()
 }
 
+pub fn tick_pomodoro_impl(
+    state: &super::bootstrap::AppState,
+    emit: impl FnMut(&'static str, &PomodoroStateResponse),
+) -> Result<PomodoroStateResponse, InfraError> {
+    PomodoroService::new(state).tick_pomodoro_with_events(chrono::Utc::now(), emit)
+}
+
+pub fn report_activity_impl(
+    state: &super::bootstrap::AppState,
+    at: Option<String>,
+) -> Result<PomodoroStateResponse, InfraError> {
+    let at = match at.as_deref().map(str::trim).filter(|value| !value.is_empty()) {
+        Some(value) => parse_rfc3339_input(value, "at")?,
+        None => chrono::Utc::now(),
+    };
+    PomodoroService::new(state).report_activity(at)
+}
+
 pub fn complete_pomodoro_impl(
     state: &super::bootstrap::AppState,
 ) -> Result<PomodoroStateResponse, InfraError> {
     PomodoroService::new(state).complete_pomodoro()
 }
 
+pub fn log_pomodoro_impl(
+    state: &super::bootstrap::AppState,
+    block_id: String,
+    task_id: Option<String>,
+    start: String,
+    end: String,
+    interrupted: bool,
+) -> Result<PomodoroLog, InfraError> {
+    let start = parse_rfc3339_input(&start, "start")?;
+    let end = parse_rfc3339_input(&end, "end")?;
+    PomodoroService::new(state).log_pomodoro(block_id, task_id, start, end, interrupted)
+}
+
 pub fn get_pomodoro_state_impl(
     state: &super::bootstrap::AppState,
 ) -> Result<PomodoroStateResponse, InfraError> {
@@ -1,12 +1,36 @@
 use crate::application::reflection_service::ReflectionService;
 use crate::infrastructure::error::InfraError;
 
-pub use crate::application::reflection_service::ReflectionSummaryResponse;
+pub use crate::application::reflection_service::{
+    BlockReflection, LongestFocus, ReflectionLogItem, ReflectionSummaryResponse, TaskInvestment,
+};
 
 pub fn get_reflection_summary_impl(
     state: &super::bootstrap::AppState,
     start: Option<String>,
     end: Option<String>,
+    log_limit: Option<usize>,
 ) -> Result<ReflectionSummaryResponse, InfraError> {
-    ReflectionService::new(state).get_summary(start, end)
+    ReflectionService::new(state).get_summary(start, end, log_limit)
+}
+
+pub fn get_block_reflection_impl(
+    state: &super::bootstrap::AppState,
+    block_id: String,
+) -> Result<BlockReflection, InfraError> {
+    ReflectionService::new(state).get_block_reflection(block_id)
+}
+
+pub fn get_longest_focus_impl(
+    state: &super::bootstrap::AppState,
+    start: String,
+    end: String,
+) -> Result<Option<LongestFocus>, InfraError> {
+    ReflectionService::new(state).get_longest_focus(start, end)
+}
+
+pub fn get_task_investment_impl(
+    state: &super::bootstrap::AppState,
+) -> Result<Vec<TaskInvestment>, InfraError> {
+    ReflectionService::new(state).get_task_investment()
 }
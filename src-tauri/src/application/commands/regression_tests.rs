@@ -8,3 +8,4 @@ mod runtime_support;
 mod studio_catalog_tests;
 mod routine_schedule_tests;
 mod task_tests;
+mod workspace_tests;
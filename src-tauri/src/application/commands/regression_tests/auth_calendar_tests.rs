@@ -1,7 +1,57 @@
-use super::auth_support::{load_oauth_config_from_lookup, InfraError, DEFAULT_ACCOUNT_ID};
+use super::auth_support::{
+    bind_loopback_listener, build_authorization_url_with_config, complete_sso_sign_in,
+    load_oauth_config_from_lookup, parse_loopback_redirect, scopes_allow_calendar_writes,
+    wait_for_loopback_callback_blocking, InfraError, DEFAULT_ACCOUNT_ID,
+};
+use crate::application::commands::calendar::{import_synced_events, with_account_sync_lock};
+use crate::application::commands::{
+    authenticate_google_impl, cancel_sso_impl, generate_blocks_impl, list_blocks_impl,
+    list_synced_events_range_impl, reauthenticate_account_impl, reset_sync_impl,
+};
+use super::block_support::busy_event;
 use super::runtime_support::lock_runtime;
+use crate::application::oauth::{OAuthConfig, OAuthManager};
 use crate::application::test_support::workspace::TempWorkspace;
-use crate::infrastructure::event_mapper::{CalendarEventDateTime, GoogleCalendarEvent};
+use crate::domain::models::OAuthToken;
+use crate::infrastructure::calendar_cache::CalendarCacheRepository;
+use crate::infrastructure::credential_store::{CredentialStore, InMemoryCredentialStore};
+use crate::infrastructure::event_mapper::{
+    encode_block_event_with_task, CalendarEventDateTime, GoogleCalendarEvent,
+};
+use crate::infrastructure::oauth_client::{
+    OAuthCodeExchangeRequest, OAuthHttpClient, OAuthRefreshRequest, OAuthTokenResponse,
+};
+use crate::infrastructure::sync_state_repository::{SqliteSyncStateRepository, SyncStateRepository};
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use std::fs;
+use std::sync::Arc;
+
+/// Exchanges any authorization code for a fresh one-hour token.
+struct CodeExchangingOAuthClient;
+
+#[async_trait]
+impl OAuthHttpClient for CodeExchangingOAuthClient {
+    async fn exchange_authorization_code(
+        &self,
+        request: OAuthCodeExchangeRequest,
+    ) -> Result<OAuthTokenResponse, InfraError> {
+        Ok(OAuthTokenResponse {
+            access_token: format!("access-for-{}", request.authorization_code),
+            refresh_token: Some("refresh-token".to_string()),
+            expires_in: 3600,
+            token_type: Some("Bearer".to_string()),
+            scope: None,
+        })
+    }
+
+    async fn refresh_access_token(
+        &self,
+        _request: OAuthRefreshRequest,
+    ) -> Result<OAuthTokenResponse, InfraError> {
+        Err(InfraError::OAuth("refresh is not expected".to_string()))
+    }
+}
 
 #[test]
 fn oauth_config_validation_reports_missing_client_id() {
@@ -17,6 +67,272 @@ fn oauth_config_validation_reports_missing_client_id() {
     }
 }
 
+#[test]
+fn oauth_scope_profile_selects_readonly_or_manage_scopes() {
+    let config_for = |profile: &'static str, scopes: Option<&'static str>| {
+        load_oauth_config_from_lookup(move |key| match key {
+            "POMBLOCK_GOOGLE_CLIENT_ID" => Some("client".to_string()),
+            "POMBLOCK_GOOGLE_CLIENT_SECRET" => Some("secret".to_string()),
+            "POMBLOCK_GOOGLE_SCOPE_PROFILE" => Some(profile.to_string()),
+            "POMBLOCK_GOOGLE_SCOPES" => scopes.map(ToOwned::to_owned),
+            _ => None,
+        })
+    };
+
+    let readonly = config_for("readonly", None).expect("readonly config");
+    assert_eq!(
+        readonly.scopes,
+        vec!["https://www.googleapis.com/auth/calendar.readonly".to_string()]
+    );
+    assert!(!scopes_allow_calendar_writes(&readonly.scopes));
+
+    let manage = config_for("manage", None).expect("manage config");
+    assert_eq!(
+        manage.scopes,
+        vec!["https://www.googleapis.com/auth/calendar".to_string()]
+    );
+    assert!(scopes_allow_calendar_writes(&manage.scopes));
+
+    let explicit = config_for(
+        "readonly",
+        Some("https://www.googleapis.com/auth/calendar.events"),
+    )
+    .expect("explicit scopes config");
+    assert_eq!(
+        explicit.scopes,
+        vec!["https://www.googleapis.com/auth/calendar.events".to_string()]
+    );
+    assert!(scopes_allow_calendar_writes(&explicit.scopes));
+
+    match config_for("everything", None) {
+        Err(InfraError::InvalidConfig(message)) => assert!(message.contains("scope profile")),
+        _ => panic!("expected unknown scope profile error"),
+    }
+}
+
+#[test]
+fn build_authorization_url_includes_client_id_scope_and_remembers_state() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let config = load_oauth_config_from_lookup(|key| match key {
+        "POMBLOCK_GOOGLE_CLIENT_ID" => Some("client-123.apps.googleusercontent.com".to_string()),
+        "POMBLOCK_GOOGLE_CLIENT_SECRET" => Some("secret".to_string()),
+        _ => None,
+    })
+    .expect("oauth config");
+
+    let response =
+        build_authorization_url_with_config(&state, None, config).expect("authorization url");
+
+    let url = url::Url::parse(&response.authorization_url).expect("valid url");
+    let query = url.query_pairs().into_owned().collect::<Vec<_>>();
+    let value_of = |key: &str| {
+        query
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.clone())
+    };
+    assert_eq!(response.account_id, DEFAULT_ACCOUNT_ID);
+    assert_eq!(
+        value_of("client_id").as_deref(),
+        Some("client-123.apps.googleusercontent.com")
+    );
+    assert_eq!(
+        value_of("scope").as_deref(),
+        Some("https://www.googleapis.com/auth/calendar")
+    );
+    assert_eq!(value_of("state").as_deref(), Some(response.state.as_str()));
+    assert_eq!(
+        state.take_oauth_state(DEFAULT_ACCOUNT_ID).expect("pending state"),
+        Some(response.state)
+    );
+}
+
+#[tokio::test]
+async fn authenticate_google_rejects_a_mismatched_oauth_state() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    state
+        .remember_oauth_state(DEFAULT_ACCOUNT_ID, "oauth-state-expected")
+        .expect("remember state");
+
+    let forged = authenticate_google_impl(
+        &state,
+        None,
+        Some("auth-code".to_string()),
+        Some("oauth-state-forged".to_string()),
+    )
+    .await
+    .expect_err("reject forged state");
+    assert!(matches!(forged, InfraError::OAuth(ref message) if message == "state mismatch"));
+
+    let replayed = authenticate_google_impl(
+        &state,
+        None,
+        Some("auth-code".to_string()),
+        Some("oauth-state-expected".to_string()),
+    )
+    .await
+    .expect_err("pending state is single use");
+    assert!(matches!(replayed, InfraError::OAuth(ref message) if message == "state mismatch"));
+}
+
+#[test]
+fn omitted_account_id_resolves_to_configured_default_account() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let app_config_path = state.config_dir().join("app.json");
+    let app_raw = fs::read_to_string(&app_config_path).expect("read app config");
+    let mut app_config: serde_json::Value =
+        serde_json::from_str(&app_raw).expect("parse app config");
+    app_config["defaultAccountId"] = serde_json::Value::String("me@example.com".to_string());
+    fs::write(
+        &app_config_path,
+        serde_json::to_string_pretty(&app_config).expect("serialize app config"),
+    )
+    .expect("write app config");
+    let config = load_oauth_config_from_lookup(|key| match key {
+        "POMBLOCK_GOOGLE_CLIENT_ID" => Some("client-123.apps.googleusercontent.com".to_string()),
+        "POMBLOCK_GOOGLE_CLIENT_SECRET" => Some("secret".to_string()),
+        _ => None,
+    })
+    .expect("oauth config");
+
+    let response =
+        build_authorization_url_with_config(&state, None, config.clone()).expect("default url");
+    assert_eq!(response.account_id, "me@example.com");
+    assert_eq!(
+        state.take_oauth_state("me@example.com").expect("pending state"),
+        Some(response.state)
+    );
+
+    let explicit = build_authorization_url_with_config(&state, Some(" work ".to_string()), config)
+        .expect("explicit url");
+    assert_eq!(explicit.account_id, "work");
+}
+
+#[test]
+fn loopback_listener_reports_busy_port() {
+    let occupied = std::net::TcpListener::bind(("127.0.0.1", 0)).expect("bind occupied port");
+    let port = occupied.local_addr().expect("local addr").port();
+    let redirect = parse_loopback_redirect(&format!("http://127.0.0.1:{port}/oauth2/callback"))
+        .expect("parse redirect");
+
+    match bind_loopback_listener(&redirect) {
+        Err(InfraError::OAuth(message)) => {
+            assert_eq!(
+                message,
+                format!("loopback port {port} in use; close the other instance")
+            );
+        }
+        _ => panic!("expected loopback port in use error"),
+    }
+}
+
+#[test]
+fn loopback_listener_binds_ephemeral_port_for_port_zero() {
+    let redirect =
+        parse_loopback_redirect("http://localhost:0/oauth2/callback").expect("parse redirect");
+
+    let listener = bind_loopback_listener(&redirect).expect("bind ephemeral port");
+
+    assert_ne!(listener.local_addr().expect("local addr").port(), 0);
+}
+
+#[test]
+fn loopback_callback_wait_returns_promptly_when_cancelled() {
+    let redirect =
+        parse_loopback_redirect("http://127.0.0.1:0/oauth2/callback").expect("parse redirect");
+    let listener = bind_loopback_listener(&redirect).expect("bind ephemeral port");
+    let cancel_flag = std::sync::atomic::AtomicBool::new(true);
+    let started_at = std::time::Instant::now();
+
+    let result = wait_for_loopback_callback_blocking(
+        listener,
+        &redirect,
+        "state",
+        std::time::Duration::from_secs(60),
+        &cancel_flag,
+    );
+
+    match result {
+        Err(InfraError::OAuth(message)) => assert!(message.contains("cancelled")),
+        _ => panic!("expected cancelled oauth error"),
+    }
+    assert!(started_at.elapsed() < std::time::Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn reauthenticate_account_requires_an_account_id() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+
+    let error = reauthenticate_account_impl(&state, "  ".to_string())
+        .await
+        .expect_err("blank account id");
+
+    assert!(matches!(error, InfraError::InvalidConfig(message) if message.contains("account_id")));
+}
+
+#[tokio::test]
+async fn completed_reauthentication_replaces_the_stored_token_and_records_the_time() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let previously_authenticated_at = Utc
+        .with_ymd_and_hms(2026, 1, 5, 8, 0, 0)
+        .single()
+        .expect("valid timestamp");
+    let store = Arc::new(InMemoryCredentialStore::default());
+    store
+        .save_token(&OAuthToken {
+            access_token: "revoked-access".to_string(),
+            refresh_token: Some("revoked-refresh".to_string()),
+            expires_at: previously_authenticated_at,
+            token_type: "Bearer".to_string(),
+            scope: None,
+            authenticated_at: Some(previously_authenticated_at),
+        })
+        .expect("seed stored account token");
+    let manager = OAuthManager::new(
+        OAuthConfig::new(
+            "client",
+            "secret",
+            "http://127.0.0.1:0/oauth2/callback",
+            vec!["https://www.googleapis.com/auth/calendar".to_string()],
+        ),
+        Arc::clone(&store),
+        Arc::new(CodeExchangingOAuthClient),
+    );
+
+    let response = complete_sso_sign_in(&state, &manager, "work".to_string(), "code-1")
+        .await
+        .expect("complete sign-in");
+
+    assert_eq!(response.account_id, "work");
+    assert_eq!(response.status, "authenticated");
+    let stored = store
+        .load_token()
+        .expect("load token")
+        .expect("stored token");
+    assert_eq!(stored.access_token, "access-for-code-1");
+    assert_eq!(response.expires_at, Some(stored.expires_at.to_rfc3339()));
+    assert!(stored
+        .authenticated_at
+        .is_some_and(|authenticated_at| authenticated_at > previously_authenticated_at));
+}
+
+#[test]
+fn cancel_sso_flags_only_pending_attempts() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    assert!(!cancel_sso_impl(&state).expect("cancel without attempt"));
+
+    let flag = state.begin_sso_attempt().expect("begin attempt");
+    assert!(cancel_sso_impl(&state).expect("cancel pending attempt"));
+    assert!(flag.load(std::sync::atomic::Ordering::SeqCst));
+    assert!(!cancel_sso_impl(&state).expect("cancel after attempt"));
+}
+
 #[test]
 fn list_synced_events_filters_by_window_and_ignores_cancelled_events() {
     let workspace = TempWorkspace::new();
@@ -42,6 +358,7 @@ fn list_synced_events_filters_by_window_and_ignores_cancelled_events() {
                         time_zone: None,
                     },
                     extended_properties: None,
+                    ..Default::default()
                 },
                 GoogleCalendarEvent {
                     id: Some("evt-cancelled".to_string()),
@@ -59,6 +376,7 @@ fn list_synced_events_filters_by_window_and_ignores_cancelled_events() {
                         time_zone: None,
                     },
                     extended_properties: None,
+                    ..Default::default()
                 },
             ],
         );
@@ -79,3 +397,230 @@ fn list_synced_events_filters_by_window_and_ignores_cancelled_events() {
     assert_eq!(listed[0].start_at, "2026-02-16T09:00:00+00:00");
     assert_eq!(listed[0].end_at, "2026-02-16T10:00:00+00:00");
 }
+
+#[test]
+fn list_synced_events_range_returns_events_for_every_day_in_the_range() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let app_config_path = state.config_dir().join("app.json");
+    let mut app_config: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&app_config_path).expect("read app config"))
+            .expect("parse app config");
+    app_config["timezone"] = serde_json::Value::String("UTC".to_string());
+    fs::write(&app_config_path, app_config.to_string()).expect("write app config");
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        runtime.synced_events_by_account.insert(
+            DEFAULT_ACCOUNT_ID.to_string(),
+            vec![
+                busy_event("evt-wed", "2026-02-18T15:00:00Z", "2026-02-18T16:00:00Z"),
+                busy_event("evt-mon", "2026-02-16T09:00:00Z", "2026-02-16T10:00:00Z"),
+                busy_event("evt-tue", "2026-02-17T23:00:00Z", "2026-02-17T23:30:00Z"),
+                busy_event("evt-thu", "2026-02-19T09:00:00Z", "2026-02-19T10:00:00Z"),
+            ],
+        );
+    }
+
+    let listed = list_synced_events_range_impl(
+        &state,
+        None,
+        "2026-02-16".to_string(),
+        "2026-02-18".to_string(),
+    )
+    .expect("list synced events range");
+
+    let ids = listed.iter().map(|event| event.id.as_str()).collect::<Vec<_>>();
+    assert_eq!(ids, vec!["evt-mon", "evt-tue", "evt-wed"]);
+
+    let reversed = list_synced_events_range_impl(
+        &state,
+        None,
+        "2026-02-18".to_string(),
+        "2026-02-16".to_string(),
+    );
+    assert!(reversed.is_err());
+}
+
+#[tokio::test]
+async fn account_sync_lock_serializes_overlapping_syncs_for_same_account() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+
+    let simulated_sync = |event_id: &'static str| {
+        let state = &state;
+        async move {
+            with_account_sync_lock(state, DEFAULT_ACCOUNT_ID, || async move {
+                let mut events = state
+                    .synced_events_snapshot(Some(DEFAULT_ACCOUNT_ID))?
+                    .into_iter()
+                    .flat_map(|(_, events)| events)
+                    .collect::<Vec<_>>();
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                events.push(GoogleCalendarEvent {
+                    id: Some(event_id.to_string()),
+                    status: Some("confirmed".to_string()),
+                    ..Default::default()
+                });
+                state.replace_synced_events(DEFAULT_ACCOUNT_ID, events, "blocks-calendar")?;
+                Ok(())
+            })
+            .await
+            .expect("sync under account lock")
+        }
+    };
+
+    tokio::join!(simulated_sync("evt-timer"), simulated_sync("evt-manual"));
+
+    let synced = state
+        .synced_events_snapshot(Some(DEFAULT_ACCOUNT_ID))
+        .expect("snapshot");
+    let mut ids = synced[0]
+        .1
+        .iter()
+        .filter_map(|event| event.id.clone())
+        .collect::<Vec<_>>();
+    ids.sort();
+    assert_eq!(ids, vec!["evt-manual".to_string(), "evt-timer".to_string()]);
+}
+
+#[tokio::test]
+async fn account_sync_lock_does_not_block_other_accounts() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+
+    let primary = state.account_sync_lock("primary").expect("sync lock");
+    let _primary_guard = primary.lock().await;
+    let secondary = state.account_sync_lock("secondary").expect("sync lock");
+
+    assert!(secondary.try_lock().is_ok());
+    assert!(state
+        .account_sync_lock("primary")
+        .expect("sync lock")
+        .try_lock()
+        .is_err());
+}
+
+#[test]
+fn list_synced_events_reads_cached_events_after_restart() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    state
+        .replace_synced_events(
+            DEFAULT_ACCOUNT_ID,
+            vec![busy_event("evt-cached", "2026-02-16T09:00:00Z", "2026-02-16T10:00:00Z")],
+            "blocks-calendar",
+        )
+        .expect("replace synced events");
+    drop(state);
+
+    let restarted = workspace.app_state();
+    let listed = crate::application::commands::calendar::list_synced_events_impl(
+        &restarted,
+        None,
+        Some("2026-02-16T00:00:00Z".to_string()),
+        Some("2026-02-17T00:00:00Z".to_string()),
+    )
+    .expect("list synced events");
+
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, "evt-cached");
+    assert_eq!(
+        lock_runtime(&restarted)
+            .expect("runtime lock")
+            .blocks_calendar_ids
+            .get(DEFAULT_ACCOUNT_ID)
+            .map(String::as_str),
+        Some("blocks-calendar")
+    );
+}
+
+#[tokio::test]
+async fn synced_block_event_summaries_become_block_titles() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    let renamed = GoogleCalendarEvent {
+        summary: Some("Write the quarterly report".to_string()),
+        ..encode_block_event_with_task(&generated[0], None)
+    };
+    let untouched = encode_block_event_with_task(&generated[1], None);
+
+    import_synced_events(&state, DEFAULT_ACCOUNT_ID, vec![renamed, untouched], "blocks-calendar")
+        .expect("import synced events");
+
+    let listed = list_blocks_impl(&state, Some("2026-02-16".to_string()), None).expect("list");
+    let title_of = |block_id: &str| {
+        listed
+            .iter()
+            .find(|block| block.id == block_id)
+            .and_then(|block| block.title.clone())
+    };
+    assert_eq!(title_of(&generated[0].id), Some("Write the quarterly report".to_string()));
+    assert_eq!(title_of(&generated[1].id), None);
+}
+
+#[tokio::test]
+async fn reset_sync_clears_only_the_accounts_cached_events_and_sync_token() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let event = busy_event("evt-stale", "2026-02-16T09:00:00Z", "2026-02-16T10:00:00Z");
+    for account_id in [DEFAULT_ACCOUNT_ID, "work"] {
+        state
+            .replace_synced_events(account_id, vec![event.clone()], "blocks-calendar")
+            .expect("replace synced events");
+        SqliteSyncStateRepository::new(state.database_path(), account_id)
+            .save(Some("token-1"), chrono::Utc::now())
+            .expect("save sync token");
+    }
+
+    let mismatch = reset_sync_impl(&state, None, "other-calendar".to_string())
+        .await
+        .expect_err("mismatched calendar");
+    assert!(matches!(mismatch, InfraError::InvalidConfig(_)));
+
+    reset_sync_impl(&state, None, "blocks-calendar".to_string())
+        .await
+        .expect("reset sync");
+
+    let sync_token = |account_id: &str| {
+        SqliteSyncStateRepository::new(state.database_path(), account_id)
+            .load()
+            .expect("load sync state")
+            .and_then(|sync_state| sync_state.sync_token)
+    };
+    assert_eq!(sync_token(DEFAULT_ACCOUNT_ID), None);
+    assert_eq!(sync_token("work"), Some("token-1".to_string()));
+    assert!(state
+        .calendar_cache(DEFAULT_ACCOUNT_ID)
+        .list()
+        .expect("list persisted cache")
+        .is_empty());
+    assert_eq!(state.calendar_cache("work").list().expect("list work cache").len(), 1);
+    assert!(state
+        .synced_events_snapshot(Some(DEFAULT_ACCOUNT_ID))
+        .expect("snapshot")
+        .is_empty());
+    assert_eq!(state.synced_events_snapshot(Some("work")).expect("snapshot").len(), 1);
+}
+
+#[tokio::test]
+async fn reset_sync_waits_for_a_running_sync_of_the_account() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let sync_lock = state.account_sync_lock(DEFAULT_ACCOUNT_ID).expect("sync lock");
+    let running_sync = sync_lock.lock().await;
+
+    let blocked = tokio::time::timeout(
+        std::time::Duration::from_millis(20),
+        reset_sync_impl(&state, None, "blocks-calendar".to_string()),
+    )
+    .await;
+    assert!(blocked.is_err(), "reset must wait for the running sync");
+
+    drop(running_sync);
+    reset_sync_impl(&state, None, "blocks-calendar".to_string())
+        .await
+        .expect("reset after sync");
+}
@@ -1,4 +1,6 @@
 pub(crate) use crate::application::commands::auth::{
-    load_oauth_config_from_lookup, DEFAULT_ACCOUNT_ID,
+    bind_loopback_listener, build_authorization_url_with_config, complete_sso_sign_in,
+    load_oauth_config_from_lookup, parse_loopback_redirect, scopes_allow_calendar_writes,
+    wait_for_loopback_callback_blocking, DEFAULT_ACCOUNT_ID,
 };
 pub(crate) use crate::infrastructure::error::InfraError;
@@ -1,18 +1,32 @@
-use super::auth_support::DEFAULT_ACCOUNT_ID;
+use super::auth_support::{InfraError, DEFAULT_ACCOUNT_ID};
 use super::block_support::{
-    intervals_overlap, save_suppression, Block, DateTime, Interval, NaiveDate, Utc,
-    BLOCK_GENERATION_TARGET_MS,
+    auto_relocate_after_sync, busy_event, generate_blocks_at, intervals_overlap,
+    remaining_capacity, run_auto_generation_with_clock, run_startup_catch_up, save_suppression,
+    Block, DateTime, Interval, NaiveDate, SchedulerClock, Utc, BLOCK_GENERATION_TARGET_MS,
+    FOCUS_MINUTES_PER_POMODORO,
 };
 use super::runtime_support::{lock_runtime, StoredBlock};
+use crate::application::test_support::config_fs::allow_out_of_date_pomodoro_start;
 use crate::application::test_support::workspace::TempWorkspace;
 use crate::application::commands::{
-    adjust_block_time_impl, approve_blocks_impl, delete_block_impl, generate_blocks_impl,
-    generate_one_block_impl, list_blocks_impl, relocate_if_needed_impl,
+    add_holiday_impl, adjust_block_time_impl, approve_blocks_impl, clear_day_impl, create_task_impl,
+    delete_block_impl, export_blocks_ics_impl, export_day_markdown_impl,
+    generate_blocks_impl, generate_from_source_impl, get_blocks_localized_impl,
+    generate_one_block_impl, get_overlapping_blocks_impl, get_week_capacity_impl, list_blocks_impl,
+    reflow_day_impl, reschedule_block_impl, reset_day_impl, get_busy_summary_impl,
+    list_tasks_impl, regenerate_auto_blocks_impl, remove_holiday_impl,
+    relocate_blocks_impl, relocate_if_needed_impl, set_block_project_impl, snooze_block_impl,
+    start_pomodoro_impl,
 };
+#[cfg(feature = "debug-tools")]
+use crate::application::commands::get_block_calendar_event_impl;
 use crate::domain::models::{AutoDriveMode, BlockContents, Firmness};
-use crate::infrastructure::event_mapper::{CalendarEventDateTime, GoogleCalendarEvent};
-use chrono::{Duration, NaiveTime, TimeZone};
+use crate::infrastructure::event_mapper::{
+    CalendarEventAttendee, CalendarEventDateTime, GoogleCalendarEvent,
+};
+use chrono::{Duration, NaiveTime, TimeZone, Timelike};
 use std::fs;
+use std::sync::Mutex;
 use std::time::Instant;
 
 #[tokio::test]
@@ -33,6 +47,106 @@ async fn generate_and_approve_blocks_flow() {
     assert_eq!(approved[0].firmness, Firmness::Soft);
 }
 
+#[tokio::test]
+async fn auto_approve_generates_soft_blocks_without_approval() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("policies.json"),
+        r#"{"generation":{"autoApprove":true}}"#,
+    )
+    .expect("write policies");
+
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    assert!(!generated.is_empty());
+    assert!(generated.iter().all(|block| block.firmness == Firmness::Soft));
+
+    let stored =
+        list_blocks_impl(&state, Some("2026-02-16".to_string()), None).expect("list blocks");
+    assert_eq!(stored.len(), generated.len());
+    assert!(stored.iter().all(|block| block.firmness == Firmness::Soft));
+}
+
+#[tokio::test]
+async fn round_start_to_minutes_snaps_auto_blocks_to_quarter_hours() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("policies.json"),
+        r#"{"blockDurationMinutes":50,"minAutoBlockMinutes":50,"minBlockGapMinutes":7,
+            "generation":{"roundStartToMinutes":15}}"#,
+    )
+    .expect("write policies");
+
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+
+    assert!(generated.len() > 1);
+    assert!(generated
+        .iter()
+        .all(|block| block.start_at.minute() % 15 == 0 && block.start_at.second() == 0));
+}
+
+#[tokio::test]
+async fn holidays_generate_no_blocks() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let holidays = add_holiday_impl(&state, "2026-02-16".to_string()).expect("add holiday");
+    assert_eq!(holidays, vec!["2026-02-16".to_string()]);
+
+    let holiday = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate holiday");
+    let next_day = generate_blocks_impl(&state, "2026-02-17".to_string(), None)
+        .await
+        .expect("generate next day");
+
+    assert!(holiday.is_empty());
+    assert!(!next_day.is_empty());
+}
+
+#[test]
+fn malformed_holidays_file_is_rejected_and_left_unchanged() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let path = state.config_dir().join("holidays.json");
+    let malformed = "[\"2026-02-16\", \"next tuesday\"]\n";
+    fs::write(&path, malformed).expect("write holidays");
+
+    let added = add_holiday_impl(&state, "2026-02-17".to_string());
+    let error = added.expect_err("malformed holidays must be rejected");
+    assert!(
+        matches!(error, InfraError::InvalidConfig(ref message) if message.contains("holidays.json"))
+    );
+    let removed = remove_holiday_impl(&state, "2026-02-16".to_string());
+    assert!(matches!(removed, Err(InfraError::InvalidConfig(_))));
+    assert_eq!(fs::read_to_string(&path).expect("read holidays"), malformed);
+}
+
+#[tokio::test]
+async fn generation_skips_valid_holidays_next_to_invalid_entries() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("holidays.json"),
+        "[\"2026-02-16\", \"next tuesday\"]\n",
+    )
+    .expect("write holidays");
+
+    let holiday = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate holiday");
+    let next_day = generate_blocks_impl(&state, "2026-02-17".to_string(), None)
+        .await
+        .expect("generate next day");
+
+    assert!(holiday.is_empty());
+    assert!(!next_day.is_empty());
+}
+
 #[tokio::test]
 async fn generate_blocks_rejects_invalid_date() {
     let workspace = TempWorkspace::new();
@@ -49,6 +163,7 @@ async fn generate_blocks_respects_suppressions() {
         state.database_path(),
         "rtn:auto:2026-02-16:0",
         Some("test_suppression"),
+        None,
     )
     .expect("save suppression");
 
@@ -62,6 +177,37 @@ async fn generate_blocks_respects_suppressions() {
         .all(|block| block.instance != "rtn:auto:2026-02-16:0"));
 }
 
+#[tokio::test]
+async fn generate_blocks_ignores_expired_suppressions() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    save_suppression(
+        state.database_path(),
+        "rtn:auto:2026-02-16:0",
+        Some("test_suppression"),
+        Some(Utc::now() - Duration::minutes(1)),
+    )
+    .expect("save expired suppression");
+    save_suppression(
+        state.database_path(),
+        "rtn:auto:2026-02-16:1",
+        Some("test_suppression"),
+        Some(Utc::now() + Duration::days(1)),
+    )
+    .expect("save active suppression");
+
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+
+    assert!(generated
+        .iter()
+        .any(|block| block.instance == "rtn:auto:2026-02-16:0"));
+    assert!(generated
+        .iter()
+        .all(|block| block.instance != "rtn:auto:2026-02-16:1"));
+}
+
 #[tokio::test]
 async fn generate_blocks_regenerates_after_all_blocks_deleted_for_date() {
     let workspace = TempWorkspace::new();
@@ -73,7 +219,7 @@ async fn generate_blocks_regenerates_after_all_blocks_deleted_for_date() {
     assert_eq!(generated.len(), 9);
 
     for block in generated {
-        let deleted = delete_block_impl(&state, block.id.clone())
+        let deleted = delete_block_impl(&state, block.id.clone(), None)
             .await
             .expect("delete generated block");
         assert!(deleted);
@@ -98,7 +244,7 @@ async fn generate_blocks_refills_gap_after_single_block_deleted() {
         .expect("initial generation");
     generated.sort_by(|left, right| left.start_at.cmp(&right.start_at));
     let removed = generated[4].clone();
-    let deleted = delete_block_impl(&state, removed.id.clone())
+    let deleted = delete_block_impl(&state, removed.id.clone(), None)
         .await
         .expect("delete one generated block");
     assert!(deleted);
@@ -110,7 +256,7 @@ async fn generate_blocks_refills_gap_after_single_block_deleted() {
     assert_eq!(refill[0].start_at, removed.start_at);
     assert_eq!(refill[0].end_at, removed.end_at);
 
-    let listed = list_blocks_impl(&state, Some("2026-02-16".to_string())).expect("list blocks");
+    let listed = list_blocks_impl(&state, Some("2026-02-16".to_string()), None).expect("list blocks");
     assert_eq!(listed.len(), 9);
 }
 
@@ -158,7 +304,7 @@ async fn generate_one_block_adds_single_block_per_call() {
         .expect("generate second block");
     assert_eq!(second.len(), 1);
 
-    let listed = list_blocks_impl(&state, Some("2026-02-16".to_string())).expect("list blocks");
+    let listed = list_blocks_impl(&state, Some("2026-02-16".to_string()), None).expect("list blocks");
     assert_eq!(listed.len(), 2);
 }
 
@@ -190,7 +336,7 @@ async fn generate_one_block_allows_overlap_when_day_is_full() {
         )
     }));
 
-    let listed = list_blocks_impl(&state, Some("2026-02-16".to_string())).expect("list blocks");
+    let listed = list_blocks_impl(&state, Some("2026-02-16".to_string()), None).expect("list blocks");
     assert_eq!(listed.len(), 10);
 }
 
@@ -237,6 +383,168 @@ async fn generate_blocks_respects_max_auto_blocks_per_day() {
         .all(|block| block.instance.starts_with("rtn:auto:")));
 }
 
+#[tokio::test]
+async fn template_blocks_do_not_reduce_auto_block_allowance() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("policies.json"),
+        r#"{
+  "schema": 1,
+  "workHours": {
+    "start": "09:00",
+    "end": "17:00",
+    "days": ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday"]
+  },
+  "generation": {
+    "maxAutoBlocksPerDay": 2
+  },
+  "blockDurationMinutes": 60,
+  "breakDurationMinutes": 5,
+  "minBlockGapMinutes": 0
+}
+"#,
+    )
+    .expect("write policies config");
+    fs::write(
+        state.config_dir().join("templates.json"),
+        r#"{
+  "templates": [
+    { "id": "tpl-a", "name": "A", "start": "09:00", "durationMinutes": 60, "firmness": "soft", "plannedPomodoros": 2 },
+    { "id": "tpl-b", "name": "B", "start": "10:00", "durationMinutes": 60, "firmness": "soft", "plannedPomodoros": 2 },
+    { "id": "tpl-c", "name": "C", "start": "11:00", "durationMinutes": 60, "firmness": "soft", "plannedPomodoros": 2 }
+  ]
+}
+"#,
+    )
+    .expect("write templates config");
+
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+
+    let template_count = generated
+        .iter()
+        .filter(|block| block.instance.starts_with("tpl:"))
+        .count();
+    let auto_count = generated
+        .iter()
+        .filter(|block| block.instance.starts_with("rtn:auto:"))
+        .count();
+    assert_eq!(template_count, 3);
+    assert_eq!(auto_count, 2);
+
+    let regenerated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks again");
+    assert!(regenerated
+        .iter()
+        .all(|block| !block.instance.starts_with("rtn:auto:")));
+}
+
+#[tokio::test]
+async fn generate_blocks_skips_quiet_hours_inside_work_window() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let policies_path = state.config_dir().join("policies.json");
+    fs::write(
+        &policies_path,
+        r#"{
+  "schema": 1,
+  "workHours": {
+    "start": "09:00",
+    "end": "17:00",
+    "days": ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday"]
+  },
+  "quietHours": {
+    "start": "12:00",
+    "end": "14:00"
+  },
+  "blockDurationMinutes": 60,
+  "breakDurationMinutes": 5,
+  "minBlockGapMinutes": 0
+}
+"#,
+    )
+    .expect("write policies config");
+
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+
+    assert_eq!(generated.len(), 6);
+    let quiet = Interval {
+        start: Utc.with_ymd_and_hms(2026, 2, 16, 12, 0, 0).single().expect("quiet start"),
+        end: Utc.with_ymd_and_hms(2026, 2, 16, 14, 0, 0).single().expect("quiet end"),
+    };
+    for block in &generated {
+        let interval = Interval {
+            start: block.start_at,
+            end: block.end_at,
+        };
+        assert!(!intervals_overlap(&interval, &quiet));
+    }
+}
+
+#[tokio::test]
+async fn generate_blocks_fills_trailing_gap_with_short_block_when_min_duration_allows() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let policies_path = state.config_dir().join("policies.json");
+    fs::write(
+        &policies_path,
+        r#"{
+  "schema": 1,
+  "workHours": {
+    "start": "09:00",
+    "end": "17:40",
+    "days": ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday"]
+  },
+  "blockDurationMinutes": 60,
+  "breakDurationMinutes": 5,
+  "minBlockGapMinutes": 0,
+  "minAutoBlockMinutes": 30
+}
+"#,
+    )
+    .expect("write policies config");
+
+    let mut generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    generated.sort_by_key(|block| block.start_at);
+
+    assert_eq!(generated.len(), 9);
+    let last = generated.last().expect("last block");
+    assert_eq!(last.start_at.to_rfc3339(), "2026-02-16T17:00:00+00:00");
+    assert_eq!(last.end_at.to_rfc3339(), "2026-02-16T17:40:00+00:00");
+    assert_eq!(last.planned_pomodoros, 1);
+}
+
+#[tokio::test]
+async fn localized_blocks_carry_the_policy_timezone_offset() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let app_config_path = state.config_dir().join("app.json");
+    let app_raw = fs::read_to_string(&app_config_path).expect("read app config");
+    let mut app_config: serde_json::Value =
+        serde_json::from_str(&app_raw).expect("parse app config");
+    app_config["timezone"] = serde_json::Value::String("Asia/Tokyo".to_string());
+    fs::write(&app_config_path, app_config.to_string()).expect("write app config");
+    generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+
+    let localized = get_blocks_localized_impl(&state, Some("2026-02-16".to_string()), None)
+        .expect("localized blocks");
+
+    assert!(!localized.is_empty());
+    let first = &localized[0];
+    assert_eq!(first.start_local, "2026-02-16T09:00:00+09:00");
+    assert!(first.end_local.ends_with("+09:00"));
+    assert_eq!(first.block.start_at.to_rfc3339(), "2026-02-16T00:00:00+00:00");
+}
+
 #[tokio::test]
 async fn generate_blocks_uses_configured_timezone() {
     let workspace = TempWorkspace::new();
@@ -325,72 +633,271 @@ async fn generate_blocks_uses_templates_and_routines_when_configured() {
 }
 
 #[tokio::test]
-async fn relocate_if_needed_moves_block_when_conflicting_event_exists() {
+async fn template_and_routine_at_the_same_time_keep_only_the_firmer_plan() {
     let workspace = TempWorkspace::new();
     let state = workspace.app_state();
-    let block = Block {
-        id: "blk-relocate".to_string(),
-        instance: "rtn:auto:2026-02-16:0".to_string(),
-        date: "2026-02-16".to_string(),
-        start_at: DateTime::parse_from_rfc3339("2026-02-16T09:00:00Z")
-            .expect("start")
-            .with_timezone(&Utc),
-        end_at: DateTime::parse_from_rfc3339("2026-02-16T09:50:00Z")
-            .expect("end")
-            .with_timezone(&Utc),
-        firmness: Firmness::Draft,
-        planned_pomodoros: 2,
-        source: "routine".to_string(),
-        source_id: Some("auto".to_string()),
-        recipe_id: "rcp-default".to_string(),
-        auto_drive_mode: AutoDriveMode::Manual,
-        contents: BlockContents::default(),
-    };
+    fs::write(
+        state.config_dir().join("templates.json"),
+        r#"{
+  "templates": [
+    { "id": "focus-morning", "start": "09:00", "durationMinutes": 50, "firmness": "draft" }
+  ]
+}
+"#,
+    )
+    .expect("write templates config");
+    fs::write(
+        state.config_dir().join("routines.json"),
+        r#"{
+  "schema": 1,
+  "routines": [
     {
-        let mut runtime = lock_runtime(&state).expect("runtime lock");
-        runtime.blocks.insert(
-            block.id.clone(),
-            StoredBlock {
-                block: block.clone(),
-                calendar_event_id: None,
-                calendar_account_id: Some(DEFAULT_ACCOUNT_ID.to_string()),
-            },
-        );
-        runtime.synced_events_by_account.insert(
-            DEFAULT_ACCOUNT_ID.to_string(),
-            vec![GoogleCalendarEvent {
-                id: Some("evt-conflict".to_string()),
-                summary: Some("conflict".to_string()),
-                description: None,
-                status: Some("confirmed".to_string()),
-                updated: None,
-                etag: None,
-                start: CalendarEventDateTime {
-                    date_time: "2026-02-16T09:10:00Z".to_string(),
-                    time_zone: None,
-                },
-                end: CalendarEventDateTime {
-                    date_time: "2026-02-16T09:40:00Z".to_string(),
-                    time_zone: None,
-                },
-                extended_properties: None,
-            }],
-        );
+      "id": "daily-admin",
+      "rrule": "FREQ=DAILY",
+      "default": { "start": "09:00", "durationMinutes": 50 },
+      "firmness": "hard"
     }
+  ]
+}
+"#,
+    )
+    .expect("write routines config");
 
-    let relocated = relocate_if_needed_impl(&state, block.id.clone(), None)
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
         .await
-        .expect("relocate")
-        .expect("block relocated");
+        .expect("generate blocks");
 
-    assert_eq!(relocated.id, block.id);
-    let conflict_end = DateTime::parse_from_rfc3339("2026-02-16T09:40:00Z")
-        .expect("conflict end")
-        .with_timezone(&Utc);
+    let configured = generated
+        .iter()
+        .filter(|block| !block.instance.starts_with("rtn:auto:"))
+        .map(|block| block.instance.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(configured, vec!["rtn:daily-admin:2026-02-16"]);
+}
+
+#[tokio::test]
+async fn regenerate_auto_blocks_keeps_templated_blocks_and_rebuilds_auto_blocks() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("templates.json"),
+        r#"{
+  "templates": [
+    {
+      "id": "focus-morning",
+      "name": "Focus Morning",
+      "start": "09:00",
+      "durationMinutes": 50,
+      "firmness": "soft",
+      "plannedPomodoros": 2
+    }
+  ]
+}
+"#,
+    )
+    .expect("write templates config");
+
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    let templated = generated
+        .iter()
+        .find(|block| block.instance == "tpl:focus-morning:2026-02-16")
+        .cloned()
+        .expect("templated block");
+    let original_auto_ids = generated
+        .iter()
+        .filter(|block| block.instance.starts_with("rtn:auto:"))
+        .map(|block| block.id.clone())
+        .collect::<Vec<_>>();
+    assert!(!original_auto_ids.is_empty());
+
+    let regenerated = regenerate_auto_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("regenerate auto blocks");
+
+    assert_eq!(regenerated.len(), original_auto_ids.len());
+    assert!(regenerated.iter().all(|block| {
+        block.instance.starts_with("rtn:auto:2026-02-16:")
+            && !original_auto_ids.contains(&block.id)
+    }));
+
+    let listed = list_blocks_impl(&state, Some("2026-02-16".to_string()), None).expect("list blocks");
+    assert_eq!(listed.len(), regenerated.len() + 1);
+    assert!(listed
+        .iter()
+        .any(|block| block.id == templated.id && block.instance == templated.instance));
+    assert!(listed
+        .iter()
+        .all(|block| !original_auto_ids.contains(&block.id)));
+}
+
+#[tokio::test]
+async fn regenerate_auto_blocks_keeps_other_accounts_and_undeleted_events() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let mut generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    generated.retain(|block| block.instance.starts_with("rtn:auto:"));
+    assert!(generated.len() >= 3, "at least three auto blocks expected");
+    generated.sort_by_key(|block| block.start_at);
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        runtime
+            .blocks
+            .get_mut(generated[0].id.as_str())
+            .expect("work block")
+            .calendar_account_id = Some("work".to_string());
+        // Without a token this event cannot be deleted, so its block has to stay.
+        runtime
+            .blocks
+            .get_mut(generated[1].id.as_str())
+            .expect("synced block")
+            .calendar_event_id = Some("evt-synced".to_string());
+    }
+
+    let regenerated = regenerate_auto_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("regenerate auto blocks");
+
+    let runtime = lock_runtime(&state).expect("runtime lock");
+    assert!(runtime.blocks.contains_key(generated[0].id.as_str()));
+    assert!(runtime.blocks.contains_key(generated[1].id.as_str()));
+    assert!(generated[2..]
+        .iter()
+        .all(|block| !runtime.blocks.contains_key(block.id.as_str())));
+    assert!(regenerated
+        .iter()
+        .all(|block| generated.iter().all(|original| original.id != block.id)));
+}
+
+#[tokio::test]
+async fn relocate_if_needed_moves_block_when_conflicting_event_exists() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let block = Block {
+        id: "blk-relocate".to_string(),
+        instance: "rtn:auto:2026-02-16:0".to_string(),
+        date: "2026-02-16".to_string(),
+        start_at: DateTime::parse_from_rfc3339("2026-02-16T09:00:00Z")
+            .expect("start")
+            .with_timezone(&Utc),
+        end_at: DateTime::parse_from_rfc3339("2026-02-16T09:50:00Z")
+            .expect("end")
+            .with_timezone(&Utc),
+        firmness: Firmness::Draft,
+        planned_pomodoros: 2,
+        source: "routine".to_string(),
+        source_id: Some("auto".to_string()),
+        project: None,
+        title: None,
+        recipe_id: "rcp-default".to_string(),
+        auto_drive_mode: AutoDriveMode::Manual,
+        contents: BlockContents::default(),
+    };
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        runtime.blocks.insert(
+            block.id.clone(),
+            StoredBlock {
+                block: block.clone(),
+                calendar_event_id: None,
+                calendar_account_id: Some(DEFAULT_ACCOUNT_ID.to_string()),
+            },
+        );
+        runtime.synced_events_by_account.insert(
+            DEFAULT_ACCOUNT_ID.to_string(),
+            vec![GoogleCalendarEvent {
+                id: Some("evt-conflict".to_string()),
+                summary: Some("conflict".to_string()),
+                description: None,
+                status: Some("confirmed".to_string()),
+                updated: None,
+                etag: None,
+                start: CalendarEventDateTime {
+                    date_time: "2026-02-16T09:10:00Z".to_string(),
+                    time_zone: None,
+                },
+                end: CalendarEventDateTime {
+                    date_time: "2026-02-16T09:40:00Z".to_string(),
+                    time_zone: None,
+                },
+                extended_properties: None,
+                ..Default::default()
+            }],
+        );
+    }
+
+    let relocated = relocate_if_needed_impl(&state, block.id.clone(), None)
+        .await
+        .expect("relocate")
+        .expect("block relocated");
+
+    assert_eq!(relocated.id, block.id);
+    let conflict_end = DateTime::parse_from_rfc3339("2026-02-16T09:40:00Z")
+        .expect("conflict end")
+        .with_timezone(&Utc);
     assert!(relocated.start_at >= conflict_end);
     assert_eq!(relocated.end_at - relocated.start_at, block.end_at - block.start_at);
 }
 
+#[tokio::test]
+async fn auto_relocate_after_sync_reports_each_moved_block() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let block = Block {
+        id: "blk-sync-relocate".to_string(),
+        instance: "rtn:auto:2026-02-16:0".to_string(),
+        date: "2026-02-16".to_string(),
+        start_at: DateTime::parse_from_rfc3339("2026-02-16T09:00:00Z")
+            .expect("start")
+            .with_timezone(&Utc),
+        end_at: DateTime::parse_from_rfc3339("2026-02-16T10:00:00Z")
+            .expect("end")
+            .with_timezone(&Utc),
+        firmness: Firmness::Draft,
+        planned_pomodoros: 2,
+        source: "routine".to_string(),
+        source_id: Some("auto".to_string()),
+        project: None,
+        title: None,
+        recipe_id: "rcp-default".to_string(),
+        auto_drive_mode: AutoDriveMode::Manual,
+        contents: BlockContents::default(),
+    };
+    let meeting = busy_event("evt-new-meeting", "2026-02-16T09:00:00Z", "2026-02-16T09:30:00Z");
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        runtime.blocks.insert(
+            block.id.clone(),
+            StoredBlock {
+                block: block.clone(),
+                calendar_event_id: None,
+                calendar_account_id: Some(DEFAULT_ACCOUNT_ID.to_string()),
+            },
+        );
+        runtime
+            .synced_events_by_account
+            .insert(DEFAULT_ACCOUNT_ID.to_string(), vec![meeting]);
+    }
+    let changed = vec![Interval {
+        start: block.start_at,
+        end: block.start_at + Duration::minutes(30),
+    }];
+
+    let relocated = auto_relocate_after_sync(&state, DEFAULT_ACCOUNT_ID, &changed, 10)
+        .await
+        .expect("auto relocate");
+
+    assert_eq!(relocated.len(), 1);
+    assert_eq!(relocated[0].block_id, block.id);
+    assert_eq!(relocated[0].old_start, "2026-02-16T09:00:00+00:00");
+    assert_ne!(relocated[0].new_start, relocated[0].old_start);
+    let listed = list_blocks_impl(&state, Some("2026-02-16".to_string()), None).expect("list blocks");
+    assert_eq!(listed[0].start_at.to_rfc3339(), relocated[0].new_start);
+}
+
 #[tokio::test]
 async fn delete_and_adjust_block_flow() {
     let workspace = TempWorkspace::new();
@@ -410,14 +917,103 @@ async fn delete_and_adjust_block_flow() {
     .expect("adjust block");
     assert_eq!(shifted.start_at.to_rfc3339(), "2026-02-16T10:00:00+00:00");
 
-    let deleted = delete_block_impl(&state, block.id.clone())
+    let deleted = delete_block_impl(&state, block.id.clone(), None)
         .await
         .expect("delete block");
     assert!(deleted);
-    let blocks = list_blocks_impl(&state, Some("2026-02-16".to_string())).expect("list blocks");
+    let blocks = list_blocks_impl(&state, Some("2026-02-16".to_string()), None).expect("list blocks");
     assert!(blocks.into_iter().all(|candidate| candidate.id != block.id));
 }
 
+#[tokio::test]
+async fn reset_day_discards_manual_edits_and_restores_generated_schedule() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let mut canonical = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("initial generation");
+    canonical.sort_by_key(|block| block.start_at);
+
+    adjust_block_time_impl(
+        &state,
+        canonical[0].id.clone(),
+        "2026-02-16T18:00:00Z".to_string(),
+        "2026-02-16T18:50:00Z".to_string(),
+    )
+    .await
+    .expect("adjust block");
+    let deleted = delete_block_impl(&state, canonical[3].id.clone(), None)
+        .await
+        .expect("delete block");
+    assert!(deleted);
+
+    let mut reset = reset_day_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("reset day");
+    reset.sort_by_key(|block| block.start_at);
+
+    let slots = |blocks: &[Block]| {
+        blocks
+            .iter()
+            .map(|block| (block.instance.clone(), block.start_at, block.end_at))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(slots(&reset), slots(&canonical));
+    let listed =
+        list_blocks_impl(&state, Some("2026-02-16".to_string()), None).expect("list blocks");
+    assert_eq!(listed.len(), canonical.len());
+}
+
+#[tokio::test]
+async fn clear_day_only_drops_the_accounts_blocks_whose_events_are_gone() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let mut generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    assert!(generated.len() >= 3, "at least three blocks expected");
+    generated.sort_by_key(|block| block.start_at);
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        runtime
+            .blocks
+            .get_mut(generated[0].id.as_str())
+            .expect("work block")
+            .calendar_account_id = Some("work".to_string());
+        // Without a token this event cannot be deleted, so its block has to stay.
+        runtime
+            .blocks
+            .get_mut(generated[1].id.as_str())
+            .expect("synced block")
+            .calendar_event_id = Some("evt-synced".to_string());
+    }
+    let remaining_ids = || {
+        let mut ids = lock_runtime(&state)
+            .expect("runtime lock")
+            .blocks
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        ids.sort();
+        ids
+    };
+
+    let cleared = clear_day_impl(&state, "2026-02-16".to_string(), None, false)
+        .await
+        .expect("clear default account");
+    assert_eq!(cleared, generated.len() - 2);
+    let mut expected = vec![generated[0].id.clone(), generated[1].id.clone()];
+    expected.sort();
+    assert_eq!(remaining_ids(), expected);
+
+    let cleared_work =
+        clear_day_impl(&state, "2026-02-16".to_string(), Some("work".to_string()), false)
+            .await
+            .expect("clear work account");
+    assert_eq!(cleared_work, 1);
+    assert_eq!(remaining_ids(), vec![generated[1].id.clone()]);
+}
+
 #[tokio::test]
 async fn generate_to_confirm_stays_within_target_for_dense_calendar() {
     let workspace = TempWorkspace::new();
@@ -446,6 +1042,7 @@ async fn generate_to_confirm_stays_within_target_for_dense_calendar() {
                     time_zone: None,
                 },
                 extended_properties: None,
+                ..Default::default()
             }
         })
         .collect::<Vec<_>>();
@@ -461,7 +1058,7 @@ async fn generate_to_confirm_stays_within_target_for_dense_calendar() {
     let _generated = generate_blocks_impl(&state, date.to_string(), None)
         .await
         .expect("generate blocks");
-    let _listed = list_blocks_impl(&state, Some(date.to_string())).expect("list blocks");
+    let _listed = list_blocks_impl(&state, Some(date.to_string()), None).expect("list blocks");
     let elapsed_ms = started.elapsed().as_millis();
     assert!(
         elapsed_ms < BLOCK_GENERATION_TARGET_MS,
@@ -469,6 +1066,40 @@ async fn generate_to_confirm_stays_within_target_for_dense_calendar() {
     );
 }
 
+#[tokio::test]
+async fn generate_blocks_ignores_cancelled_recurring_instance() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        runtime.synced_events_by_account.insert(
+            DEFAULT_ACCOUNT_ID.to_string(),
+            vec![GoogleCalendarEvent {
+                status: Some("cancelled".to_string()),
+                recurring_event_id: Some("standup".to_string()),
+                original_start_time: Some(CalendarEventDateTime {
+                    date_time: "2026-02-16T10:00:00Z".to_string(),
+                    time_zone: None,
+                }),
+                ..busy_event(
+                    "standup_20260216T100000Z",
+                    "2026-02-16T10:00:00Z",
+                    "2026-02-16T11:00:00Z",
+                )
+            }],
+        );
+    }
+
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+
+    assert_eq!(generated.len(), 9);
+    assert!(generated
+        .iter()
+        .any(|block| block.start_at.to_rfc3339() == "2026-02-16T10:00:00+00:00"));
+}
+
 #[tokio::test]
 async fn property_8_generated_blocks_do_not_overlap_existing_events() {
     let workspace = TempWorkspace::new();
@@ -493,6 +1124,7 @@ async fn property_8_generated_blocks_do_not_overlap_existing_events() {
                     time_zone: None,
                 },
                 extended_properties: None,
+                ..Default::default()
             }],
         );
     }
@@ -563,6 +1195,7 @@ async fn property_11_generation_is_prevented_for_overlapping_time_bands() {
                     time_zone: None,
                 },
                 extended_properties: None,
+                ..Default::default()
             }],
         );
     }
@@ -573,3 +1206,1099 @@ async fn property_11_generation_is_prevented_for_overlapping_time_bands() {
 
     assert!(generated.is_empty(), "full-day overlap should block generation");
 }
+
+#[tokio::test]
+async fn export_blocks_ics_writes_one_event_per_block() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    let export_path = workspace.path().join("blocks.ics");
+
+    let written = export_blocks_ics_impl(
+        &state,
+        "2026-02-16".to_string(),
+        "2026-02-16".to_string(),
+        export_path.to_string_lossy().to_string(),
+    )
+    .expect("export blocks");
+
+    assert_eq!(written, generated.len());
+    let exported = fs::read_to_string(&export_path).expect("read exported ics");
+    assert!(exported.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(exported.ends_with("END:VCALENDAR\r\n"));
+    let events = exported
+        .split("\r\n")
+        .filter(|line| *line == "BEGIN:VEVENT")
+        .count();
+    assert_eq!(events, generated.len());
+    assert!(exported.contains("DTSTART:20260216T090000Z\r\n"));
+    assert!(exported.contains(&format!("UID:{}@pomoblock\r\n", generated[0].id)));
+}
+
+#[tokio::test]
+async fn export_day_markdown_lists_the_accounts_blocks_and_meetings_in_local_time() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let app_config_path = state.config_dir().join("app.json");
+    let mut app_config: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&app_config_path).expect("read app config"))
+            .expect("parse app config");
+    app_config["timezone"] = serde_json::Value::String("Asia/Tokyo".to_string());
+    fs::write(&app_config_path, app_config.to_string()).expect("write app config");
+    let mut generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    generated.sort_by_key(|block| block.start_at);
+    let task = create_task_impl(&state, "Draft proposal".to_string(), None, None).expect("task");
+    allow_out_of_date_pomodoro_start(state.config_dir());
+    start_pomodoro_impl(&state, generated[0].id.clone(), Some(task.id)).expect("assign task");
+    assert!(generated.len() >= 2, "at least two blocks expected");
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        runtime
+            .blocks
+            .get_mut(generated[1].id.as_str())
+            .expect("stored block")
+            .calendar_account_id = Some("work".to_string());
+        runtime.synced_events_by_account.insert(
+            DEFAULT_ACCOUNT_ID.to_string(),
+            vec![GoogleCalendarEvent {
+                summary: Some("Standup".to_string()),
+                ..busy_event("evt-standup", "2026-02-16T03:00:00Z", "2026-02-16T03:15:00Z")
+            }],
+        );
+    }
+
+    let agenda =
+        export_day_markdown_impl(&state, "2026-02-16".to_string(), None).expect("export agenda");
+
+    let bullets = agenda.lines().filter(|line| line.starts_with("- ")).collect::<Vec<_>>();
+    assert_eq!(bullets.len(), generated.len());
+    assert!(bullets[0].starts_with("- 09:00-"));
+    assert!(bullets[0].ends_with(", task: Draft proposal"));
+    assert!(bullets.contains(&"- 12:00-12:15 meeting: Standup"));
+}
+
+#[tokio::test]
+async fn hard_blocks_are_left_in_place_when_a_new_event_collides() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let block = Block {
+        id: "blk-hard".to_string(),
+        instance: "manual:blk-hard".to_string(),
+        date: "2026-02-16".to_string(),
+        start_at: DateTime::parse_from_rfc3339("2026-02-16T09:00:00Z")
+            .expect("start")
+            .with_timezone(&Utc),
+        end_at: DateTime::parse_from_rfc3339("2026-02-16T10:00:00Z")
+            .expect("end")
+            .with_timezone(&Utc),
+        firmness: Firmness::Hard,
+        planned_pomodoros: 2,
+        source: "manual".to_string(),
+        source_id: None,
+        project: None,
+        title: None,
+        recipe_id: "rcp-default".to_string(),
+        auto_drive_mode: AutoDriveMode::Manual,
+        contents: BlockContents::default(),
+    };
+    let meeting = busy_event("evt-hard-collision", "2026-02-16T09:00:00Z", "2026-02-16T09:30:00Z");
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        runtime.blocks.insert(
+            block.id.clone(),
+            StoredBlock {
+                block: block.clone(),
+                calendar_event_id: None,
+                calendar_account_id: Some(DEFAULT_ACCOUNT_ID.to_string()),
+            },
+        );
+        runtime
+            .synced_events_by_account
+            .insert(DEFAULT_ACCOUNT_ID.to_string(), vec![meeting]);
+    }
+
+    let relocated = relocate_if_needed_impl(&state, block.id.clone(), None)
+        .await
+        .expect("relocate if needed");
+    assert!(relocated.is_none());
+
+    let changed = vec![Interval {
+        start: block.start_at,
+        end: block.start_at + Duration::minutes(30),
+    }];
+    let relocated = auto_relocate_after_sync(&state, DEFAULT_ACCOUNT_ID, &changed, 10)
+        .await
+        .expect("auto relocate");
+    assert!(relocated.is_empty());
+
+    let listed = list_blocks_impl(&state, Some("2026-02-16".to_string()), None).expect("list blocks");
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].start_at, block.start_at);
+    assert_eq!(listed[0].end_at, block.end_at);
+}
+
+#[tokio::test]
+async fn reflow_day_relocates_every_conflicting_block() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let make_block = |id: &str, start: &str, end: &str| Block {
+        id: id.to_string(),
+        instance: format!("manual:{id}"),
+        date: "2026-02-16".to_string(),
+        start_at: DateTime::parse_from_rfc3339(start)
+            .expect("start")
+            .with_timezone(&Utc),
+        end_at: DateTime::parse_from_rfc3339(end)
+            .expect("end")
+            .with_timezone(&Utc),
+        firmness: Firmness::Draft,
+        planned_pomodoros: 1,
+        source: "manual".to_string(),
+        source_id: None,
+        project: None,
+        title: None,
+        recipe_id: "rcp-default".to_string(),
+        auto_drive_mode: AutoDriveMode::Manual,
+        contents: BlockContents::default(),
+    };
+    let first = make_block("blk-reflow-1", "2026-02-16T09:00:00Z", "2026-02-16T09:30:00Z");
+    let second = make_block("blk-reflow-2", "2026-02-16T10:00:00Z", "2026-02-16T10:30:00Z");
+    let imported = busy_event("evt-imported", "2026-02-16T09:00:00Z", "2026-02-16T10:30:00Z");
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        for block in [first.clone(), second.clone()] {
+            runtime.blocks.insert(
+                block.id.clone(),
+                StoredBlock {
+                    block,
+                    calendar_event_id: None,
+                    calendar_account_id: Some(DEFAULT_ACCOUNT_ID.to_string()),
+                },
+            );
+        }
+        runtime
+            .synced_events_by_account
+            .insert(DEFAULT_ACCOUNT_ID.to_string(), vec![imported]);
+    }
+
+    let relocated = reflow_day_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("reflow day");
+
+    let mut relocated_ids = relocated
+        .iter()
+        .map(|block| block.id.as_str())
+        .collect::<Vec<_>>();
+    relocated_ids.sort();
+    assert_eq!(relocated_ids, vec!["blk-reflow-1", "blk-reflow-2"]);
+    let busy = Interval {
+        start: first.start_at,
+        end: second.end_at,
+    };
+    for block in &relocated {
+        let interval = Interval {
+            start: block.start_at,
+            end: block.end_at,
+        };
+        assert!(!intervals_overlap(&interval, &busy));
+    }
+}
+
+#[test]
+fn get_overlapping_blocks_reports_only_overlapping_pairs() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let make_block = |id: &str, start: &str, end: &str| Block {
+        id: id.to_string(),
+        instance: format!("manual:{id}"),
+        date: "2026-02-16".to_string(),
+        start_at: DateTime::parse_from_rfc3339(start)
+            .expect("start")
+            .with_timezone(&Utc),
+        end_at: DateTime::parse_from_rfc3339(end)
+            .expect("end")
+            .with_timezone(&Utc),
+        firmness: Firmness::Draft,
+        planned_pomodoros: 1,
+        source: "manual".to_string(),
+        source_id: None,
+        project: None,
+        title: None,
+        recipe_id: "rcp-default".to_string(),
+        auto_drive_mode: AutoDriveMode::Manual,
+        contents: BlockContents::default(),
+    };
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        for block in [
+            make_block("blk-a", "2026-02-16T09:00:00Z", "2026-02-16T09:50:00Z"),
+            make_block("blk-b", "2026-02-16T09:30:00Z", "2026-02-16T10:20:00Z"),
+            make_block("blk-c", "2026-02-16T10:20:00Z", "2026-02-16T11:10:00Z"),
+        ] {
+            runtime.blocks.insert(
+                block.id.clone(),
+                StoredBlock {
+                    block,
+                    calendar_event_id: None,
+                    calendar_account_id: Some(DEFAULT_ACCOUNT_ID.to_string()),
+                },
+            );
+        }
+    }
+
+    let overlaps =
+        get_overlapping_blocks_impl(&state, "2026-02-16".to_string()).expect("overlapping blocks");
+    assert_eq!(overlaps, vec![("blk-a".to_string(), "blk-b".to_string())]);
+
+    let error = get_overlapping_blocks_impl(&state, "16/02/2026".to_string())
+        .expect_err("invalid date");
+    assert!(error.to_string().contains("YYYY-MM-DD"));
+}
+
+#[tokio::test]
+async fn reschedule_block_moves_block_into_next_open_gap() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let make_block = |id: &str, start: &str, end: &str| Block {
+        id: id.to_string(),
+        instance: format!("manual:{id}"),
+        date: "2026-02-16".to_string(),
+        start_at: DateTime::parse_from_rfc3339(start)
+            .expect("start")
+            .with_timezone(&Utc),
+        end_at: DateTime::parse_from_rfc3339(end)
+            .expect("end")
+            .with_timezone(&Utc),
+        firmness: Firmness::Draft,
+        planned_pomodoros: 1,
+        source: "manual".to_string(),
+        source_id: None,
+        project: None,
+        title: None,
+        recipe_id: "rcp-default".to_string(),
+        auto_drive_mode: AutoDriveMode::Manual,
+        contents: BlockContents::default(),
+    };
+    let target = make_block("blk-bump", "2026-02-16T09:00:00Z", "2026-02-16T09:30:00Z");
+    let neighbour = make_block("blk-next", "2026-02-16T09:30:00Z", "2026-02-16T10:00:00Z");
+    let meeting = busy_event("evt-meeting", "2026-02-16T10:00:00Z", "2026-02-16T11:00:00Z");
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        for block in [target, neighbour] {
+            runtime.blocks.insert(
+                block.id.clone(),
+                StoredBlock {
+                    block,
+                    calendar_event_id: None,
+                    calendar_account_id: Some(DEFAULT_ACCOUNT_ID.to_string()),
+                },
+            );
+        }
+        runtime
+            .synced_events_by_account
+            .insert(DEFAULT_ACCOUNT_ID.to_string(), vec![meeting]);
+    }
+
+    let moved = reschedule_block_impl(&state, "blk-bump".to_string(), None)
+        .await
+        .expect("reschedule block")
+        .expect("moved block");
+
+    assert_eq!(moved.start_at.to_rfc3339(), "2026-02-16T11:00:00+00:00");
+    assert_eq!(moved.end_at.to_rfc3339(), "2026-02-16T11:30:00+00:00");
+    let runtime = lock_runtime(&state).expect("runtime lock");
+    assert_eq!(runtime.blocks["blk-bump"].block.start_at, moved.start_at);
+}
+
+#[test]
+fn get_busy_summary_reports_occupancy_of_work_window() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let make_block = |id: &str, start: &str, end: &str| Block {
+        id: id.to_string(),
+        instance: format!("manual:{id}"),
+        date: "2026-02-16".to_string(),
+        start_at: DateTime::parse_from_rfc3339(start)
+            .expect("start")
+            .with_timezone(&Utc),
+        end_at: DateTime::parse_from_rfc3339(end)
+            .expect("end")
+            .with_timezone(&Utc),
+        firmness: Firmness::Draft,
+        planned_pomodoros: 1,
+        source: "manual".to_string(),
+        source_id: None,
+        project: None,
+        title: None,
+        recipe_id: "rcp-default".to_string(),
+        auto_drive_mode: AutoDriveMode::Manual,
+        contents: BlockContents::default(),
+    };
+    let meeting = busy_event("evt-meeting", "2026-02-16T10:00:00Z", "2026-02-16T11:00:00Z");
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        for block in [
+            make_block("blk-morning", "2026-02-16T09:00:00Z", "2026-02-16T09:30:00Z"),
+            make_block("blk-overlap", "2026-02-16T10:30:00Z", "2026-02-16T11:30:00Z"),
+        ] {
+            runtime.blocks.insert(
+                block.id.clone(),
+                StoredBlock {
+                    block,
+                    calendar_event_id: None,
+                    calendar_account_id: Some(DEFAULT_ACCOUNT_ID.to_string()),
+                },
+            );
+        }
+        runtime
+            .synced_events_by_account
+            .insert(DEFAULT_ACCOUNT_ID.to_string(), vec![meeting]);
+    }
+
+    let summary =
+        get_busy_summary_impl(&state, "2026-02-16".to_string(), None).expect("busy summary");
+
+    assert_eq!(summary.work_minutes, 540);
+    assert_eq!(summary.busy_minutes, 60);
+    assert_eq!(summary.block_minutes, 90);
+    assert_eq!(summary.free_minutes, 420);
+    assert!((summary.occupancy_ratio - 120.0 / 540.0).abs() < f64::EPSILON);
+}
+
+#[tokio::test]
+async fn auto_relocation_leaves_imminent_blocks_in_place() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let make_block = |id: &str, start: DateTime<Utc>| Block {
+        id: id.to_string(),
+        instance: format!("manual:{id}"),
+        date: start.date_naive().to_string(),
+        start_at: start,
+        end_at: start + Duration::minutes(30),
+        firmness: Firmness::Draft,
+        planned_pomodoros: 1,
+        source: "manual".to_string(),
+        source_id: None,
+        project: None,
+        title: None,
+        recipe_id: "rcp-default".to_string(),
+        auto_drive_mode: AutoDriveMode::Manual,
+        contents: BlockContents::default(),
+    };
+    let make_event = |id: &str, start: DateTime<Utc>, minutes: i64| {
+        busy_event(
+            id,
+            &start.to_rfc3339(),
+            &(start + Duration::minutes(minutes)).to_rfc3339(),
+        )
+    };
+    let now = Utc::now();
+    let imminent_start = now + Duration::minutes(10);
+    let far_start = Utc.from_utc_datetime(
+        &(now + Duration::days(3))
+            .date_naive()
+            .and_hms_opt(10, 0, 0)
+            .expect("far start"),
+    );
+    let imminent = make_block("blk-imminent", imminent_start);
+    let far = make_block("blk-far", far_start);
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        for block in [imminent.clone(), far.clone()] {
+            runtime.blocks.insert(
+                block.id.clone(),
+                StoredBlock {
+                    block,
+                    calendar_event_id: None,
+                    calendar_account_id: Some(DEFAULT_ACCOUNT_ID.to_string()),
+                },
+            );
+        }
+        runtime.synced_events_by_account.insert(
+            DEFAULT_ACCOUNT_ID.to_string(),
+            vec![
+                make_event("evt-imminent", imminent_start, 30),
+                make_event("evt-far", far_start, 60),
+            ],
+        );
+    }
+    let changed = vec![
+        Interval {
+            start: imminent.start_at,
+            end: imminent.end_at,
+        },
+        Interval {
+            start: far.start_at,
+            end: far.start_at + Duration::minutes(60),
+        },
+    ];
+
+    let relocated = auto_relocate_after_sync(&state, DEFAULT_ACCOUNT_ID, &changed, 10)
+        .await
+        .expect("auto relocate");
+
+    assert_eq!(relocated.len(), 1);
+    assert_eq!(relocated[0].block_id, "blk-far");
+    let runtime = lock_runtime(&state).expect("runtime lock");
+    assert_eq!(runtime.blocks["blk-imminent"].block.start_at, imminent.start_at);
+    assert_ne!(runtime.blocks["blk-far"].block.start_at, far.start_at);
+}
+
+#[tokio::test]
+async fn generate_blocks_for_today_skips_elapsed_part_of_work_window() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let now = Utc
+        .with_ymd_and_hms(2026, 2, 16, 14, 0, 0)
+        .single()
+        .expect("simulated now");
+
+    let generated = generate_blocks_at(&state, "2026-02-16".to_string(), None, now)
+        .await
+        .expect("generate blocks");
+
+    assert!(!generated.is_empty());
+    assert!(generated.iter().all(|block| block.start_at >= now));
+    assert_eq!(generated[0].start_at, now);
+
+    fs::write(
+        state.config_dir().join("policies.json"),
+        r#"{"generation":{"skipPast":false}}"#,
+    )
+    .expect("write policies");
+    let tomorrow = now + Duration::days(1);
+    let generated = generate_blocks_at(&state, "2026-02-17".to_string(), None, tomorrow)
+        .await
+        .expect("generate blocks without skipping");
+    assert_eq!(
+        generated[0].start_at.to_rfc3339(),
+        "2026-02-17T09:00:00+00:00"
+    );
+}
+
+#[tokio::test]
+async fn generate_blocks_stops_auto_fill_at_daily_focus_budget() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let policies_path = state.config_dir().join("policies.json");
+    fs::write(
+        &policies_path,
+        r#"{"workHours":{"start":"08:00","end":"20:00"}}"#,
+    )
+    .expect("write policies");
+    let unbounded = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate without budget");
+    assert_eq!(unbounded.len(), 12);
+
+    fs::write(
+        &policies_path,
+        r#"{"workHours":{"start":"08:00","end":"20:00"},"generation":{"maxDailyFocusMinutes":240}}"#,
+    )
+    .expect("write policies");
+    let budgeted = generate_blocks_impl(&state, "2026-02-17".to_string(), None)
+        .await
+        .expect("generate with budget");
+
+    assert_eq!(budgeted.len(), 4);
+    let focus_minutes = budgeted
+        .iter()
+        .map(|block| block.planned_pomodoros.max(0) as u32 * FOCUS_MINUTES_PER_POMODORO)
+        .sum::<u32>();
+    assert!(focus_minutes <= 240);
+}
+
+#[tokio::test]
+async fn blocks_can_be_tagged_with_a_project_and_filtered() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("templates.json"),
+        r#"{
+  "templates": [
+    { "id": "tpl-client", "start": "09:00", "durationMinutes": 60, "project": "Client A" }
+  ]
+}
+"#,
+    )
+    .expect("write templates config");
+
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    let template_block = generated
+        .iter()
+        .find(|block| block.instance.starts_with("tpl:tpl-client:"))
+        .expect("template block");
+    assert_eq!(template_block.project.as_deref(), Some("Client A"));
+    let auto_block = generated
+        .iter()
+        .find(|block| block.instance.starts_with("rtn:auto:"))
+        .expect("auto block");
+    assert_eq!(auto_block.project, None);
+
+    let client_a =
+        list_blocks_impl(&state, None, Some("Client A".to_string())).expect("list blocks");
+    assert_eq!(client_a.len(), 1);
+    assert_eq!(client_a[0].id, template_block.id);
+
+    let tagged =
+        set_block_project_impl(&state, auto_block.id.clone(), Some(" Client B ".to_string()))
+            .await
+            .expect("set block project");
+    assert_eq!(tagged.project.as_deref(), Some("Client B"));
+    let client_b = list_blocks_impl(
+        &state,
+        Some("2026-02-16".to_string()),
+        Some("Client B".to_string()),
+    )
+    .expect("list blocks");
+    assert_eq!(client_b.len(), 1);
+    assert_eq!(client_b[0].id, auto_block.id);
+
+    let cleared = set_block_project_impl(&state, auto_block.id.clone(), None)
+        .await
+        .expect("clear block project");
+    assert_eq!(cleared.project, None);
+    let all = list_blocks_impl(&state, Some("2026-02-16".to_string()), None).expect("list blocks");
+    assert_eq!(all.len(), generated.len());
+}
+
+#[tokio::test]
+async fn snooze_block_shifts_block_within_work_window() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let mut generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    generated.sort_by(|left, right| left.start_at.cmp(&right.start_at));
+    let first = generated.first().expect("first block").clone();
+    let last = generated.last().expect("last block").clone();
+
+    let snoozed = snooze_block_impl(&state, first.id.clone(), 15, None, None)
+        .await
+        .expect("snooze block");
+    assert_eq!(snoozed.start_at, first.start_at + Duration::minutes(15));
+    assert_eq!(snoozed.end_at, first.end_at + Duration::minutes(15));
+    assert_eq!(
+        lock_runtime(&state).expect("runtime lock").blocks[&first.id].block.start_at,
+        snoozed.start_at
+    );
+
+    let past_window = snooze_block_impl(&state, last.id.clone(), 15, None, None).await;
+    assert!(past_window.is_err());
+    let allowed = snooze_block_impl(&state, last.id.clone(), 15, None, Some(true))
+        .await
+        .expect("snooze outside window");
+    assert_eq!(allowed.end_at, last.end_at + Duration::minutes(15));
+}
+
+#[tokio::test]
+async fn relocate_blocks_places_conflicting_blocks_without_overlaps() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let make_block = |id: &str, start: &str, end: &str| Block {
+        id: id.to_string(),
+        instance: format!("manual:{id}"),
+        date: "2026-02-16".to_string(),
+        start_at: DateTime::parse_from_rfc3339(start)
+            .expect("start")
+            .with_timezone(&Utc),
+        end_at: DateTime::parse_from_rfc3339(end)
+            .expect("end")
+            .with_timezone(&Utc),
+        firmness: Firmness::Draft,
+        planned_pomodoros: 1,
+        source: "manual".to_string(),
+        source_id: None,
+        project: None,
+        title: None,
+        recipe_id: "rcp-default".to_string(),
+        auto_drive_mode: AutoDriveMode::Manual,
+        contents: BlockContents::default(),
+    };
+    let blocks = [
+        make_block("blk-batch-1", "2026-02-16T09:00:00Z", "2026-02-16T09:30:00Z"),
+        make_block("blk-batch-2", "2026-02-16T09:30:00Z", "2026-02-16T10:00:00Z"),
+        make_block("blk-batch-3", "2026-02-16T10:00:00Z", "2026-02-16T10:30:00Z"),
+    ];
+    let stuck = Block {
+        date: "2026-02-17".to_string(),
+        ..make_block("blk-stuck", "2026-02-17T09:00:00Z", "2026-02-17T09:30:00Z")
+    };
+    let imported = busy_event("evt-busy-day", "2026-02-16T09:00:00Z", "2026-02-16T11:00:00Z");
+    let offsite = busy_event("evt-offsite", "2026-02-17T00:00:00Z", "2026-02-18T00:00:00Z");
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        for block in blocks.iter().chain([&stuck]).cloned() {
+            runtime.blocks.insert(
+                block.id.clone(),
+                StoredBlock {
+                    block,
+                    calendar_event_id: None,
+                    calendar_account_id: Some(DEFAULT_ACCOUNT_ID.to_string()),
+                },
+            );
+        }
+        runtime
+            .synced_events_by_account
+            .insert(DEFAULT_ACCOUNT_ID.to_string(), vec![imported, offsite]);
+    }
+
+    let result = relocate_blocks_impl(
+        &state,
+        blocks.iter().chain([&stuck]).map(|block| block.id.clone()).collect(),
+        None,
+    )
+    .await
+    .expect("relocate blocks");
+
+    assert_eq!(result.relocated.len(), 3);
+    assert_eq!(result.unplaced, vec!["blk-stuck".to_string()]);
+    assert!(result.failed.is_empty());
+    let listed =
+        list_blocks_impl(&state, Some("2026-02-16".to_string()), None).expect("list blocks");
+    let busy = Interval {
+        start: blocks[0].start_at,
+        end: blocks[0].start_at + Duration::hours(2),
+    };
+    for (index, block) in listed.iter().enumerate() {
+        let interval = Interval {
+            start: block.start_at,
+            end: block.end_at,
+        };
+        assert!(!intervals_overlap(&interval, &busy));
+        for other in &listed[index + 1..] {
+            let other_interval = Interval {
+                start: other.start_at,
+                end: other.end_at,
+            };
+            assert!(!intervals_overlap(&interval, &other_interval));
+        }
+    }
+}
+
+#[tokio::test]
+async fn startup_catch_up_only_generates_the_most_recent_work_days() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("policies.json"),
+        r#"{"generation":{"catchUpMaxDays":7}}"#,
+    )
+    .expect("write policies");
+    let now = Utc
+        .with_ymd_and_hms(2026, 3, 18, 7, 0, 0)
+        .single()
+        .expect("simulated now");
+
+    let generated = run_startup_catch_up(&state, Some("2026-02-16".to_string()), None, now)
+        .await
+        .expect("run catch-up");
+
+    let mut dates = generated
+        .iter()
+        .map(|block| block.date.as_str())
+        .collect::<Vec<_>>();
+    dates.dedup();
+    assert_eq!(
+        dates,
+        vec![
+            "2026-03-10",
+            "2026-03-11",
+            "2026-03-12",
+            "2026-03-13",
+            "2026-03-16",
+            "2026-03-17",
+            "2026-03-18",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn startup_catch_up_materializes_recurring_tasks_for_each_missed_day() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("recurring_tasks.json"),
+        r#"{
+  "schema": 1,
+  "recurringTasks": [
+    { "id": "standup-prep", "title": "Daily standup prep", "rrule": "FREQ=DAILY" }
+  ]
+}
+"#,
+    )
+    .expect("write recurring tasks config");
+    let now = Utc
+        .with_ymd_and_hms(2026, 3, 18, 7, 0, 0)
+        .single()
+        .expect("simulated now");
+
+    run_startup_catch_up(&state, Some("2026-03-16".to_string()), None, now)
+        .await
+        .expect("run catch-up");
+    run_startup_catch_up(&state, Some("2026-03-16".to_string()), None, now)
+        .await
+        .expect("run catch-up again");
+
+    let tasks = list_tasks_impl(&state, None).expect("list tasks");
+    assert_eq!(tasks.len(), 2);
+    assert!(tasks.iter().all(|task| task.title == "Daily standup prep"));
+}
+
+#[tokio::test]
+async fn start_less_routine_is_placed_in_first_free_slot() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("routines.json"),
+        r#"{
+  "schema": 1,
+  "routines": [
+    {
+      "id": "deep-work",
+      "name": "Deep Work",
+      "rrule": "FREQ=DAILY",
+      "default": { "durationMinutes": 120 }
+    }
+  ]
+}
+"#,
+    )
+    .expect("write routines config");
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        runtime.synced_events_by_account.insert(
+            DEFAULT_ACCOUNT_ID.to_string(),
+            vec![busy_event("evt-standup", "2026-02-16T09:00:00Z", "2026-02-16T10:00:00Z")],
+        );
+    }
+
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+
+    let routine_block = generated
+        .iter()
+        .find(|block| block.instance == "rtn:deep-work:2026-02-16")
+        .expect("floating routine block");
+    assert_eq!(routine_block.source_id.as_deref(), Some("deep-work"));
+    assert_eq!(
+        routine_block.start_at,
+        Utc.with_ymd_and_hms(2026, 2, 16, 10, 0, 0).single().expect("start")
+    );
+    assert_eq!((routine_block.end_at - routine_block.start_at).num_minutes(), 120);
+    assert!(generated
+        .iter()
+        .filter(|block| block.id != routine_block.id)
+        .all(|block| block.end_at <= routine_block.start_at
+            || block.start_at >= routine_block.end_at));
+}
+
+/// Jumps straight to the first deadline, then requests shutdown on the next sleep.
+struct ScriptedClock {
+    now: Mutex<DateTime<Utc>>,
+    sleeps: Mutex<Vec<DateTime<Utc>>>,
+    shutdown: tokio::sync::watch::Sender<bool>,
+}
+
+impl SchedulerClock for ScriptedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().expect("clock lock")
+    }
+
+    async fn sleep_until(&self, deadline: DateTime<Utc>) {
+        let first_sleep = {
+            let mut sleeps = self.sleeps.lock().expect("sleeps lock");
+            sleeps.push(deadline);
+            sleeps.len() == 1
+        };
+        if first_sleep {
+            *self.now.lock().expect("clock lock") = deadline;
+            return;
+        }
+        self.shutdown.send_replace(true);
+        std::future::pending::<()>().await;
+    }
+}
+
+#[tokio::test]
+async fn auto_generation_scheduler_fires_once_at_auto_time() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("policies.json"),
+        r#"{"generation":{"autoEnabled":true,"autoTime":"06:15"}}"#,
+    )
+    .expect("write policies");
+    let (shutdown, shutdown_signal) = tokio::sync::watch::channel(false);
+    let clock = ScriptedClock {
+        now: Mutex::new(Utc.with_ymd_and_hms(2026, 2, 16, 4, 0, 0).single().expect("now")),
+        sleeps: Mutex::new(Vec::new()),
+        shutdown,
+    };
+
+    run_auto_generation_with_clock(&state, &clock, shutdown_signal).await;
+
+    let fire_at = Utc.with_ymd_and_hms(2026, 2, 16, 6, 15, 0).single().expect("fire at");
+    assert_eq!(
+        *clock.sleeps.lock().expect("sleeps lock"),
+        vec![fire_at, fire_at + Duration::days(1)]
+    );
+    let blocks = list_blocks_impl(&state, None, None).expect("list blocks");
+    assert!(!blocks.is_empty());
+    assert!(blocks.iter().all(|block| block.date == "2026-02-16"));
+}
+
+#[tokio::test]
+async fn generate_from_source_places_only_the_matching_template() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("templates.json"),
+        r#"{
+  "templates": [
+    { "id": "focus-morning", "start": "09:00", "durationMinutes": 50, "firmness": "soft" },
+    { "id": "focus-afternoon", "start": "14:00", "durationMinutes": 50 }
+  ]
+}
+"#,
+    )
+    .expect("write templates config");
+
+    let generated = generate_from_source_impl(
+        &state,
+        "2026-02-16".to_string(),
+        "focus-morning".to_string(),
+        None,
+    )
+    .await
+    .expect("generate from source");
+
+    assert_eq!(generated.len(), 1);
+    assert_eq!(generated[0].instance, "tpl:focus-morning:2026-02-16");
+    assert_eq!(generated[0].firmness, Firmness::Soft);
+    assert_eq!(
+        list_blocks_impl(&state, Some("2026-02-16".to_string()), None)
+            .expect("list blocks")
+            .len(),
+        1
+    );
+    assert!(generate_from_source_impl(&state, "2026-02-16".to_string(), " ".to_string(), None)
+        .await
+        .is_err());
+}
+
+#[test]
+fn remaining_capacity_counts_cycles_left_after_now() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let meeting = busy_event("evt-review", "2026-02-16T14:00:00Z", "2026-02-16T15:00:00Z");
+    lock_runtime(&state)
+        .expect("runtime lock")
+        .synced_events_by_account
+        .insert(DEFAULT_ACCOUNT_ID.to_string(), vec![meeting]);
+    let now = Utc.with_ymd_and_hms(2026, 2, 16, 13, 30, 0).single().expect("now");
+
+    // 13:30-14:00 fits one 30-minute cycle and 15:00-18:00 fits six.
+    let capacity =
+        remaining_capacity(&state, "2026-02-16".to_string(), None, now).expect("capacity");
+
+    assert_eq!(capacity, 7);
+}
+
+#[tokio::test]
+async fn break_blocks_are_interleaved_between_auto_focus_blocks() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("policies.json"),
+        r#"{"generation":{"insertBreakBlocks":true}}"#,
+    )
+    .expect("write policies");
+
+    let mut generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    generated.sort_by_key(|block| block.start_at);
+
+    assert!(generated.len() >= 3);
+    for (index, block) in generated.iter().enumerate() {
+        if index % 2 == 0 {
+            assert!(block.instance.starts_with("rtn:auto:2026-02-16:"));
+            assert!(block.planned_pomodoros > 0);
+        } else {
+            assert!(block.instance.starts_with("rtn:break:2026-02-16:"));
+            assert_eq!(block.source, "break");
+            assert_eq!(block.planned_pomodoros, 0);
+            assert_eq!((block.end_at - block.start_at).num_minutes(), 5);
+            assert_eq!(block.start_at, generated[index - 1].end_at);
+        }
+    }
+    assert!(generated
+        .last()
+        .is_some_and(|block| block.instance.starts_with("rtn:auto:")));
+
+    let break_block = &generated[1];
+    assert!(start_pomodoro_impl(&state, break_block.id.clone(), None).is_err());
+}
+
+#[tokio::test]
+async fn min_block_gap_pads_synced_busy_events() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("policies.json"),
+        r#"{
+  "schema": 1,
+  "workHours": {
+    "start": "09:00",
+    "end": "12:00",
+    "days": ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday"]
+  },
+  "blockDurationMinutes": 60,
+  "breakDurationMinutes": 5,
+  "minBlockGapMinutes": 10
+}"#,
+    )
+    .expect("write policies config");
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        runtime.synced_events_by_account.insert(
+            DEFAULT_ACCOUNT_ID.to_string(),
+            vec![busy_event("evt-meeting", "2026-02-16T09:00:00Z", "2026-02-16T10:00:00Z")],
+        );
+    }
+
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+
+    let first_start = generated
+        .iter()
+        .map(|block| block.start_at)
+        .min()
+        .expect("at least one block");
+    assert_eq!(first_start, Utc.with_ymd_and_hms(2026, 2, 16, 10, 10, 0).unwrap());
+}
+
+#[tokio::test]
+async fn declined_events_do_not_block_generation() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("policies.json"),
+        r#"{
+  "schema": 1,
+  "workHours": {
+    "start": "09:00",
+    "end": "10:00",
+    "days": ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday"]
+  },
+  "blockDurationMinutes": 60,
+  "breakDurationMinutes": 5
+}"#,
+    )
+    .expect("write policies config");
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        runtime.synced_events_by_account.insert(
+            DEFAULT_ACCOUNT_ID.to_string(),
+            vec![GoogleCalendarEvent {
+                attendees: vec![CalendarEventAttendee {
+                    email: Some("me@example.com".to_string()),
+                    is_self: true,
+                    response_status: Some("declined".to_string()),
+                }],
+                ..busy_event("evt-declined", "2026-02-16T09:00:00Z", "2026-02-16T10:00:00Z")
+            }],
+        );
+    }
+
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+
+    assert_eq!(generated.len(), 1);
+    assert_eq!(generated[0].start_at, Utc.with_ymd_and_hms(2026, 2, 16, 9, 0, 0).unwrap());
+}
+
+#[tokio::test]
+async fn week_capacity_combines_planned_blocks_with_free_cycles() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("policies.json"),
+        r#"{
+  "schema": 1,
+  "workHours": {
+    "start": "09:00",
+    "end": "12:00",
+    "days": ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday"]
+  },
+  "blockDurationMinutes": 60,
+  "breakDurationMinutes": 5
+}"#,
+    )
+    .expect("write policies config");
+    let monday = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate monday");
+    let tuesday = generate_blocks_impl(&state, "2026-02-17".to_string(), None)
+        .await
+        .expect("generate tuesday");
+    let planned = |blocks: &[Block]| {
+        blocks
+            .iter()
+            .map(|block| block.planned_pomodoros as u32)
+            .sum::<u32>()
+    };
+
+    let week = get_week_capacity_impl(&state, "2026-02-16".to_string(), None)
+        .expect("week capacity");
+
+    assert_eq!(week.days.len(), 7);
+    assert_eq!(week.days[0].planned_pomodoros, planned(&monday));
+    assert_eq!(week.days[1].planned_pomodoros, planned(&tuesday));
+    assert_eq!(week.days[2].planned_pomodoros, 0);
+    // A 3-hour window fits six 30-minute focus+break cycles on each work day.
+    assert!(week.days[..5].iter().all(|day| day.available_pomodoros == 6));
+    assert!(week.days[5..].iter().all(|day| day.available_pomodoros == 0));
+    assert_eq!(week.planned_pomodoros, planned(&monday) + planned(&tuesday));
+    assert_eq!(week.available_pomodoros, 30);
+}
+
+#[cfg(feature = "debug-tools")]
+#[tokio::test]
+async fn block_calendar_event_is_none_without_an_event_id_or_token() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+
+    let unsynced = get_block_calendar_event_impl(&state, generated[0].id.clone(), None)
+        .await
+        .expect("read unsynced block event");
+    assert_eq!(unsynced, None);
+
+    lock_runtime(&state)
+        .expect("runtime lock")
+        .blocks
+        .get_mut(generated[0].id.as_str())
+        .expect("stored block")
+        .calendar_event_id = Some("evt-offline".to_string());
+    let without_token = get_block_calendar_event_impl(&state, generated[0].id.clone(), None)
+        .await
+        .expect("read block event without a token");
+    assert_eq!(without_token, None);
+
+    assert!(get_block_calendar_event_impl(&state, "missing".to_string(), None)
+        .await
+        .is_err());
+}
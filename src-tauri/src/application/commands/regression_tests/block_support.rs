@@ -1,8 +1,34 @@
 pub(crate) const BLOCK_GENERATION_TARGET_MS: u128 = 30_000;
 
+pub(crate) use crate::application::auto_generation::{
+    run_auto_generation_with_clock, SchedulerClock,
+};
+pub(crate) use crate::application::block_calendar_events::FOCUS_MINUTES_PER_POMODORO;
+pub(crate) use crate::application::block_operations::remaining_capacity;
+pub(crate) use crate::application::block_generation::{generate_blocks_at, run_startup_catch_up};
+
 pub(crate) use crate::application::calendar_runtime::{
-    collect_relocation_target_block_ids, save_suppression,
+    auto_relocate_after_sync, collect_relocation_target_block_ids, save_suppression,
 };
 pub(crate) use crate::application::time_slots::{intervals_overlap, Interval};
 pub(crate) use crate::domain::models::Block;
 pub(crate) use chrono::{DateTime, NaiveDate, Utc};
+use crate::infrastructure::event_mapper::{CalendarEventDateTime, GoogleCalendarEvent};
+
+/// A confirmed event occupying `start..end` (RFC3339) in the synced calendar.
+pub(crate) fn busy_event(id: &str, start: &str, end: &str) -> GoogleCalendarEvent {
+    GoogleCalendarEvent {
+        id: Some(id.to_string()),
+        summary: Some("Busy".to_string()),
+        status: Some("confirmed".to_string()),
+        start: CalendarEventDateTime {
+            date_time: start.to_string(),
+            time_zone: None,
+        },
+        end: CalendarEventDateTime {
+            date_time: end.to_string(),
+            time_zone: None,
+        },
+        ..Default::default()
+    }
+}
@@ -1,9 +1,19 @@
-use super::pomodoro_support::{configured_recipes, load_runtime_policy, pomodoro_session_plan};
+use super::pomodoro_support::{
+    configured_recipes, load_runtime_policy, pomodoro_session_plan, save_pomodoro_log,
+};
+use super::runtime_support::lock_runtime;
+use crate::application::test_support::config_fs::allow_out_of_date_pomodoro_start;
 use crate::application::test_support::workspace::TempWorkspace;
 use crate::application::commands::{
-    advance_pomodoro_impl, complete_pomodoro_impl, generate_blocks_impl, get_pomodoro_state_impl,
-    get_reflection_summary_impl, pause_pomodoro_impl, resume_pomodoro_impl, start_pomodoro_impl,
+    advance_pomodoro_impl, complete_pomodoro_impl, create_task_impl, generate_blocks_impl,
+    get_block_reflection_impl, get_longest_focus_impl,
+    get_pomodoro_state_impl, get_reflection_summary_impl, get_session_logs_impl,
+    get_task_investment_impl,
+    interrupt_timer_impl, list_tasks_impl, log_pomodoro_impl, pause_pomodoro_impl,
+    resume_pomodoro_impl, set_current_task_impl, start_pomodoro_impl,
 };
+use crate::domain::models::{PomodoroLog, PomodoroPhase};
+use chrono::{Datelike, Duration, Utc};
 
 #[test]
 fn start_pomodoro_requires_existing_block() {
@@ -13,6 +23,54 @@ fn start_pomodoro_requires_existing_block() {
     assert!(result.is_err());
 }
 
+#[tokio::test]
+async fn start_pomodoro_rejects_a_future_block_unless_allowed() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let next_week = Utc::now().date_naive() + Duration::days(7);
+    let monday = next_week - Duration::days(i64::from(next_week.weekday().num_days_from_monday()));
+    let generated = generate_blocks_impl(&state, monday.to_string(), None)
+        .await
+        .expect("generate future blocks");
+
+    let error = start_pomodoro_impl(&state, generated[0].id.clone(), None)
+        .expect_err("future block is rejected");
+    assert!(error.to_string().contains("not today"));
+
+    allow_out_of_date_pomodoro_start(state.config_dir());
+    let started =
+        start_pomodoro_impl(&state, generated[0].id.clone(), None).expect("start when allowed");
+    assert_eq!(started.current_block_id, Some(generated[0].id.clone()));
+}
+
+#[tokio::test]
+async fn start_pomodoro_is_rejected_during_quiet_hours() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    let now = Utc::now();
+    std::fs::write(
+        state.config_dir().join("policies.json"),
+        serde_json::json!({
+            "schema": 1,
+            "quietHours": {
+                "start": (now - Duration::hours(1)).format("%H:%M").to_string(),
+                "end": (now + Duration::hours(1)).format("%H:%M").to_string()
+            }
+        })
+        .to_string(),
+    )
+    .expect("write policies config");
+
+    let error = start_pomodoro_impl(&state, generated[0].id.clone(), None)
+        .expect_err("quiet hours should reject focus start");
+    assert!(error.to_string().contains("quiet hours"));
+    let current = get_pomodoro_state_impl(&state).expect("pomodoro state");
+    assert_eq!(current.phase, "idle");
+}
+
 #[tokio::test]
 async fn start_pause_and_get_pomodoro_state_flow() {
     let workspace = TempWorkspace::new();
@@ -26,9 +84,11 @@ async fn start_pause_and_get_pomodoro_state_flow() {
     let expected_plan = pomodoro_session_plan::build_pomodoro_session_plan(
         &generated[0],
         policy.break_duration_minutes,
+        policy.setup_seconds,
         &recipes,
     );
 
+    allow_out_of_date_pomodoro_start(state.config_dir());
     let started = start_pomodoro_impl(&state, block_id.clone(), None).expect("start pomodoro");
     assert_eq!(started.phase, "focus");
     assert_eq!(started.current_block_id, Some(block_id.clone()));
@@ -54,6 +114,7 @@ async fn property_15_starting_pomodoro_activates_running_timer() {
         .await
         .expect("generate blocks");
 
+    allow_out_of_date_pomodoro_start(state.config_dir());
     let snapshot =
         start_pomodoro_impl(&state, generated[0].id.clone(), None).expect("start pomodoro");
 
@@ -78,9 +139,11 @@ async fn advance_pomodoro_tracks_cycles_inside_block() {
     let expected_plan = pomodoro_session_plan::build_pomodoro_session_plan(
         &block,
         policy.break_duration_minutes,
+        policy.setup_seconds,
         &recipes,
     );
 
+    allow_out_of_date_pomodoro_start(state.config_dir());
     let started =
         start_pomodoro_impl(&state, block.id.clone(), None).expect("start pomodoro session");
     assert_eq!(started.total_cycles, expected_plan.total_cycles);
@@ -110,6 +173,46 @@ async fn advance_pomodoro_tracks_cycles_inside_block() {
     assert_eq!(snapshot.current_block_id, None);
 }
 
+#[tokio::test]
+async fn session_logs_list_completed_segments_of_current_session() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    let block_id = generated[0].id.clone();
+    {
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        let stored = runtime.blocks.get_mut(&block_id).expect("stored block");
+        stored.block.recipe_id = "rcp-missing".to_string();
+        stored.block.planned_pomodoros = 2;
+    }
+    assert!(get_session_logs_impl(&state).expect("idle logs").is_empty());
+
+    allow_out_of_date_pomodoro_start(state.config_dir());
+    let _ = start_pomodoro_impl(&state, block_id.clone(), None).expect("first session");
+    let _ = interrupt_timer_impl(&state, Some("restart".to_string())).expect("interrupt");
+
+    let started = start_pomodoro_impl(&state, block_id.clone(), None).expect("second session");
+    assert_eq!(started.total_cycles, 2);
+    let _ = advance_pomodoro_impl(&state).expect("first break");
+    let _ = advance_pomodoro_impl(&state).expect("second focus");
+    let snapshot = advance_pomodoro_impl(&state).expect("second break");
+    assert_eq!(snapshot.phase, "break");
+
+    let logs = get_session_logs_impl(&state).expect("session logs");
+    let focus_logs = logs
+        .iter()
+        .filter(|log| log.phase == "focus")
+        .collect::<Vec<_>>();
+    assert_eq!(focus_logs.len(), 2);
+    assert!(logs.iter().all(|log| log.block_id == block_id));
+    assert!(logs.iter().all(|log| log.interruption_reason.is_none()));
+
+    let _ = interrupt_timer_impl(&state, None).expect("stop session");
+    assert!(get_session_logs_impl(&state).expect("idle logs").is_empty());
+}
+
 #[tokio::test]
 async fn resume_complete_and_reflection_flow() {
     let workspace = TempWorkspace::new();
@@ -119,6 +222,7 @@ async fn resume_complete_and_reflection_flow() {
         .expect("generate blocks");
     let block_id = generated[0].id.clone();
 
+    allow_out_of_date_pomodoro_start(state.config_dir());
     let _ = start_pomodoro_impl(&state, block_id, None).expect("start");
     let _ = pause_pomodoro_impl(&state, Some("break".to_string())).expect("pause");
     let resumed = resume_pomodoro_impl(&state).expect("resume");
@@ -127,10 +231,59 @@ async fn resume_complete_and_reflection_flow() {
     let completed = complete_pomodoro_impl(&state).expect("complete");
     assert_eq!(completed.phase, "idle");
 
-    let summary = get_reflection_summary_impl(&state, None, None).expect("summary");
+    let summary = get_reflection_summary_impl(&state, None, None, None).expect("summary");
     assert!(summary.interrupted_count >= 1);
 }
 
+#[tokio::test]
+async fn resumed_segment_links_to_the_paused_segment() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+
+    allow_out_of_date_pomodoro_start(state.config_dir());
+    let _ = start_pomodoro_impl(&state, generated[0].id.clone(), None).expect("start");
+    let _ = pause_pomodoro_impl(&state, Some("phone call".to_string())).expect("pause");
+    let _ = resume_pomodoro_impl(&state).expect("resume");
+    let _ = complete_pomodoro_impl(&state).expect("complete");
+
+    let logs = get_reflection_summary_impl(&state, None, None, None)
+        .expect("summary")
+        .logs;
+    let paused = logs
+        .iter()
+        .find(|log| log.interruption_reason.as_deref() == Some("phone call"))
+        .expect("paused segment");
+    assert_eq!(paused.resumed_from, None);
+    let resumed = logs
+        .iter()
+        .find(|log| log.id != paused.id)
+        .expect("resumed segment");
+    assert_eq!(resumed.resumed_from.as_deref(), Some(paused.id.as_str()));
+}
+
+#[tokio::test]
+async fn pausing_a_break_is_not_counted_as_an_interrupted_focus() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+
+    allow_out_of_date_pomodoro_start(state.config_dir());
+    let _ = start_pomodoro_impl(&state, generated[0].id.clone(), None).expect("start");
+    let snapshot = advance_pomodoro_impl(&state).expect("advance to break");
+    assert_eq!(snapshot.phase, "break");
+    let _ = pause_pomodoro_impl(&state, Some("coffee".to_string())).expect("pause break");
+
+    let summary = get_reflection_summary_impl(&state, None, None, None).expect("summary");
+    assert_eq!(summary.completed_count, 1);
+    assert_eq!(summary.interrupted_count, 0);
+    assert_eq!(summary.break_interrupted_count, 1);
+}
+
 #[tokio::test]
 async fn reflection_summary_survives_app_state_restart() {
     let workspace = TempWorkspace::new();
@@ -140,12 +293,13 @@ async fn reflection_summary_survives_app_state_restart() {
         .expect("generate blocks");
     let block_id = generated[0].id.clone();
 
+    allow_out_of_date_pomodoro_start(state.config_dir());
     let _ = start_pomodoro_impl(&state, block_id, None).expect("start");
     let _ = pause_pomodoro_impl(&state, Some("restart-check".to_string())).expect("pause");
     let _ = complete_pomodoro_impl(&state).expect("complete");
 
     let restarted_state = workspace.app_state();
-    let summary = get_reflection_summary_impl(&restarted_state, None, None).expect("summary");
+    let summary = get_reflection_summary_impl(&restarted_state, None, None, None).expect("summary");
 
     assert!(summary.interrupted_count >= 1);
     assert!(!summary.logs.is_empty());
@@ -159,6 +313,7 @@ async fn property_32_reflection_aggregates_match_underlying_logs() {
         .await
         .expect("generate blocks");
 
+    allow_out_of_date_pomodoro_start(state.config_dir());
     let _ = start_pomodoro_impl(&state, generated[0].id.clone(), None).expect("start first");
     let _ = pause_pomodoro_impl(&state, Some("property-32".to_string())).expect("pause first");
     let _ = complete_pomodoro_impl(&state).expect("complete first");
@@ -167,9 +322,12 @@ async fn property_32_reflection_aggregates_match_underlying_logs() {
     let _ = advance_pomodoro_impl(&state).expect("advance second");
     let _ = complete_pomodoro_impl(&state).expect("complete second");
 
-    let summary = get_reflection_summary_impl(&state, None, None).expect("summary");
+    let summary = get_reflection_summary_impl(&state, None, None, None).expect("summary");
 
-    assert_eq!(summary.logs.len() as u32, summary.completed_count + summary.interrupted_count);
+    assert_eq!(
+        summary.logs.len() as u32,
+        summary.completed_count + summary.interrupted_count + summary.break_interrupted_count
+    );
     assert!(summary.total_focus_minutes >= 0);
 }
 
@@ -181,9 +339,10 @@ async fn property_17_interruption_reason_and_time_are_logged_on_pause() {
         .await
         .expect("generate blocks");
 
+    allow_out_of_date_pomodoro_start(state.config_dir());
     let _ = start_pomodoro_impl(&state, generated[0].id.clone(), None).expect("start");
     let _ = pause_pomodoro_impl(&state, Some("meeting".to_string())).expect("pause");
-    let summary = get_reflection_summary_impl(&state, None, None).expect("summary");
+    let summary = get_reflection_summary_impl(&state, None, None, None).expect("summary");
     let paused_log = summary
         .logs
         .iter()
@@ -193,3 +352,284 @@ async fn property_17_interruption_reason_and_time_are_logged_on_pause() {
     assert_eq!(paused_log.phase, "focus");
     assert!(paused_log.end_time.is_some());
 }
+
+#[tokio::test]
+async fn set_current_task_splits_focus_log_between_tasks() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    let first = create_task_impl(&state, "first".to_string(), None, Some(1)).expect("first task");
+    let second =
+        create_task_impl(&state, "second".to_string(), None, Some(1)).expect("second task");
+
+    allow_out_of_date_pomodoro_start(state.config_dir());
+    let _ = start_pomodoro_impl(&state, generated[0].id.clone(), Some(first.id.clone()))
+        .expect("start");
+    let switched =
+        set_current_task_impl(&state, Some(second.id.clone())).expect("switch current task");
+    assert_eq!(switched.current_task_id.as_deref(), Some(second.id.as_str()));
+    assert_eq!(switched.phase, "focus");
+    let _ = pause_pomodoro_impl(&state, Some("wrap-up".to_string())).expect("pause");
+
+    let summary = get_reflection_summary_impl(&state, None, None, None).expect("summary");
+    let mut focus_logs = summary
+        .logs
+        .iter()
+        .filter(|log| log.phase == "focus")
+        .collect::<Vec<_>>();
+    focus_logs.sort_by(|left, right| left.start_time.cmp(&right.start_time));
+    assert_eq!(focus_logs.len(), 2);
+    assert_eq!(focus_logs[0].task_id.as_deref(), Some(first.id.as_str()));
+    assert_eq!(focus_logs[0].interruption_reason.as_deref(), Some("task_switched"));
+    assert_eq!(focus_logs[1].task_id.as_deref(), Some(second.id.as_str()));
+    assert_eq!(focus_logs[1].interruption_reason.as_deref(), Some("wrap-up"));
+
+    let cleared = set_current_task_impl(&state, None).expect("clear current task");
+    assert_eq!(cleared.current_task_id, None);
+}
+
+#[test]
+fn reflection_summary_reports_focus_streaks() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let now = Utc::now();
+    let focus_log = |id: &str, days_ago: i64, interruption_reason: Option<&str>| PomodoroLog {
+        id: id.to_string(),
+        block_id: "blk-streak".to_string(),
+        task_id: None,
+        phase: PomodoroPhase::Focus,
+        start_time: now - Duration::days(days_ago) - Duration::seconds(1),
+        end_time: Some(now - Duration::days(days_ago)),
+        interruption_reason: interruption_reason.map(ToOwned::to_owned),
+        resumed_from: None,
+    };
+    let logs = [
+        focus_log("log-today", 0, None),
+        focus_log("log-yesterday", 1, None),
+        focus_log("log-two-days-ago", 2, None),
+        focus_log("log-interrupted", 3, Some("meeting")),
+        focus_log("log-before-gap-1", 4, None),
+        focus_log("log-before-gap-2", 5, None),
+        focus_log("log-before-gap-3", 6, None),
+        focus_log("log-before-gap-4", 7, None),
+    ];
+    for log in &logs {
+        save_pomodoro_log(state.database_path(), log).expect("save pomodoro log");
+    }
+
+    let summary = get_reflection_summary_impl(
+        &state,
+        Some((now - Duration::days(10)).to_rfc3339()),
+        Some((now + Duration::minutes(1)).to_rfc3339()),
+        None,
+    )
+    .expect("summary");
+    assert_eq!(summary.current_focus_streak_days, 3);
+    assert_eq!(summary.longest_focus_streak_days, 4);
+
+    let narrow_summary = get_reflection_summary_impl(
+        &state,
+        Some((now - Duration::days(5) - Duration::hours(1)).to_rfc3339()),
+        Some((now + Duration::minutes(1)).to_rfc3339()),
+        None,
+    )
+    .expect("narrow summary");
+    assert_eq!(narrow_summary.current_focus_streak_days, 3);
+    assert_eq!(narrow_summary.longest_focus_streak_days, 3);
+}
+
+#[test]
+fn longest_focus_returns_the_longest_uninterrupted_focus_log() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let start = Utc::now() - Duration::hours(4);
+    let log = |id: &str, offset: i64, minutes: i64, interruption_reason: Option<&str>| PomodoroLog {
+        id: id.to_string(),
+        block_id: format!("blk-{id}"),
+        task_id: None,
+        phase: PomodoroPhase::Focus,
+        start_time: start + Duration::minutes(offset),
+        end_time: Some(start + Duration::minutes(offset + minutes)),
+        interruption_reason: interruption_reason.map(ToOwned::to_owned),
+        resumed_from: None,
+    };
+    let logs = [
+        log("short", 0, 25, None),
+        log("long", 40, 50, None),
+        log("interrupted", 100, 90, Some("call")),
+    ];
+    for entry in &logs {
+        save_pomodoro_log(state.database_path(), entry).expect("save pomodoro log");
+    }
+
+    let longest = get_longest_focus_impl(
+        &state,
+        (start - Duration::minutes(1)).to_rfc3339(),
+        Utc::now().to_rfc3339(),
+    )
+    .expect("longest focus")
+    .expect("a focus log in the window");
+    assert_eq!(longest.minutes, 50);
+    assert_eq!(longest.block_id, "blk-long");
+    assert_eq!(longest.start_time, (start + Duration::minutes(40)).to_rfc3339());
+}
+
+#[test]
+fn task_investment_totals_focus_minutes_per_task() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let writing = create_task_impl(&state, "Write spec".to_string(), None, None).expect("task");
+    let review = create_task_impl(&state, "Review PR".to_string(), None, None).expect("task");
+    create_task_impl(&state, "Untouched".to_string(), None, None).expect("task");
+    let start = Utc::now() - Duration::days(30);
+    let log = |id: &str, task_id: &str, offset: i64, minutes: i64, interrupted: bool| PomodoroLog {
+        id: id.to_string(),
+        block_id: "blk-1".to_string(),
+        task_id: Some(task_id.to_string()),
+        phase: PomodoroPhase::Focus,
+        start_time: start + Duration::days(offset),
+        end_time: Some(start + Duration::days(offset) + Duration::minutes(minutes)),
+        interruption_reason: interrupted.then(|| "call".to_string()),
+        resumed_from: None,
+    };
+    let logs = [
+        log("log-review-1", &review.id, 0, 25, false),
+        log("log-writing-1", &writing.id, 1, 25, false),
+        log("log-writing-2", &writing.id, 20, 25, false),
+        log("log-writing-3", &writing.id, 29, 10, true),
+    ];
+    for entry in &logs {
+        save_pomodoro_log(state.database_path(), entry).expect("save pomodoro log");
+    }
+
+    let investment = get_task_investment_impl(&state).expect("task investment");
+
+    let totals = investment
+        .iter()
+        .map(|item| (item.title.as_str(), item.focus_minutes, item.completed_pomodoros))
+        .collect::<Vec<_>>();
+    assert_eq!(totals, vec![("Write spec", 60, 2), ("Review PR", 25, 1)]);
+}
+
+#[test]
+fn block_reflection_counts_only_focus_logs_of_the_block() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let start = Utc::now() - Duration::hours(2);
+    let log = |id: &str, block_id: &str, offset: i64, phase, interruption_reason: Option<&str>| {
+        PomodoroLog {
+            id: id.to_string(),
+            block_id: block_id.to_string(),
+            task_id: None,
+            phase,
+            start_time: start + Duration::minutes(offset),
+            end_time: Some(start + Duration::minutes(offset + 25)),
+            interruption_reason: interruption_reason.map(ToOwned::to_owned),
+            resumed_from: None,
+        }
+    };
+    let logs = [
+        log("log-completed", "blk-target", 0, PomodoroPhase::Focus, None),
+        log("log-break", "blk-target", 25, PomodoroPhase::Break, None),
+        log("log-interrupted", "blk-target", 30, PomodoroPhase::Focus, Some("call")),
+        log("log-other-block", "blk-other", 60, PomodoroPhase::Focus, None),
+    ];
+    for entry in &logs {
+        save_pomodoro_log(state.database_path(), entry).expect("save pomodoro log");
+    }
+
+    let reflection =
+        get_block_reflection_impl(&state, "blk-target".to_string()).expect("block reflection");
+    assert_eq!(reflection.block_id, "blk-target");
+    assert_eq!(reflection.completed_focus, 1);
+    assert_eq!(reflection.interrupted_focus, 1);
+    assert_eq!(reflection.focus_minutes, 50);
+
+    let error = get_block_reflection_impl(&state, "  ".to_string()).expect_err("empty id");
+    assert!(error.to_string().contains("block_id must not be empty"));
+}
+
+#[tokio::test]
+async fn manually_logged_focus_counts_in_reflection_and_task_progress() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    let task = create_task_impl(&state, "write report".to_string(), None, Some(3)).expect("task");
+
+    let rejected = log_pomodoro_impl(
+        &state,
+        generated[0].id.clone(),
+        None,
+        "2026-02-16T09:25:00Z".to_string(),
+        "2026-02-16T09:00:00Z".to_string(),
+        false,
+    );
+    assert!(rejected.is_err());
+
+    let log = log_pomodoro_impl(
+        &state,
+        generated[0].id.clone(),
+        Some(task.id.clone()),
+        "2026-02-16T09:00:00Z".to_string(),
+        "2026-02-16T09:25:00Z".to_string(),
+        false,
+    )
+    .expect("log pomodoro");
+    assert_eq!(log.phase, PomodoroPhase::Focus);
+    assert_eq!(log.interruption_reason, None);
+
+    let summary = get_reflection_summary_impl(
+        &state,
+        Some("2026-02-16T00:00:00Z".to_string()),
+        Some("2026-02-17T00:00:00Z".to_string()),
+        None,
+    )
+    .expect("summary");
+    assert_eq!(summary.completed_count, 1);
+    assert_eq!(summary.total_focus_minutes, 25);
+
+    let tasks = list_tasks_impl(&state, None).expect("tasks");
+    let logged_task = tasks.iter().find(|candidate| candidate.id == task.id).expect("task");
+    assert_eq!(logged_task.completed_pomodoros, 1);
+}
+
+#[test]
+fn reflection_summary_limits_logs_to_the_most_recent_while_counting_all() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let window_start = Utc::now() - Duration::hours(12);
+    for index in 0..6 {
+        let start_time = window_start + Duration::minutes(30 * index);
+        save_pomodoro_log(
+            state.database_path(),
+            &PomodoroLog {
+                id: format!("log-{index}"),
+                block_id: "blk-many".to_string(),
+                task_id: None,
+                phase: PomodoroPhase::Focus,
+                start_time,
+                end_time: Some(start_time + Duration::minutes(25)),
+                interruption_reason: None,
+                resumed_from: None,
+            },
+        )
+        .expect("save pomodoro log");
+    }
+
+    let summary = get_reflection_summary_impl(
+        &state,
+        Some((window_start - Duration::minutes(1)).to_rfc3339()),
+        Some(Utc::now().to_rfc3339()),
+        Some(2),
+    )
+    .expect("summary");
+
+    assert_eq!(summary.completed_count, 6);
+    assert_eq!(summary.total_focus_minutes, 150);
+    let ids = summary.logs.iter().map(|log| log.id.as_str()).collect::<Vec<_>>();
+    assert_eq!(ids, vec!["log-5", "log-4"]);
+}
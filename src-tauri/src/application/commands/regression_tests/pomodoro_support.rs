@@ -1,3 +1,4 @@
 pub(crate) use crate::application::configured_recipes;
 pub(crate) use crate::application::policy_service::load_runtime_policy;
+pub(crate) use crate::application::pomodoro_log_store::save_pomodoro_log;
 pub(crate) use crate::application::pomodoro_session_plan;
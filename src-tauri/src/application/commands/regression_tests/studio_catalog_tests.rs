@@ -194,7 +194,7 @@ async fn apply_studio_template_to_today_creates_block_without_shift() {
 
     assert!(!result.shifted);
     assert_eq!(result.conflict_count, 0);
-    let blocks = list_blocks_impl(&state, Some("2026-02-16".to_string())).expect("list blocks");
+    let blocks = list_blocks_impl(&state, Some("2026-02-16".to_string()), None).expect("list blocks");
     assert_eq!(blocks.len(), 1);
     assert_eq!(blocks[0].source, "routine_studio");
     assert_eq!(blocks[0].recipe_id, "rcp-studio-a");
@@ -241,6 +241,8 @@ async fn apply_studio_template_to_today_shifts_when_conflict_exists() {
         planned_pomodoros: 1,
         source: "manual".to_string(),
         source_id: Some("busy".to_string()),
+        project: None,
+        title: None,
         recipe_id: "rcp-default".to_string(),
         auto_drive_mode: AutoDriveMode::Manual,
         contents: BlockContents::default(),
@@ -316,6 +318,8 @@ async fn apply_studio_template_to_today_fails_when_no_free_slot() {
         planned_pomodoros: 1,
         source: "manual".to_string(),
         source_id: Some("full".to_string()),
+        project: None,
+        title: None,
         recipe_id: "rcp-default".to_string(),
         auto_drive_mode: AutoDriveMode::Manual,
         contents: BlockContents::default(),
@@ -486,6 +490,8 @@ fn collect_relocation_target_block_ids_filters_by_changes_and_limit() {
         planned_pomodoros: 2,
         source: "routine".to_string(),
         source_id: Some("auto".to_string()),
+        project: None,
+        title: None,
         recipe_id: "rcp-default".to_string(),
         auto_drive_mode: AutoDriveMode::Manual,
         contents: BlockContents::default(),
@@ -1,9 +1,16 @@
 use super::runtime_support::{lock_runtime, TaskStatus};
 use crate::application::test_support::workspace::TempWorkspace;
 use crate::application::commands::{
-    carry_over_task_impl, create_task_impl, delete_task_impl, generate_blocks_impl, list_tasks_impl,
-    split_task_impl, update_task_impl,
+    begin_task_impl, carry_over_candidates_impl, carry_over_task_impl, create_task_impl,
+    create_tasks_bulk_impl, delete_block_impl, delete_task_impl, generate_blocks_impl,
+    list_tasks_impl, list_unassigned_tasks_impl, materialize_recurring_tasks_impl, move_task_impl,
+    split_task_impl, suggest_block_for_task_impl, suggest_pomodoros_impl, update_task_impl,
 };
+use crate::application::task_runtime::{
+    assign_task_to_block, restore_task_assignments_from_events,
+};
+use crate::infrastructure::event_mapper::encode_block_event_with_task;
+use std::fs;
 
 #[test]
 fn create_task_rejects_empty_title() {
@@ -13,6 +20,56 @@ fn create_task_rejects_empty_title() {
     assert!(result.is_err());
 }
 
+#[test]
+fn update_task_rejects_estimate_below_completed_pomodoros() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let created =
+        create_task_impl(&state, "Estimate guard".to_string(), None, Some(4)).expect("create task");
+    lock_runtime(&state)
+        .expect("runtime lock")
+        .tasks
+        .get_mut(&created.id)
+        .expect("stored task")
+        .completed_pomodoros = 3;
+
+    let result = update_task_impl(&state, created.id.clone(), None, None, Some(2), None, None);
+    let error = result.expect_err("estimate below completed must be rejected");
+    assert!(error.to_string().contains("completed_pomodoros"));
+    let stored = list_tasks_impl(&state, None).expect("list tasks");
+    assert_eq!(stored[0].estimated_pomodoros, Some(4));
+
+    let updated = update_task_impl(&state, created.id, None, None, Some(3), None, None)
+        .expect("estimate equal to completed");
+    assert_eq!(updated.estimated_pomodoros, Some(3));
+}
+
+#[test]
+fn create_tasks_bulk_skips_blank_lines_and_preserves_order() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+
+    let created = create_tasks_bulk_impl(
+        &state,
+        "  Write outline  \n   \nReview draft\n".to_string(),
+        Some(2),
+    )
+    .expect("bulk create tasks");
+
+    let titles = created
+        .iter()
+        .map(|task| task.title.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(titles, vec!["Write outline", "Review draft"]);
+    assert!(created
+        .iter()
+        .all(|task| task.estimated_pomodoros == Some(2)));
+    let listed = list_tasks_impl(&state, None).expect("list tasks");
+    let listed_ids = listed.iter().map(|task| task.id.clone()).collect::<Vec<_>>();
+    let created_ids = created.iter().map(|task| task.id.clone()).collect::<Vec<_>>();
+    assert_eq!(listed_ids, created_ids);
+}
+
 #[test]
 fn create_and_list_tasks_roundtrip() {
     let workspace = TempWorkspace::new();
@@ -25,7 +82,7 @@ fn create_and_list_tasks_roundtrip() {
         Some(2),
     )
     .expect("create task");
-    let listed = list_tasks_impl(&state).expect("list tasks");
+    let listed = list_tasks_impl(&state, None).expect("list tasks");
 
     assert_eq!(listed.len(), 1);
     assert_eq!(listed[0].id, created.id);
@@ -46,6 +103,7 @@ fn update_and_delete_task_flow() {
         Some("Details".to_string()),
         Some(3),
         Some("in_progress".to_string()),
+        None,
     )
     .expect("update task");
     assert_eq!(updated.title, "Updated");
@@ -54,7 +112,7 @@ fn update_and_delete_task_flow() {
 
     let deleted = delete_task_impl(&state, created.id.clone()).expect("delete task");
     assert!(deleted);
-    let tasks = list_tasks_impl(&state).expect("list tasks");
+    let tasks = list_tasks_impl(&state, None).expect("list tasks");
     assert!(tasks.is_empty());
 }
 
@@ -74,7 +132,7 @@ fn split_task_creates_children_and_defers_parent() {
         .iter()
         .all(|child| child.estimated_pomodoros == Some(2)));
 
-    let listed = list_tasks_impl(&state).expect("list tasks");
+    let listed = list_tasks_impl(&state, None).expect("list tasks");
     let refreshed_parent = listed
         .iter()
         .find(|task| task.id == parent.id)
@@ -128,3 +186,362 @@ async fn carry_over_task_moves_to_selected_available_block() {
     assert_eq!(result.to_block_id, next_block.id);
     assert_eq!(result.status, "in_progress");
 }
+
+#[tokio::test]
+async fn deleting_a_block_carries_over_or_unassigns_its_task_per_policy() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let policies_path = state.config_dir().join("policies.json");
+    let mut sorted = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    assert!(sorted.len() >= 2, "at least two blocks expected");
+    sorted.sort_by_key(|block| block.start_at);
+    let task = create_task_impl(&state, "Follow the block".to_string(), None, Some(2))
+        .expect("create task");
+    assign_task_to_block(
+        &mut lock_runtime(&state).expect("runtime lock"),
+        &task.id,
+        &sorted[0].id,
+    );
+
+    fs::write(&policies_path, r#"{"onBlockDelete":"carry_over"}"#).expect("write policies");
+    assert!(delete_block_impl(&state, sorted[0].id.clone(), None)
+        .await
+        .expect("delete carried block"));
+    assert_eq!(
+        lock_runtime(&state)
+            .expect("runtime lock")
+            .task_assignments_by_task
+            .get(task.id.as_str()),
+        Some(&sorted[1].id)
+    );
+
+    fs::write(&policies_path, r#"{"onBlockDelete":"unassign"}"#).expect("write policies");
+    assert!(delete_block_impl(&state, sorted[1].id.clone(), None)
+        .await
+        .expect("delete unassigned block"));
+    let backlog = list_unassigned_tasks_impl(&state).expect("list unassigned tasks");
+    assert!(backlog.iter().any(|unassigned| unassigned.id == task.id));
+}
+
+#[tokio::test]
+async fn carry_over_candidates_lists_later_unassigned_blocks_in_order() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let mut sorted = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    assert!(sorted.len() >= 4, "at least four blocks expected");
+    sorted.sort_by_key(|block| block.start_at);
+    let task = create_task_impl(&state, "Taken".to_string(), None, Some(1)).expect("create task");
+    assign_task_to_block(
+        &mut lock_runtime(&state).expect("runtime lock"),
+        &task.id,
+        &sorted[2].id,
+    );
+
+    let candidates =
+        carry_over_candidates_impl(&state, sorted[1].id.clone()).expect("carry-over candidates");
+
+    let expected = sorted[3..]
+        .iter()
+        .map(|block| block.id.clone())
+        .collect::<Vec<_>>();
+    let candidate_ids = candidates
+        .into_iter()
+        .map(|block| block.id)
+        .collect::<Vec<_>>();
+    assert_eq!(candidate_ids, expected);
+    assert!(carry_over_candidates_impl(&state, "missing".to_string()).is_err());
+}
+
+#[tokio::test]
+async fn suggest_block_for_task_prefers_blocks_of_the_task_type() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("templates.json"),
+        r#"{
+  "templates": [
+    { "id": "admin", "start": "09:00", "durationMinutes": 50 },
+    { "id": "deep", "start": "13:00", "durationMinutes": 50 }
+  ]
+}
+"#,
+    )
+    .expect("write templates config");
+    let mut sorted = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    sorted.sort_by_key(|block| block.start_at);
+    let deep_block = sorted
+        .iter()
+        .find(|block| block.source_id.as_deref() == Some("deep"))
+        .expect("deep template block")
+        .clone();
+    let untyped = create_task_impl(&state, "Anything".to_string(), None, Some(1))
+        .expect("create untyped task");
+    let deep = create_task_impl(&state, "Design doc".to_string(), None, Some(1))
+        .expect("create deep task");
+    update_task_impl(&state, deep.id.clone(), None, None, None, None, Some("deep".to_string()))
+        .expect("tag deep task");
+
+    let suggested = suggest_block_for_task_impl(&state, deep.id, "2026-02-16".to_string(), None)
+        .expect("suggest deep block");
+    let fallback = suggest_block_for_task_impl(&state, untyped.id, "2026-02-16".to_string(), None)
+        .expect("suggest any block");
+
+    assert_eq!(suggested.map(|block| block.id), Some(deep_block.id));
+    assert_eq!(fallback.map(|block| block.id), Some(sorted[0].id.clone()));
+}
+
+#[tokio::test]
+async fn begin_task_assigns_to_the_block_in_progress() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let idle_task = create_task_impl(&state, "Idle start".to_string(), None, Some(1))
+        .expect("create idle task");
+
+    let begun = begin_task_impl(&state, idle_task.id.clone()).expect("begin without block");
+    assert_eq!(begun.status, TaskStatus::InProgress);
+    assert!(lock_runtime(&state)
+        .expect("runtime lock")
+        .task_assignments_by_task
+        .is_empty());
+
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    let current_block_id = generated[0].id.clone();
+    {
+        let now = chrono::Utc::now();
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        let stored = runtime
+            .blocks
+            .get_mut(current_block_id.as_str())
+            .expect("stored block");
+        stored.block.start_at = now - chrono::Duration::minutes(10);
+        stored.block.end_at = now + chrono::Duration::minutes(20);
+    }
+    let task = create_task_impl(&state, "Focus now".to_string(), None, Some(2))
+        .expect("create task");
+
+    let begun = begin_task_impl(&state, task.id.clone()).expect("begin during block");
+    assert_eq!(begun.status, TaskStatus::InProgress);
+    let runtime = lock_runtime(&state).expect("runtime lock");
+    assert_eq!(
+        runtime.task_assignments_by_task.get(task.id.as_str()),
+        Some(&current_block_id)
+    );
+    assert!(!runtime.task_assignments_by_task.contains_key(idle_task.id.as_str()));
+}
+
+#[tokio::test]
+async fn break_blocks_never_receive_tasks() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("policies.json"),
+        r#"{"generation":{"insertBreakBlocks":true}}"#,
+    )
+    .expect("write policies");
+    let mut sorted = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    sorted.sort_by_key(|block| block.start_at);
+    assert!(sorted.len() >= 3, "focus, break and focus blocks expected");
+    assert_eq!(sorted[1].source, "break");
+    let break_ids = sorted
+        .iter()
+        .filter(|block| block.source == "break")
+        .map(|block| block.id.clone())
+        .collect::<Vec<_>>();
+
+    let candidates =
+        carry_over_candidates_impl(&state, sorted[0].id.clone()).expect("carry-over candidates");
+    assert!(!candidates.is_empty());
+    assert!(candidates.iter().all(|block| !break_ids.contains(&block.id)));
+
+    let task = create_task_impl(&state, "No breaks".to_string(), None, Some(1))
+        .expect("create task");
+    let suggested =
+        suggest_block_for_task_impl(&state, task.id.clone(), "2026-02-16".to_string(), None)
+            .expect("suggest block")
+            .expect("a focus block is free");
+    assert!(!break_ids.contains(&suggested.id));
+
+    {
+        let now = chrono::Utc::now();
+        let mut runtime = lock_runtime(&state).expect("runtime lock");
+        let stored = runtime
+            .blocks
+            .get_mut(sorted[1].id.as_str())
+            .expect("stored break block");
+        stored.block.start_at = now - chrono::Duration::minutes(2);
+        stored.block.end_at = now + chrono::Duration::minutes(3);
+    }
+    begin_task_impl(&state, task.id.clone()).expect("begin during break");
+    assert!(!lock_runtime(&state)
+        .expect("runtime lock")
+        .task_assignments_by_task
+        .contains_key(task.id.as_str()));
+}
+
+#[test]
+fn daily_recurring_task_materializes_once_per_day() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("recurring_tasks.json"),
+        r#"{
+  "schema": 1,
+  "recurringTasks": [
+    {
+      "id": "standup-prep",
+      "title": "Daily standup prep",
+      "rrule": "FREQ=DAILY",
+      "estimatedPomodoros": 1
+    }
+  ]
+}
+"#,
+    )
+    .expect("write recurring tasks config");
+
+    let first = materialize_recurring_tasks_impl(&state, "2026-02-16".to_string())
+        .expect("materialize first day");
+    let repeated = materialize_recurring_tasks_impl(&state, "2026-02-16".to_string())
+        .expect("materialize same day again");
+    let next_day = materialize_recurring_tasks_impl(&state, "2026-02-17".to_string())
+        .expect("materialize next day");
+
+    assert_eq!(first.len(), 1);
+    assert_eq!(first[0].title, "Daily standup prep");
+    assert_eq!(first[0].estimated_pomodoros, Some(1));
+    assert!(repeated.is_empty());
+    assert_eq!(next_day.len(), 1);
+    assert_ne!(next_day[0].id, first[0].id);
+    assert_eq!(list_tasks_impl(&state, None).expect("list tasks").len(), 2);
+}
+
+#[tokio::test]
+async fn calendar_task_linkage_restores_assignment_for_known_tasks_only() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    let task = create_task_impl(&state, "Linked task".to_string(), None, None).expect("create task");
+    let events = vec![
+        encode_block_event_with_task(&generated[0], Some(task.id.as_str())),
+        encode_block_event_with_task(&generated[1], Some("task-from-another-device")),
+    ];
+
+    let mut runtime = lock_runtime(&state).expect("runtime lock");
+    let restored = restore_task_assignments_from_events(&mut runtime, &events);
+
+    assert_eq!(restored, 1);
+    assert_eq!(
+        runtime.task_assignments_by_block.get(generated[0].id.as_str()),
+        Some(&task.id)
+    );
+    assert!(!runtime
+        .task_assignments_by_block
+        .contains_key(generated[1].id.as_str()));
+    assert_eq!(restore_task_assignments_from_events(&mut runtime, &events), 0);
+}
+
+#[test]
+fn suggest_pomodoros_rounds_up_to_whole_focus_cycles() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+
+    assert_eq!(suggest_pomodoros_impl(&state, 120).expect("suggest"), 4);
+    assert_eq!(suggest_pomodoros_impl(&state, 100).expect("suggest"), 4);
+    assert_eq!(suggest_pomodoros_impl(&state, 20).expect("suggest"), 1);
+    assert!(suggest_pomodoros_impl(&state, 0).is_err());
+}
+
+#[test]
+fn unassigned_tasks_exclude_assigned_and_completed_tasks() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let tasks = ["Draft outline", "Review PR", "Plan sprint"]
+        .into_iter()
+        .map(|title| create_task_impl(&state, title.to_string(), None, None).expect("create task"))
+        .collect::<Vec<_>>();
+    lock_runtime(&state)
+        .expect("runtime lock")
+        .task_assignments_by_task
+        .insert(tasks[1].id.clone(), "blk-assigned".to_string());
+
+    let backlog = list_unassigned_tasks_impl(&state).expect("list backlog");
+    assert_eq!(
+        backlog.iter().map(|task| task.id.as_str()).collect::<Vec<_>>(),
+        vec![tasks[0].id.as_str(), tasks[2].id.as_str()]
+    );
+
+    update_task_impl(
+        &state,
+        tasks[2].id.clone(),
+        None,
+        None,
+        None,
+        Some("completed".to_string()),
+        None,
+    )
+    .expect("complete task");
+    let backlog = list_unassigned_tasks_impl(&state).expect("list backlog");
+    assert_eq!(backlog.len(), 1);
+    assert_eq!(backlog[0].id, tasks[0].id);
+}
+
+#[test]
+fn move_task_reorders_and_stops_at_the_ends() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let tasks = create_tasks_bulk_impl(&state, "A\nB\nC".to_string(), None).expect("create tasks");
+    let (a, b, c) = (tasks[0].id.clone(), tasks[1].id.clone(), tasks[2].id.clone());
+    let titles = |moved: Vec<crate::domain::models::Task>| {
+        moved.into_iter().map(|task| task.title).collect::<Vec<_>>().join("")
+    };
+
+    let moved = move_task_impl(&state, c.clone(), "top".to_string()).expect("move to top");
+    assert_eq!(titles(moved), "CAB");
+    let moved = move_task_impl(&state, c.clone(), "up".to_string()).expect("up at top");
+    assert_eq!(titles(moved), "CAB");
+    let moved = move_task_impl(&state, c.clone(), "down".to_string()).expect("move down");
+    assert_eq!(titles(moved), "ACB");
+    let moved = move_task_impl(&state, b, "up".to_string()).expect("move up");
+    assert_eq!(titles(moved), "ABC");
+    let moved = move_task_impl(&state, a.clone(), "bottom".to_string()).expect("move to bottom");
+    assert_eq!(titles(moved), "BCA");
+    let moved = move_task_impl(&state, a.clone(), "down".to_string()).expect("down at bottom");
+    assert_eq!(titles(moved), "BCA");
+
+    assert!(move_task_impl(&state, a, "sideways".to_string()).is_err());
+    assert!(move_task_impl(&state, "missing".to_string(), "top".to_string()).is_err());
+}
+
+#[test]
+fn list_tasks_query_matches_title_or_description_ignoring_case() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let documented = create_task_impl(
+        &state,
+        "Quarterly report".to_string(),
+        Some("Collect the Invoice totals".to_string()),
+        None,
+    )
+    .expect("create documented task");
+    create_task_impl(&state, "Inbox zero".to_string(), None, None).expect("create other task");
+
+    let matched =
+        list_tasks_impl(&state, Some("invoice".to_string())).expect("list by description");
+    assert_eq!(matched.len(), 1);
+    assert_eq!(matched[0].id, documented.id);
+
+    let unmatched = list_tasks_impl(&state, Some("groceries".to_string())).expect("list unmatched");
+    assert!(unmatched.is_empty());
+}
@@ -0,0 +1,149 @@
+use crate::application::commands::{
+    create_task_impl, generate_blocks_impl, get_effective_config_impl, get_metrics_impl,
+    get_timezone_impl, get_workspace_paths_impl, lint_config_impl, list_timezones_impl,
+    ConfigIssue, Metrics,
+};
+use crate::application::test_support::workspace::TempWorkspace;
+use crate::infrastructure::error::InfraError;
+use std::fs;
+
+#[test]
+fn get_workspace_paths_returns_temp_workspace_locations() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+
+    let paths = get_workspace_paths_impl(&state).expect("workspace paths");
+
+    assert_eq!(
+        paths.config_dir,
+        workspace.path().join("config").display().to_string()
+    );
+    assert_eq!(
+        paths.logs_dir,
+        workspace.path().join("logs").display().to_string()
+    );
+    assert!(paths
+        .database_path
+        .starts_with(&workspace.path().display().to_string()));
+    assert_eq!(paths.database_path, state.database_path().display().to_string());
+}
+
+#[tokio::test]
+async fn metrics_count_task_creation_and_block_generation() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    assert_eq!(get_metrics_impl(&state).expect("metrics"), Metrics::default());
+
+    create_task_impl(&state, "metrics task".to_string(), None, Some(1)).expect("create task");
+    let generated = generate_blocks_impl(&state, "2026-02-16".to_string(), None)
+        .await
+        .expect("generate blocks");
+    assert!(!generated.is_empty());
+    let _ = state.command_error("metrics_probe", &InfraError::InvalidConfig("probe".to_string()));
+
+    let metrics = get_metrics_impl(&state).expect("metrics");
+    assert_eq!(metrics.tasks_created, 1);
+    assert_eq!(metrics.blocks_generated, generated.len() as u64);
+    assert_eq!(metrics.command_errors, 1);
+    assert_eq!(metrics.syncs_run, 0);
+    assert_eq!(metrics.slow_generations, 0);
+}
+
+#[test]
+fn timezone_getter_follows_config_and_list_covers_iana_names() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    assert_eq!(get_timezone_impl(&state).expect("default timezone"), "UTC");
+
+    let app_config_path = state.config_dir().join("app.json");
+    let mut app_config: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&app_config_path).expect("read app config"))
+            .expect("parse app config");
+    app_config["timezone"] = serde_json::Value::String("Asia/Tokyo".to_string());
+    fs::write(&app_config_path, app_config.to_string()).expect("write app config");
+    assert_eq!(get_timezone_impl(&state).expect("configured timezone"), "Asia/Tokyo");
+
+    let timezones = list_timezones_impl();
+    assert!(timezones.iter().any(|name| name == "UTC"));
+    assert!(timezones.iter().any(|name| name == "Asia/Tokyo"));
+}
+
+#[test]
+fn effective_config_resolves_defaults_and_overrides() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    fs::write(
+        state.config_dir().join("policies.json"),
+        r#"{"blockDurationMinutes":45}"#,
+    )
+    .expect("write policies");
+
+    let config = get_effective_config_impl(&state).expect("effective config");
+
+    assert_eq!(config.block_duration_minutes, 45);
+    assert_eq!(config.timezone, "UTC");
+    assert_eq!(config.work_start, "09:00");
+    assert_eq!(config.work_end, "18:00");
+    assert_eq!(
+        config.work_days,
+        vec!["Monday", "Tuesday", "Wednesday", "Thursday", "Friday"]
+    );
+    assert_eq!(config.break_duration_minutes, 5);
+    assert_eq!(config.max_auto_blocks_per_day, 24);
+    assert_eq!(config.max_relocations_per_sync, 50);
+    assert_eq!(config.catch_up_max_days, 7);
+    assert_eq!(config.max_daily_focus_minutes, None);
+    assert_eq!(config.quiet_hours, None);
+    assert!(config.auto_enabled);
+    assert!(config.skip_past);
+}
+
+#[test]
+fn lint_config_reports_template_missing_duration() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    assert!(lint_config_impl(&state).expect("lint default config").is_empty());
+
+    fs::write(
+        state.config_dir().join("templates.json"),
+        r#"{"templates":[
+  { "id": "tpl-ok", "start": "09:00", "durationMinutes": 60 },
+  { "id": "tpl-typo", "start": "10:00", "durationMinuets": 30 }
+]}"#,
+    )
+    .expect("write templates");
+
+    let issues = lint_config_impl(&state).expect("lint config");
+
+    assert_eq!(
+        issues,
+        vec![ConfigIssue {
+            file: "templates.json".to_string(),
+            id: Some("tpl-typo".to_string()),
+            problem: "missing durationMinutes".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn lint_config_reports_unknown_timezone() {
+    let workspace = TempWorkspace::new();
+    let state = workspace.app_state();
+    let app_config_path = state.config_dir().join("app.json");
+    let mut app_config: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&app_config_path).expect("read app config"))
+            .expect("parse app config");
+    app_config["timezone"] = serde_json::Value::String("Asia/Tokio".to_string());
+    fs::write(&app_config_path, app_config.to_string()).expect("write app config");
+
+    let issues = lint_config_impl(&state).expect("lint config");
+
+    assert_eq!(
+        issues,
+        vec![ConfigIssue {
+            file: "app.json".to_string(),
+            id: None,
+            problem: "unknown timezone \"Asia/Tokio\", falling back to UTC".to_string(),
+        }]
+    );
+}
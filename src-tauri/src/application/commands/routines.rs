@@ -1,3 +1,4 @@
+use crate::application::configured_holidays::{add_holiday, load_holidays, remove_holiday};
 use crate::application::configured_routines::{
     delete_routine_schedule, load_configured_routines, save_routine_schedule,
     save_routine_schedule_group,
@@ -55,3 +56,31 @@ pub fn delete_routine_schedule_impl(
     }
     Ok(deleted)
 }
+
+pub fn list_holidays_impl(state: &super::bootstrap::AppState) -> Result<Vec<String>, InfraError> {
+    let holidays = load_holidays(state.config_dir())?;
+    for entry in &holidays.invalid_entries {
+        state.log_warn("list_holidays", &format!("ignored invalid holiday {entry}"));
+    }
+    Ok(holidays.dates.iter().map(ToString::to_string).collect())
+}
+
+pub fn add_holiday_impl(
+    state: &super::bootstrap::AppState,
+    date: String,
+) -> Result<Vec<String>, InfraError> {
+    let holidays = add_holiday(state.config_dir(), &date)?;
+    state.log_info("add_holiday", &format!("added date={}", date.trim()));
+    Ok(holidays.iter().map(ToString::to_string).collect())
+}
+
+pub fn remove_holiday_impl(
+    state: &super::bootstrap::AppState,
+    date: String,
+) -> Result<bool, InfraError> {
+    let removed = remove_holiday(state.config_dir(), &date)?;
+    if removed {
+        state.log_info("remove_holiday", &format!("removed date={}", date.trim()));
+    }
+    Ok(removed)
+}
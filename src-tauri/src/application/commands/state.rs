@@ -1,7 +1,9 @@
+use super::metrics::MetricsCounters;
 use crate::application::bootstrap::bootstrap_workspace;
-use crate::application::pomodoro_service::PomodoroRuntimeState;
+use crate::application::policy_service::invalid_timezone_warning;
+use crate::application::pomodoro_service::{restore_pomodoro_session, PomodoroRuntimeState};
 use crate::domain::models::{Block, Task};
-use crate::infrastructure::calendar_cache::InMemoryCalendarCacheRepository;
+use crate::infrastructure::calendar_cache::SqliteCalendarCacheRepository;
 use crate::infrastructure::error::InfraError;
 use crate::infrastructure::event_mapper::GoogleCalendarEvent;
 use chrono::{NaiveDate, Utc};
@@ -9,6 +11,7 @@ use std::collections::HashMap;
 use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, MutexGuard};
 
 #[derive(Debug, Default)]
@@ -20,6 +23,7 @@ pub(crate) struct RuntimeState {
     pub(crate) task_assignments_by_block: HashMap<String, String>,
     pub(crate) synced_events_by_account: HashMap<String, Vec<GoogleCalendarEvent>>,
     pub(crate) blocks_calendar_ids: HashMap<String, String>,
+    pub(crate) calendar_timezones: HashMap<String, String>,
     pub(crate) pomodoro: PomodoroRuntimeState,
 }
 
@@ -34,23 +38,49 @@ pub struct AppState {
     config_dir: PathBuf,
     database_path: PathBuf,
     logs_dir: PathBuf,
-    calendar_cache: Arc<InMemoryCalendarCacheRepository>,
     runtime: Mutex<RuntimeState>,
+    sync_locks: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+    sso_cancel_flag: Mutex<Option<Arc<AtomicBool>>>,
+    pending_oauth_states: Mutex<HashMap<String, String>>,
+    metrics: MetricsCounters,
     log_guard: Mutex<()>,
+    auto_generation_shutdown: tokio::sync::watch::Sender<bool>,
 }
 
 impl AppState {
     pub fn new(workspace_root: PathBuf) -> Result<Self, InfraError> {
         let bootstrap = bootstrap_workspace(&workspace_root)?;
+        let mut runtime = RuntimeState::default();
+        for (account_id, cached) in
+            SqliteCalendarCacheRepository::load_all_accounts(&bootstrap.database_path)?
+        {
+            if let Some(calendar_id) = cached.calendar_id {
+                runtime
+                    .blocks_calendar_ids
+                    .insert(account_id.clone(), calendar_id);
+            }
+            runtime
+                .synced_events_by_account
+                .insert(account_id, cached.events);
+        }
+        restore_pomodoro_session(&bootstrap.database_path, &mut runtime.pomodoro, Utc::now())?;
 
-        Ok(Self {
+        let state = Self {
             config_dir: bootstrap.config_dir,
             database_path: bootstrap.database_path,
             logs_dir: bootstrap.logs_dir,
-            calendar_cache: Arc::new(InMemoryCalendarCacheRepository::default()),
-            runtime: Mutex::new(RuntimeState::default()),
+            runtime: Mutex::new(runtime),
+            sync_locks: Mutex::new(HashMap::new()),
+            sso_cancel_flag: Mutex::new(None),
+            pending_oauth_states: Mutex::new(HashMap::new()),
+            metrics: MetricsCounters::default(),
             log_guard: Mutex::new(()),
-        })
+            auto_generation_shutdown: tokio::sync::watch::channel(false).0,
+        };
+        if let Some(warning) = invalid_timezone_warning(&state.config_dir) {
+            state.log_warn("bootstrap", &warning);
+        }
+        Ok(state)
     }
 
     pub fn config_dir(&self) -> &Path {
@@ -61,8 +91,88 @@ impl AppState {
         &self.database_path
     }
 
-    pub(crate) fn calendar_cache(&self) -> Arc<InMemoryCalendarCacheRepository> {
-        Arc::clone(&self.calendar_cache)
+    pub fn logs_dir(&self) -> &Path {
+        &self.logs_dir
+    }
+
+    /// The account's persisted event cache, shared by sync diffs and restarts.
+    pub(crate) fn calendar_cache(&self, account_id: &str) -> Arc<SqliteCalendarCacheRepository> {
+        Arc::new(SqliteCalendarCacheRepository::new(&self.database_path, account_id))
+    }
+
+    pub(crate) fn metrics(&self) -> &MetricsCounters {
+        &self.metrics
+    }
+
+    pub(crate) fn account_sync_lock(
+        &self,
+        account_id: &str,
+    ) -> Result<Arc<tokio::sync::Mutex<()>>, InfraError> {
+        let mut sync_locks = self
+            .sync_locks
+            .lock()
+            .map_err(|error| InfraError::InvalidConfig(format!("sync lock poisoned: {error}")))?;
+        Ok(Arc::clone(
+            sync_locks.entry(account_id.to_string()).or_default(),
+        ))
+    }
+
+    pub(crate) fn begin_sso_attempt(&self) -> Result<Arc<AtomicBool>, InfraError> {
+        let flag = Arc::new(AtomicBool::new(false));
+        *self.sso_cancel_guard()? = Some(Arc::clone(&flag));
+        Ok(flag)
+    }
+
+    pub(crate) fn finish_sso_attempt(&self, flag: &Arc<AtomicBool>) -> Result<(), InfraError> {
+        let mut current = self.sso_cancel_guard()?;
+        if current.as_ref().is_some_and(|active| Arc::ptr_eq(active, flag)) {
+            *current = None;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn cancel_sso_attempt(&self) -> Result<bool, InfraError> {
+        let Some(flag) = self.sso_cancel_guard()?.take() else {
+            return Ok(false);
+        };
+        flag.store(true, Ordering::SeqCst);
+        Ok(true)
+    }
+
+    fn sso_cancel_guard(&self) -> Result<MutexGuard<'_, Option<Arc<AtomicBool>>>, InfraError> {
+        self.sso_cancel_flag
+            .lock()
+            .map_err(|error| InfraError::InvalidConfig(format!("sso lock poisoned: {error}")))
+    }
+
+    pub(crate) fn auto_generation_shutdown_signal(&self) -> tokio::sync::watch::Receiver<bool> {
+        self.auto_generation_shutdown.subscribe()
+    }
+
+    pub fn shutdown_auto_generation(&self) {
+        self.auto_generation_shutdown.send_replace(true);
+    }
+
+    pub(crate) fn remember_oauth_state(
+        &self,
+        account_id: &str,
+        oauth_state: &str,
+    ) -> Result<(), InfraError> {
+        self.pending_oauth_states_guard()?
+            .insert(account_id.to_string(), oauth_state.to_string());
+        Ok(())
+    }
+
+    pub(crate) fn take_oauth_state(&self, account_id: &str) -> Result<Option<String>, InfraError> {
+        Ok(self.pending_oauth_states_guard()?.remove(account_id))
+    }
+
+    fn pending_oauth_states_guard(
+        &self,
+    ) -> Result<MutexGuard<'_, HashMap<String, String>>, InfraError> {
+        self.pending_oauth_states
+            .lock()
+            .map_err(|error| InfraError::InvalidConfig(format!("oauth state lock poisoned: {error}")))
     }
 
     pub(crate) fn replace_synced_events(
@@ -71,6 +181,8 @@ impl AppState {
         latest_events: Vec<GoogleCalendarEvent>,
         calendar_id: &str,
     ) -> Result<Vec<GoogleCalendarEvent>, InfraError> {
+        self.calendar_cache(account_id)
+            .replace_all(&latest_events, Some(calendar_id))?;
         let mut runtime = lock_runtime(self)?;
         let previous = runtime
             .synced_events_by_account
@@ -107,6 +219,7 @@ impl AppState {
     }
 
     pub fn command_error(&self, command: &str, error: &InfraError) -> String {
+        MetricsCounters::add(&self.metrics.command_errors, 1);
         self.log_error(command, &error.to_string());
         error.to_string()
     }
@@ -115,6 +228,10 @@ impl AppState {
         self.append_log("info", command, message);
     }
 
+    pub fn log_warn(&self, command: &str, message: &str) {
+        self.append_log("warn", command, message);
+    }
+
     pub fn log_error(&self, command: &str, message: &str) {
         self.append_log("error", command, message);
     }
@@ -199,6 +316,7 @@ pub(crate) fn persist_generated_blocks(
             .blocks
             .insert(stored.block.id.clone(), stored.clone());
     }
+    MetricsCounters::add(&state.metrics.blocks_generated, created.len());
     Ok(())
 }
 
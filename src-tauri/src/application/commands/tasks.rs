@@ -1,6 +1,7 @@
 pub use crate::application::task_service::CarryOverTaskResponse;
+use super::metrics::MetricsCounters;
 use crate::application::task_service::TaskService;
-use crate::domain::models::Task;
+use crate::domain::models::{Block, Task};
 use crate::infrastructure::error::InfraError;
 
 pub fn create_task_impl(
@@ -9,11 +10,39 @@ pub fn create_task_impl(
     description: Option<String>,
     estimated_pomodoros: Option<u32>,
 ) -> Result<Task, InfraError> {
-    TaskService::new(state).create_task(title, description, estimated_pomodoros)
+    let task = TaskService::new(state).create_task(title, description, estimated_pomodoros)?;
+    MetricsCounters::add(&state.metrics().tasks_created, 1);
+    Ok(task)
 }
 
-pub fn list_tasks_impl(state: &super::bootstrap::AppState) -> Result<Vec<Task>, InfraError> {
-    TaskService::new(state).list_tasks()
+pub fn create_tasks_bulk_impl(
+    state: &super::bootstrap::AppState,
+    lines: String,
+    default_estimate: Option<u32>,
+) -> Result<Vec<Task>, InfraError> {
+    let tasks = TaskService::new(state).create_tasks_bulk(lines, default_estimate)?;
+    MetricsCounters::add(&state.metrics().tasks_created, tasks.len());
+    Ok(tasks)
+}
+
+pub fn list_tasks_impl(
+    state: &super::bootstrap::AppState,
+    query: Option<String>,
+) -> Result<Vec<Task>, InfraError> {
+    TaskService::new(state).list_tasks(query)
+}
+
+pub fn list_unassigned_tasks_impl(
+    state: &super::bootstrap::AppState,
+) -> Result<Vec<Task>, InfraError> {
+    TaskService::new(state).list_unassigned_tasks()
+}
+
+pub fn materialize_recurring_tasks_impl(
+    state: &super::bootstrap::AppState,
+    date: String,
+) -> Result<Vec<Task>, InfraError> {
+    TaskService::new(state).materialize_recurring_tasks(date)
 }
 
 pub fn update_task_impl(
@@ -23,8 +52,16 @@ pub fn update_task_impl(
     description: Option<String>,
     estimated_pomodoros: Option<u32>,
     status: Option<String>,
+    preferred_block_type: Option<String>,
 ) -> Result<Task, InfraError> {
-    TaskService::new(state).update_task(task_id, title, description, estimated_pomodoros, status)
+    TaskService::new(state).update_task(
+        task_id,
+        title,
+        description,
+        estimated_pomodoros,
+        status,
+        preferred_block_type,
+    )
 }
 
 pub fn delete_task_impl(
@@ -34,6 +71,14 @@ pub fn delete_task_impl(
     TaskService::new(state).delete_task(task_id)
 }
 
+pub fn move_task_impl(
+    state: &super::bootstrap::AppState,
+    task_id: String,
+    position: String,
+) -> Result<Vec<Task>, InfraError> {
+    TaskService::new(state).move_task(task_id, position)
+}
+
 pub fn split_task_impl(
     state: &super::bootstrap::AppState,
     task_id: String,
@@ -42,6 +87,28 @@ pub fn split_task_impl(
     TaskService::new(state).split_task(task_id, parts)
 }
 
+pub fn get_estimated_completion_impl(
+    state: &super::bootstrap::AppState,
+    task_id: String,
+    account_id: Option<String>,
+) -> Result<Option<String>, InfraError> {
+    TaskService::new(state).get_estimated_completion(task_id, account_id, chrono::Utc::now())
+}
+
+pub fn suggest_pomodoros_impl(
+    state: &super::bootstrap::AppState,
+    estimated_minutes: u32,
+) -> Result<u32, InfraError> {
+    TaskService::new(state).suggest_pomodoros(estimated_minutes)
+}
+
+pub fn begin_task_impl(
+    state: &super::bootstrap::AppState,
+    task_id: String,
+) -> Result<Task, InfraError> {
+    TaskService::new(state).begin_task(task_id, chrono::Utc::now())
+}
+
 pub fn carry_over_task_impl(
     state: &super::bootstrap::AppState,
     task_id: String,
@@ -50,3 +117,19 @@ pub fn carry_over_task_impl(
 ) -> Result<CarryOverTaskResponse, InfraError> {
     TaskService::new(state).carry_over_task(task_id, from_block_id, candidate_block_ids)
 }
+
+pub fn suggest_block_for_task_impl(
+    state: &super::bootstrap::AppState,
+    task_id: String,
+    date: String,
+    account_id: Option<String>,
+) -> Result<Option<Block>, InfraError> {
+    TaskService::new(state).suggest_block_for_task(task_id, date, account_id)
+}
+
+pub fn carry_over_candidates_impl(
+    state: &super::bootstrap::AppState,
+    from_block_id: String,
+) -> Result<Vec<Block>, InfraError> {
+    TaskService::new(state).carry_over_candidates(from_block_id)
+}
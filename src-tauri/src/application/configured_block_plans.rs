@@ -3,6 +3,7 @@ use crate::application::configured_routines::load_configured_routines;
 use crate::application::policy_service::{parse_weekday, RuntimePolicy};
 use crate::domain::models::{AutoDriveMode, Firmness, Recipe};
 use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
+use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
@@ -16,10 +17,48 @@ pub struct ConfiguredBlockPlan {
     pub planned_pomodoros: i32,
     pub source: String,
     pub source_id: Option<String>,
+    pub project: Option<String>,
     pub recipe_id: String,
     pub auto_drive_mode: AutoDriveMode,
 }
 
+#[derive(Debug, Clone)]
+pub struct FloatingBlockPlan {
+    pub instance: String,
+    pub duration_minutes: u32,
+    pub firmness: Firmness,
+    pub planned_pomodoros: i32,
+    pub source: String,
+    pub source_id: Option<String>,
+    pub project: Option<String>,
+    pub recipe_id: String,
+    pub auto_drive_mode: AutoDriveMode,
+}
+
+/// Plans resolved for one date: pinned plans carry a start, floating plans only a duration.
+/// `issues` lists the config entries the loader skipped or read with a fallback.
+#[derive(Debug, Clone, Default)]
+pub struct ConfiguredPlans {
+    pub pinned: Vec<ConfiguredBlockPlan>,
+    pub floating: Vec<FloatingBlockPlan>,
+    pub dropped: Vec<DroppedPlan>,
+    pub issues: Vec<ConfigIssue>,
+}
+
+/// A pinned plan left out because it overlapped a firmer plan from another source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DroppedPlan {
+    pub instance: String,
+    pub overlapped_instance: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ConfigIssue {
+    pub file: String,
+    pub id: Option<String>,
+    pub problem: String,
+}
+
 #[derive(Debug, Clone)]
 struct TemplateDefinition {
     id: String,
@@ -30,18 +69,19 @@ struct TemplateDefinition {
     days: Option<HashSet<Weekday>>,
     recipe_id: Option<String>,
     auto_drive_mode: Option<AutoDriveMode>,
+    project: Option<String>,
 }
 
-pub fn load_configured_block_plans(
+pub fn load_configured_plans(
     config_dir: &Path,
     date: NaiveDate,
     policy: &RuntimePolicy,
     recipes: &[Recipe],
-) -> Vec<ConfiguredBlockPlan> {
-    let templates_raw = read_config_array(config_dir, "templates.json", "templates");
-    let routines_raw = load_configured_routines(config_dir);
-    let templates = parse_template_definitions(&templates_raw);
+) -> ConfiguredPlans {
+    let definitions = read_configured_definitions(config_dir);
+    let templates = &definitions.templates;
     let mut plans = Vec::new();
+    let mut floating = Vec::new();
 
     for template in templates.values() {
         if !template_applies_on_date(template, date) {
@@ -69,22 +109,13 @@ pub fn load_configured_block_plans(
             }),
             source: "template".to_string(),
             source_id: Some(template.id.clone()),
+            project: template.project.clone(),
             recipe_id,
             auto_drive_mode,
         });
     }
 
-    for routine_raw in routines_raw {
-        let Some(routine) = routine_raw.as_object() else {
-            continue;
-        };
-        let Some(routine_id) = value_by_keys(routine, &["id"])
-            .and_then(serde_json::Value::as_str)
-            .map(str::trim)
-            .filter(|value| !value.is_empty())
-        else {
-            continue;
-        };
+    for (routine_id, routine) in &definitions.routines {
         if !routine_matches_date(routine, date) {
             continue;
         }
@@ -105,9 +136,6 @@ pub fn load_configured_block_plans(
                     .and_then(parse_time_value)
             })
             .or_else(|| linked_template.and_then(|template| template.start));
-        let Some(start) = start else {
-            continue;
-        };
 
         let duration_minutes = default
             .and_then(|value| value_by_keys(value, &["durationMinutes", "duration_minutes"]))
@@ -121,11 +149,6 @@ pub fn load_configured_block_plans(
             continue;
         };
 
-        let Ok(start_at) = local_datetime_to_utc(date, start, policy.timezone) else {
-            continue;
-        };
-        let end_at = start_at + chrono::Duration::minutes(duration_minutes as i64);
-
         let firmness = parse_firmness_value(
             default
                 .and_then(|value| value_by_keys(value, &["firmness"]))
@@ -167,22 +190,59 @@ pub fn load_configured_block_plans(
         .or_else(|| linked_template.and_then(|template| template.auto_drive_mode.clone()));
         let (recipe_id, auto_drive_mode) =
             resolve_recipe_for_plan(explicit_recipe_id, auto_drive_override, recipes);
+        let project = parse_project_value(
+            default
+                .and_then(|value| value_by_keys(value, &["project"]))
+                .or_else(|| value_by_keys(routine, &["project"])),
+        )
+        .or_else(|| linked_template.and_then(|template| template.project.clone()));
 
+        let instance = format!("rtn:{}:{}", routine_id, date);
+        let Some(start) = start else {
+            floating.push(FloatingBlockPlan {
+                instance,
+                duration_minutes,
+                firmness,
+                planned_pomodoros,
+                source: "routine".to_string(),
+                source_id: Some(routine_id.to_string()),
+                project,
+                recipe_id,
+                auto_drive_mode,
+            });
+            continue;
+        };
+        let Ok(start_at) = local_datetime_to_utc(date, start, policy.timezone) else {
+            continue;
+        };
+        let end_at = start_at + chrono::Duration::minutes(duration_minutes as i64);
         plans.push(ConfiguredBlockPlan {
-            instance: format!("rtn:{}:{}", routine_id, date),
+            instance,
             start_at,
             end_at,
             firmness,
             planned_pomodoros,
             source: "routine".to_string(),
             source_id: Some(routine_id.to_string()),
+            project,
             recipe_id,
             auto_drive_mode,
         });
     }
 
+    let (mut plans, dropped) = drop_overlapping_plans(plans);
     plans.sort_by(|left, right| left.start_at.cmp(&right.start_at));
-    plans
+    floating.sort_by(|left, right| left.instance.cmp(&right.instance));
+    ConfiguredPlans {
+        pinned: plans,
+        floating,
+        dropped,
+        issues: definitions.issues,
+    }
+}
+
+pub fn lint_configured_block_plans(config_dir: &Path) -> Vec<ConfigIssue> {
+    read_configured_definitions(config_dir).issues
 }
 
 pub fn resolve_recipe_for_plan(
@@ -241,10 +301,43 @@ fn read_config_array(config_dir: &Path, file_name: &str, array_key: &str) -> Vec
         .unwrap_or_default()
 }
 
-fn parse_template_definitions(templates_raw: &[serde_json::Value]) -> HashMap<String, TemplateDefinition> {
+/// Templates and routines the loader accepts, with an issue for every entry it skipped or read
+/// with a fallback.
+struct ConfiguredDefinitions {
+    templates: HashMap<String, TemplateDefinition>,
+    routines: Vec<(String, serde_json::Map<String, serde_json::Value>)>,
+    issues: Vec<ConfigIssue>,
+}
+
+fn read_configured_definitions(config_dir: &Path) -> ConfiguredDefinitions {
+    let mut issues = Vec::new();
+    let templates_raw = read_config_array(config_dir, "templates.json", "templates");
+    let templates = parse_template_definitions(&templates_raw, &mut issues);
+    let routines_raw = load_configured_routines(config_dir);
+    let routines = parse_routine_definitions(&routines_raw, &templates, &mut issues);
+    ConfiguredDefinitions {
+        templates,
+        routines,
+        issues,
+    }
+}
+
+fn push_issue(issues: &mut Vec<ConfigIssue>, file: &str, id: Option<&str>, problem: &str) {
+    issues.push(ConfigIssue {
+        file: file.to_string(),
+        id: id.map(ToOwned::to_owned),
+        problem: problem.to_string(),
+    });
+}
+
+fn parse_template_definitions(
+    templates_raw: &[serde_json::Value],
+    issues: &mut Vec<ConfigIssue>,
+) -> HashMap<String, TemplateDefinition> {
     let mut templates = HashMap::new();
     for template_raw in templates_raw {
         let Some(template) = template_raw.as_object() else {
+            push_issue(issues, "templates.json", None, "entry is not an object");
             continue;
         };
         let Some(template_id) = value_by_keys(template, &["id"])
@@ -252,16 +345,31 @@ fn parse_template_definitions(templates_raw: &[serde_json::Value]) -> HashMap<St
             .map(str::trim)
             .filter(|value| !value.is_empty())
         else {
+            push_issue(issues, "templates.json", None, "missing id");
             continue;
         };
         let Some(duration_minutes) = value_by_keys(template, &["durationMinutes", "duration_minutes"])
             .and_then(parse_positive_u32_value)
         else {
+            push_issue(issues, "templates.json", Some(template_id), "missing durationMinutes");
             continue;
         };
-        let start = value_by_keys(template, &["start", "time"]).and_then(parse_time_value);
-        let firmness =
-            parse_firmness_value(value_by_keys(template, &["firmness"])).unwrap_or(Firmness::Draft);
+        if templates.contains_key(template_id) {
+            push_issue(issues, "templates.json", Some(template_id), "duplicate id");
+            continue;
+        }
+        let start_value = value_by_keys(template, &["start", "time"]);
+        let start = start_value.and_then(parse_time_value);
+        if start_value.is_some() && start.is_none() {
+            push_issue(issues, "templates.json", Some(template_id), "invalid start time");
+        }
+        let firmness_value = value_by_keys(template, &["firmness"]);
+        let firmness = parse_firmness_value(firmness_value).unwrap_or_else(|| {
+            if firmness_value.is_some() {
+                push_issue(issues, "templates.json", Some(template_id), "unknown firmness");
+            }
+            Firmness::Draft
+        });
         let planned_pomodoros = value_by_keys(
             template,
             &["plannedPomodoros", "planned_pomodoros", "pomodoros"],
@@ -283,7 +391,7 @@ fn parse_template_definitions(templates_raw: &[serde_json::Value]) -> HashMap<St
             .map(ToOwned::to_owned);
         let auto_drive_mode =
             parse_auto_drive_mode_value(value_by_keys(template, &["autoDriveMode", "auto_drive_mode"]));
-
+        let project = parse_project_value(value_by_keys(template, &["project"]));
         templates.insert(
             template_id.to_string(),
             TemplateDefinition {
@@ -295,12 +403,134 @@ fn parse_template_definitions(templates_raw: &[serde_json::Value]) -> HashMap<St
                 days,
                 recipe_id,
                 auto_drive_mode,
+                project,
             },
         );
     }
     templates
 }
 
+/// Keeps the routines generation can use, in file order; the first of a duplicated id wins.
+fn parse_routine_definitions(
+    routines_raw: &[serde_json::Value],
+    templates: &HashMap<String, TemplateDefinition>,
+    issues: &mut Vec<ConfigIssue>,
+) -> Vec<(String, serde_json::Map<String, serde_json::Value>)> {
+    let mut routines = Vec::new();
+    let mut seen_routine_ids = HashSet::new();
+    for routine_raw in routines_raw {
+        let Some(routine) = routine_raw.as_object() else {
+            push_issue(issues, "routines.json", None, "entry is not an object");
+            continue;
+        };
+        let Some(routine_id) = value_by_keys(routine, &["id"])
+            .and_then(serde_json::Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        else {
+            push_issue(issues, "routines.json", None, "missing id");
+            continue;
+        };
+        if !seen_routine_ids.insert(routine_id) {
+            push_issue(issues, "routines.json", Some(routine_id), "duplicate id");
+            continue;
+        }
+        let template_id = value_by_keys(routine, &["template_id", "templateId"])
+            .and_then(serde_json::Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty());
+        let linked_template = template_id.and_then(|template_id| templates.get(template_id));
+        if template_id.is_some() && linked_template.is_none() {
+            push_issue(issues, "routines.json", Some(routine_id), "unknown templateId");
+        }
+        let default = value_by_keys(routine, &["default"]).and_then(serde_json::Value::as_object);
+        let schedule = value_by_keys(routine, &["schedule"]).and_then(serde_json::Value::as_object);
+
+        let start_values = [
+            default.and_then(|value| value_by_keys(value, &["start", "time"])),
+            schedule.and_then(|value| value_by_keys(value, &["time", "start"])),
+        ];
+        if start_values
+            .iter()
+            .flatten()
+            .any(|value| parse_time_value(value).is_none())
+        {
+            push_issue(issues, "routines.json", Some(routine_id), "invalid start time");
+        }
+        if default
+            .and_then(|value| value_by_keys(value, &["firmness"]))
+            .or_else(|| value_by_keys(routine, &["firmness"]))
+            .is_some_and(|value| parse_firmness_value(Some(value)).is_none())
+        {
+            push_issue(issues, "routines.json", Some(routine_id), "unknown firmness");
+        }
+
+        let has_duration = default
+            .and_then(|value| value_by_keys(value, &["durationMinutes", "duration_minutes"]))
+            .and_then(parse_positive_u32_value)
+            .or_else(|| {
+                value_by_keys(routine, &["durationMinutes", "duration_minutes"])
+                    .and_then(parse_positive_u32_value)
+            })
+            .is_some()
+            || linked_template.is_some();
+        if !has_duration {
+            push_issue(issues, "routines.json", Some(routine_id), "missing durationMinutes");
+            continue;
+        }
+        routines.push((routine_id.to_string(), routine.clone()));
+    }
+    routines
+}
+
+/// When plans from different sources overlap, keeps the firmer one, preferring templates over
+/// routines on a tie. Overlaps within one source are left to the generator.
+fn drop_overlapping_plans(
+    mut plans: Vec<ConfiguredBlockPlan>,
+) -> (Vec<ConfiguredBlockPlan>, Vec<DroppedPlan>) {
+    fn firmness_rank(firmness: &Firmness) -> u8 {
+        match firmness {
+            Firmness::Draft => 0,
+            Firmness::Soft => 1,
+            Firmness::Hard => 2,
+        }
+    }
+    plans.sort_by(|left, right| {
+        firmness_rank(&right.firmness)
+            .cmp(&firmness_rank(&left.firmness))
+            .then_with(|| (left.source != "template").cmp(&(right.source != "template")))
+            .then_with(|| left.start_at.cmp(&right.start_at))
+            .then_with(|| left.instance.cmp(&right.instance))
+    });
+
+    let mut kept: Vec<ConfiguredBlockPlan> = Vec::with_capacity(plans.len());
+    let mut dropped = Vec::new();
+    for plan in plans {
+        let conflict = kept.iter().find(|existing| {
+            existing.source != plan.source
+                && existing.start_at < plan.end_at
+                && plan.start_at < existing.end_at
+        });
+        if let Some(existing) = conflict {
+            dropped.push(DroppedPlan {
+                instance: plan.instance,
+                overlapped_instance: existing.instance.clone(),
+            });
+            continue;
+        }
+        kept.push(plan);
+    }
+    (kept, dropped)
+}
+
+fn parse_project_value(value: Option<&serde_json::Value>) -> Option<String> {
+    value
+        .and_then(serde_json::Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(ToOwned::to_owned)
+}
+
 fn template_applies_on_date(template: &TemplateDefinition, date: NaiveDate) -> bool {
     match &template.days {
         Some(days) => days.contains(&date.weekday()),
@@ -391,7 +621,7 @@ fn parse_rrule_weekday_token(token: &str) -> Option<(Option<i32>, Weekday)> {
     Some((ordinal, weekday))
 }
 
-fn rrule_matches_date(rrule: &str, date: NaiveDate) -> bool {
+pub(crate) fn rrule_matches_date(rrule: &str, date: NaiveDate) -> bool {
     let parts = parse_rrule(rrule);
     let Some(freq) = parts.get("FREQ").map(String::as_str) else {
         return false;
@@ -432,6 +662,44 @@ fn rrule_matches_date(rrule: &str, date: NaiveDate) -> bool {
     true
 }
 
+pub(crate) fn rrule_is_supported(rrule: &str) -> bool {
+    let parts = parse_rrule(rrule);
+    let freq = parts.get("FREQ").map(String::as_str);
+    if !matches!(freq, Some("DAILY" | "WEEKLY" | "MONTHLY")) {
+        return false;
+    }
+    if parts.keys().any(|key| !matches!(key.as_str(), "FREQ" | "BYDAY" | "BYMONTHDAY" | "WKST")) {
+        return false;
+    }
+    let by_day_valid = parts.get("BYDAY").is_none_or(|by_day| {
+        by_day
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .all(|value| parse_rrule_weekday_token(value).is_some())
+    });
+    let by_month_day_valid = parts.get("BYMONTHDAY").is_none_or(|by_month_day| {
+        by_month_day
+            .split(',')
+            .map(str::trim)
+            .all(|value| value.parse::<i32>().is_ok_and(|day| (1..=31).contains(&day)))
+    });
+    by_day_valid && by_month_day_valid
+}
+
+pub(crate) fn upcoming_rrule_dates(
+    rrule: &str,
+    from: NaiveDate,
+    days: usize,
+    limit: usize,
+) -> Vec<NaiveDate> {
+    from.iter_days()
+        .take(days)
+        .filter(|date| rrule_matches_date(rrule, *date))
+        .take(limit)
+        .collect()
+}
+
 fn routine_in_date_range(routine: &serde_json::Map<String, serde_json::Value>, date: NaiveDate) -> bool {
     let schedule = value_by_keys(routine, &["schedule"]).and_then(serde_json::Value::as_object);
     let start_date = value_by_keys(routine, &["startDate", "start_date"])
@@ -631,6 +899,7 @@ fn local_datetime_to_utc(
 mod tests {
     use super::*;
     use crate::application::test_support::config_fs::TempConfigDir;
+    use crate::application::policy_service::{BlockColorBy, BlockDeleteBehavior};
     use crate::domain::models::AutoDriveMode;
     use chrono::NaiveTime;
 
@@ -641,13 +910,37 @@ mod tests {
             work_days: HashSet::from([Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]),
             timezone: chrono_tz::Tz::UTC,
             auto_enabled: true,
+            auto_time: NaiveTime::from_hms_opt(5, 30, 0).expect("time"),
             catch_up_on_app_start: true,
+            catch_up_max_days: 7,
             block_duration_minutes: 60,
             break_duration_minutes: 5,
             min_block_gap_minutes: 0,
+            min_auto_block_minutes: 60,
             max_auto_blocks_per_day: 24,
+            max_daily_focus_minutes: None,
             max_relocations_per_sync: 50,
+            relocation_grace_minutes: 30,
+            sync_max_events: None,
+            quiet_hours: None,
+            idle_timeout_minutes: None,
+            break_reminder_seconds: None,
+            setup_seconds: None,
+            allow_out_of_date_start: false,
+            on_block_end: None,
+            on_block_delete: BlockDeleteBehavior::Unassign,
+            reminder_minutes: None,
+            color_by: BlockColorBy::Type,
+            load_color_ramp: Vec::new(),
+            ignore_declined_events: true,
+            ignore_tentative_events: false,
             respect_suppression: true,
+            insert_break_blocks: false,
+            skip_past: true,
+            auto_approve: false,
+            round_start_to_minutes: None,
+            auto_start_break: true,
+            auto_start_focus: true,
         }
     }
 
@@ -733,12 +1026,13 @@ mod tests {
         )
         .expect("write routines");
 
-        let plans = load_configured_block_plans(
+        let plans = load_configured_plans(
             config_dir.path(),
             NaiveDate::from_ymd_opt(2026, 2, 16).expect("date"),
             &sample_policy(),
             &sample_recipes(),
-        );
+        )
+        .pinned;
 
         assert_eq!(plans.len(), 2);
         assert_eq!(plans[0].source, "template");
@@ -747,6 +1041,54 @@ mod tests {
         assert_eq!(plans[1].source_id.as_deref(), Some("rtn-daily"));
     }
 
+    #[test]
+    fn overlapping_routine_plan_is_dropped_in_favor_of_the_firmer_template() {
+        let config_dir = TempConfigDir::new("plans", "overlap");
+        fs::write(
+            config_dir.join("templates.json"),
+            r#"{
+  "schema": 1,
+  "templates": [
+    { "id": "tpl-deep", "start": "09:00", "durationMinutes": 90, "firmness": "hard" }
+  ]
+}
+"#,
+        )
+        .expect("write templates");
+        fs::write(
+            config_dir.join("routines.json"),
+            r#"{
+  "schema": 1,
+  "routines": [
+    {
+      "id": "rtn-standup",
+      "rrule": "FREQ=DAILY",
+      "default": { "start": "10:00", "durationMinutes": 30 }
+    }
+  ]
+}
+"#,
+        )
+        .expect("write routines");
+
+        let configured = load_configured_plans(
+            config_dir.path(),
+            NaiveDate::from_ymd_opt(2026, 2, 16).expect("date"),
+            &sample_policy(),
+            &sample_recipes(),
+        );
+
+        assert_eq!(configured.pinned.len(), 1);
+        assert_eq!(configured.pinned[0].source_id.as_deref(), Some("tpl-deep"));
+        assert_eq!(
+            configured.dropped,
+            vec![DroppedPlan {
+                instance: "rtn:rtn-standup:2026-02-16".to_string(),
+                overlapped_instance: "tpl:tpl-deep:2026-02-16".to_string(),
+            }]
+        );
+    }
+
     #[test]
     fn load_configured_block_plans_honors_date_ranges_and_nth_weekdays() {
         let config_dir = TempConfigDir::new("plans", "recurrence");
@@ -802,23 +1144,83 @@ mod tests {
         )
         .expect("write routines");
 
-        let matching_plans = load_configured_block_plans(
+        let matching_plans = load_configured_plans(
             config_dir.path(),
             NaiveDate::from_ymd_opt(2026, 2, 10).expect("date"),
             &sample_policy(),
             &sample_recipes(),
-        );
+        )
+        .pinned;
         assert_eq!(matching_plans.len(), 3);
         assert!(matching_plans
             .iter()
             .all(|plan| plan.source == "routine" && plan.recipe_id == "rcp-default"));
 
-        let outside_plans = load_configured_block_plans(
+        let outside_plans = load_configured_plans(
             config_dir.path(),
             NaiveDate::from_ymd_opt(2026, 2, 3).expect("date"),
             &sample_policy(),
             &sample_recipes(),
-        );
+        )
+        .pinned;
         assert!(outside_plans.is_empty());
     }
+
+    #[test]
+    fn duplicate_template_ids_are_reported_and_the_first_wins() {
+        let config_dir = TempConfigDir::new("plans", "duplicates");
+        fs::write(
+            config_dir.join("templates.json"),
+            r#"{
+  "schema": 1,
+  "templates": [
+    { "id": "tpl-dup", "start": "09:00", "durationMinutes": 30 },
+    { "id": "tpl-dup", "start": "14:00", "durationMinutes": 90 }
+  ]
+}
+"#,
+        )
+        .expect("write templates");
+
+        let issues = lint_configured_block_plans(config_dir.path());
+        assert_eq!(
+            issues,
+            vec![ConfigIssue {
+                file: "templates.json".to_string(),
+                id: Some("tpl-dup".to_string()),
+                problem: "duplicate id".to_string(),
+            }]
+        );
+
+        let configured = load_configured_plans(
+            config_dir.path(),
+            NaiveDate::from_ymd_opt(2026, 2, 16).expect("date"),
+            &sample_policy(),
+            &sample_recipes(),
+        );
+        assert_eq!(configured.issues, issues);
+        let plans = configured.pinned;
+        assert_eq!(plans.len(), 1);
+        assert_eq!(plans[0].start_at.to_rfc3339(), "2026-02-16T09:00:00+00:00");
+        assert_eq!((plans[0].end_at - plans[0].start_at).num_minutes(), 30);
+    }
+
+    #[test]
+    fn weekly_rrule_previews_upcoming_matches() {
+        let rrule = "FREQ=WEEKLY;BYDAY=MO,WE";
+        let from = NaiveDate::from_ymd_opt(2026, 2, 16).expect("date");
+
+        assert!(rrule_is_supported(rrule));
+        let dates = upcoming_rrule_dates(rrule, from, 31, 4)
+            .into_iter()
+            .map(|date| date.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(dates, vec!["2026-02-16", "2026-02-18", "2026-02-23", "2026-02-25"]);
+    }
+
+    #[test]
+    fn yearly_rrule_is_reported_unsupported() {
+        assert!(!rrule_is_supported("FREQ=YEARLY;BYMONTH=3;BYMONTHDAY=1"));
+        assert!(!rrule_is_supported("FREQ=WEEKLY;BYDAY=XX"));
+    }
 }
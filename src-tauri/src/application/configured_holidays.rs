@@ -0,0 +1,89 @@
+use crate::application::time_slots::parse_date_input;
+use crate::infrastructure::error::InfraError;
+use chrono::NaiveDate;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+const HOLIDAYS_FILE_NAME: &str = "holidays.json";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfiguredHolidays {
+    pub dates: BTreeSet<NaiveDate>,
+    /// Entries that are not YYYY-MM-DD strings, as written in the file.
+    pub invalid_entries: Vec<String>,
+}
+
+/// Holidays from holidays.json; a missing file means no holidays, while a file that is not a
+/// JSON array is rejected.
+pub fn load_holidays(config_dir: &Path) -> Result<ConfiguredHolidays, InfraError> {
+    let path = config_dir.join(HOLIDAYS_FILE_NAME);
+    if !path.exists() {
+        return Ok(ConfiguredHolidays::default());
+    }
+    let raw = fs::read_to_string(path)?;
+    let parsed = serde_json::from_str::<serde_json::Value>(&raw)
+        .map_err(|error| malformed_holidays(error.to_string()))?;
+    let Some(values) = parsed.as_array() else {
+        return Err(malformed_holidays("expected an array of dates".to_string()));
+    };
+    let mut holidays = ConfiguredHolidays::default();
+    for value in values {
+        match value
+            .as_str()
+            .and_then(|value| NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").ok())
+        {
+            Some(date) => {
+                holidays.dates.insert(date);
+            }
+            None => holidays.invalid_entries.push(value.to_string()),
+        }
+    }
+    Ok(holidays)
+}
+
+/// Holiday dates to rewrite the file from; refuses when rewriting would drop invalid entries.
+fn load_rewritable_holidays(config_dir: &Path) -> Result<BTreeSet<NaiveDate>, InfraError> {
+    let holidays = load_holidays(config_dir)?;
+    if !holidays.invalid_entries.is_empty() {
+        return Err(malformed_holidays(format!(
+            "invalid dates {}",
+            holidays.invalid_entries.join(", ")
+        )));
+    }
+    Ok(holidays.dates)
+}
+
+fn malformed_holidays(detail: String) -> InfraError {
+    InfraError::InvalidConfig(format!("{HOLIDAYS_FILE_NAME} is malformed: {detail}"))
+}
+
+pub fn add_holiday(config_dir: &Path, date: &str) -> Result<BTreeSet<NaiveDate>, InfraError> {
+    let date = parse_date_input(date, "date")?;
+    let mut holidays = load_rewritable_holidays(config_dir)?;
+    if holidays.insert(date) {
+        write_holidays(config_dir, &holidays)?;
+    }
+    Ok(holidays)
+}
+
+pub fn remove_holiday(config_dir: &Path, date: &str) -> Result<bool, InfraError> {
+    let date = parse_date_input(date, "date")?;
+    let mut holidays = load_rewritable_holidays(config_dir)?;
+    let removed = holidays.remove(&date);
+    if removed {
+        write_holidays(config_dir, &holidays)?;
+    }
+    Ok(removed)
+}
+
+fn write_holidays(config_dir: &Path, holidays: &BTreeSet<NaiveDate>) -> Result<(), InfraError> {
+    let path = config_dir.join(HOLIDAYS_FILE_NAME);
+    let dates = holidays
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    let formatted = serde_json::to_string_pretty(&dates)?;
+    fs::write(path, format!("{formatted}\n"))?;
+    Ok(())
+}
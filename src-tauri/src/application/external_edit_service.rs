@@ -1,6 +1,7 @@
 use crate::infrastructure::calendar_cache::CalendarCacheRepository;
 use crate::infrastructure::error::InfraError;
-use crate::infrastructure::event_mapper::GoogleCalendarEvent;
+use crate::infrastructure::event_mapper::{CalendarEventDateTime, GoogleCalendarEvent};
+use chrono::DateTime;
 use std::sync::Arc;
 
 #[derive(Debug, Clone)]
@@ -57,20 +58,37 @@ where
                 if existing.is_some() {
                     self.cache_repository.remove(&event_id)?;
                     deleted.push(event_id);
+                } else if let Some(occurrence_id) = self.find_cancelled_occurrence(&event)? {
+                    self.cache_repository.remove(&occurrence_id)?;
+                    deleted.push(occurrence_id);
                 }
                 continue;
             }
 
-            match existing {
-                None => {
+            let managed_instance = extract_managed_instance(&event);
+            if let Some(instance) = managed_instance.as_deref() {
+                suppressed_instances.retain(|suppressed| suppressed != instance);
+            }
+            let recreated_from = match (&existing, managed_instance.as_deref()) {
+                (None, Some(instance)) => self.find_by_instance(instance, &event_id)?,
+                _ => None,
+            };
+
+            match (existing, recreated_from) {
+                (None, Some(stale_id)) => {
+                    self.cache_repository.remove(&stale_id)?;
+                    self.cache_repository.upsert(&event)?;
+                    updated.push(event);
+                }
+                (None, None) => {
                     self.cache_repository.upsert(&event)?;
                     added.push(event);
                 }
-                Some(cached) if cached != event => {
+                (Some(cached), _) if cached != event => {
                     self.cache_repository.upsert(&event)?;
                     updated.push(event);
                 }
-                Some(_) => {}
+                (Some(_), _) => {}
             }
         }
 
@@ -81,6 +99,60 @@ where
             suppressed_instances,
         })
     }
+
+    fn find_by_instance(
+        &self,
+        instance: &str,
+        event_id: &str,
+    ) -> Result<Option<String>, InfraError> {
+        Ok(self.cache_repository.list()?.into_iter().find_map(|cached| {
+            let cached_id = cached.id.as_deref().map(str::trim)?;
+            if cached_id == event_id
+                || extract_managed_instance(&cached).as_deref() != Some(instance)
+            {
+                return None;
+            }
+            Some(cached_id.to_string())
+        }))
+    }
+
+    fn find_cancelled_occurrence(
+        &self,
+        cancelled: &GoogleCalendarEvent,
+    ) -> Result<Option<String>, InfraError> {
+        let Some(recurring_event_id) = cancelled
+            .recurring_event_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        else {
+            return Ok(None);
+        };
+        let Some(original_start) = cancelled.original_start_time.as_ref() else {
+            return Ok(None);
+        };
+
+        Ok(self.cache_repository.list()?.into_iter().find_map(|cached| {
+            if cached.recurring_event_id.as_deref().map(str::trim) != Some(recurring_event_id) {
+                return None;
+            }
+            let cached_start = cached.original_start_time.as_ref().unwrap_or(&cached.start);
+            if !same_instant(cached_start, original_start) {
+                return None;
+            }
+            cached.id
+        }))
+    }
+}
+
+fn same_instant(left: &CalendarEventDateTime, right: &CalendarEventDateTime) -> bool {
+    match (
+        DateTime::parse_from_rfc3339(&left.date_time),
+        DateTime::parse_from_rfc3339(&right.date_time),
+    ) {
+        (Ok(left), Ok(right)) => left == right,
+        _ => left.date_time.trim() == right.date_time.trim(),
+    }
 }
 
 fn extract_managed_instance(event: &GoogleCalendarEvent) -> Option<String> {
@@ -119,6 +191,7 @@ mod tests {
                 time_zone: None,
             },
             extended_properties: None,
+            ..Default::default()
         }
     }
 
@@ -217,4 +290,87 @@ mod tests {
         assert_eq!(cache.get_by_id("evt-add").expect("cache read add"), Some(added));
         assert!(cache.get_by_id("evt-delete").expect("cache read delete").is_none());
     }
+
+    #[test]
+    fn cancelled_recurring_instance_removes_matching_occurrence() {
+        let cache = Arc::new(InMemoryCalendarCacheRepository::default());
+        let mut first = sample_event(
+            "standup_20260216T100000Z",
+            "standup",
+            "2026-02-16T10:00:00Z",
+            "2026-02-16T10:30:00Z",
+        );
+        first.recurring_event_id = Some("standup".to_string());
+        let mut second = sample_event(
+            "standup_20260217T100000Z",
+            "standup",
+            "2026-02-17T10:00:00Z",
+            "2026-02-17T10:30:00Z",
+        );
+        second.recurring_event_id = Some("standup".to_string());
+        cache.upsert(&first).expect("seed first occurrence");
+        cache.upsert(&second).expect("seed second occurrence");
+
+        let mut cancelled = sample_event("standup_exception", "standup", "", "");
+        cancelled.status = Some("cancelled".to_string());
+        cancelled.recurring_event_id = Some("standup".to_string());
+        cancelled.original_start_time = Some(CalendarEventDateTime {
+            date_time: "2026-02-16T19:00:00+09:00".to_string(),
+            time_zone: Some("Asia/Tokyo".to_string()),
+        });
+
+        let service = ExternalEditService::new(Arc::clone(&cache));
+        let result = service.apply_events(vec![cancelled]).expect("apply cancellation");
+
+        assert_eq!(result.deleted, vec!["standup_20260216T100000Z".to_string()]);
+        assert!(cache
+            .get_by_id("standup_20260216T100000Z")
+            .expect("cache read first")
+            .is_none());
+        assert_eq!(
+            cache.get_by_id("standup_20260217T100000Z").expect("cache read second"),
+            Some(second)
+        );
+    }
+
+    #[test]
+    fn recreated_managed_event_replaces_cached_entry_for_the_same_instance() {
+        let instance = "rtn:auto:2026-02-16:0";
+        let cache = Arc::new(InMemoryCalendarCacheRepository::default());
+        let mut original = sample_event(
+            "evt-original",
+            "[PomoBlock] Work Block",
+            "2026-02-16T09:00:00Z",
+            "2026-02-16T10:00:00Z",
+        );
+        original.extended_properties = sample_cancelled_managed_event("evt-original", instance)
+            .extended_properties;
+        cache.upsert(&original).expect("seed original event");
+
+        let mut recreated = original.clone();
+        recreated.id = Some("evt-recreated".to_string());
+        recreated.etag = Some("etag-recreated".to_string());
+
+        let service = ExternalEditService::new(Arc::clone(&cache));
+        let result = service
+            .apply_events(vec![
+                sample_cancelled_managed_event("evt-original", instance),
+                recreated.clone(),
+            ])
+            .expect("apply recreation");
+
+        assert!(result.suppressed_instances.is_empty());
+        let cached = cache.list().expect("cache list");
+        assert_eq!(cached, vec![recreated.clone()]);
+
+        let mut recreated_again = recreated.clone();
+        recreated_again.id = Some("evt-recreated-again".to_string());
+        let result = service
+            .apply_events(vec![recreated_again.clone()])
+            .expect("apply recreation without cancellation");
+
+        assert!(result.added.is_empty());
+        assert_eq!(result.updated, vec![recreated_again.clone()]);
+        assert_eq!(cache.list().expect("cache list"), vec![recreated_again]);
+    }
 }
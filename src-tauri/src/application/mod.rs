@@ -1,5 +1,7 @@
 pub mod audit_log;
+pub mod auto_generation;
 pub mod bootstrap;
+pub(crate) mod calendar_reauth;
 pub mod calendar_runtime;
 pub(crate) mod calendar_services;
 pub mod calendar_window;
@@ -10,7 +12,9 @@ pub mod block_generation;
 pub mod block_operations;
 pub mod block_service;
 pub mod block_calendar_events;
+pub mod block_export;
 pub mod configured_block_plans;
+pub mod configured_holidays;
 pub mod configured_modules;
 pub mod configured_recipes;
 pub mod configured_routines;
@@ -20,7 +24,9 @@ pub mod oauth;
 pub mod policy_service;
 pub mod pomodoro_log_store;
 pub mod pomodoro_session_plan;
+pub mod pomodoro_session_store;
 pub mod pomodoro_service;
+pub mod recurring_tasks;
 pub mod reflection_service;
 pub mod studio_template_application;
 pub mod task_service;
@@ -117,7 +117,8 @@ where
             })
             .await?;
 
-        let token = self.token_from_response(response, None);
+        let mut token = self.token_from_response(response, None);
+        token.authenticated_at = Some((self.now_provider)());
         self.credential_store.save_token(&token)?;
         Ok(token)
     }
@@ -130,7 +131,21 @@ where
         if self.is_token_valid(&stored_token) {
             return Ok(EnsureTokenResult::Existing(stored_token));
         }
+        self.refresh_stored_token(stored_token).await
+    }
 
+    /// Refreshes even when the stored token still looks valid, e.g. after Google rejected it.
+    pub async fn force_refresh(&self) -> Result<EnsureTokenResult, InfraError> {
+        let Some(stored_token) = self.credential_store.load_token()? else {
+            return Ok(EnsureTokenResult::ReauthenticationRequired);
+        };
+        self.refresh_stored_token(stored_token).await
+    }
+
+    async fn refresh_stored_token(
+        &self,
+        stored_token: OAuthToken,
+    ) -> Result<EnsureTokenResult, InfraError> {
         if let Some(refresh_token) = stored_token.refresh_token.clone() {
             let refreshed = self
                 .oauth_client
@@ -144,8 +159,9 @@ where
 
             match refreshed {
                 Ok(response) => {
-                    let token =
+                    let mut token =
                         self.token_from_response(response, stored_token.refresh_token.clone());
+                    token.authenticated_at = stored_token.authenticated_at;
                     self.credential_store.save_token(&token)?;
                     Ok(EnsureTokenResult::Refreshed(token))
                 }
@@ -168,6 +184,7 @@ where
             expires_at,
             token_type: response.token_type.unwrap_or_else(|| "Bearer".to_string()),
             scope: response.scope,
+            authenticated_at: None,
         }
     }
 }
@@ -285,6 +302,7 @@ mod tests {
                     expires_at: Utc::now() + Duration::seconds(expires_in_seconds),
                     token_type,
                     scope,
+                    authenticated_at: None,
                 },
             )
     }
@@ -340,6 +358,7 @@ mod tests {
                     expires_at: Utc::now() - Duration::seconds(expired_seconds_ago),
                     token_type,
                     scope,
+                    authenticated_at: None,
                 };
 
                 let store = Arc::new(InMemoryCredentialStore::default());
@@ -370,6 +389,7 @@ mod tests {
             expires_at: Utc::now() - Duration::seconds(120),
             token_type: "Bearer".to_string(),
             scope: Some("scope-a".to_string()),
+            authenticated_at: None,
         };
         store.save_token(&expired).expect("save token");
 
@@ -1,5 +1,6 @@
+use crate::application::time_slots::local_datetime_to_utc;
 use crate::infrastructure::config::read_timezone;
-use chrono::{NaiveTime, Weekday};
+use chrono::{DateTime, Datelike, Days, NaiveTime, Utc, Weekday};
 use chrono_tz::Tz;
 use std::collections::HashSet;
 use std::fs;
@@ -7,6 +8,62 @@ use std::path::Path;
 
 const DEFAULT_MAX_AUTO_BLOCKS_PER_DAY: u32 = 24;
 const DEFAULT_MAX_RELOCATIONS_PER_SYNC: u32 = 50;
+const DEFAULT_RELOCATION_GRACE_MINUTES: u32 = 30;
+const DEFAULT_CATCH_UP_MAX_DAYS: u32 = 7;
+// Google Calendar color ids from light to intense: sage, banana, tangerine, tomato.
+const DEFAULT_LOAD_COLOR_RAMP: &[&str] = &["2", "5", "6", "11"];
+const DEPRECATED_TIMEZONE_ALIASES: &[(&str, &str)] = &[
+    ("Asia/Calcutta", "Asia/Kolkata"),
+    ("Asia/Katmandu", "Asia/Kathmandu"),
+    ("Asia/Rangoon", "Asia/Yangon"),
+    ("Asia/Saigon", "Asia/Ho_Chi_Minh"),
+    ("Europe/Kiev", "Europe/Kyiv"),
+    ("GB", "Europe/London"),
+    ("Japan", "Asia/Tokyo"),
+    ("PRC", "Asia/Shanghai"),
+    ("ROK", "Asia/Seoul"),
+    ("Singapore", "Asia/Singapore"),
+    ("US/Central", "America/Chicago"),
+    ("US/Eastern", "America/New_York"),
+    ("US/Mountain", "America/Denver"),
+    ("US/Pacific", "America/Los_Angeles"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl QuietHours {
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockEndBehavior {
+    Stop,
+    Continue,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlockDeleteBehavior {
+    #[default]
+    Unassign,
+    CarryOver,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlockColorBy {
+    #[default]
+    Type,
+    Load,
+}
 
 #[derive(Debug, Clone)]
 pub struct RuntimePolicy {
@@ -15,13 +72,37 @@ pub struct RuntimePolicy {
     pub work_days: HashSet<Weekday>,
     pub timezone: Tz,
     pub auto_enabled: bool,
+    pub auto_time: NaiveTime,
     pub catch_up_on_app_start: bool,
+    pub catch_up_max_days: u32,
     pub block_duration_minutes: u32,
     pub break_duration_minutes: u32,
     pub min_block_gap_minutes: u32,
+    pub min_auto_block_minutes: u32,
     pub max_auto_blocks_per_day: u32,
+    pub max_daily_focus_minutes: Option<u32>,
     pub max_relocations_per_sync: u32,
+    pub relocation_grace_minutes: u32,
+    pub sync_max_events: Option<usize>,
+    pub quiet_hours: Option<QuietHours>,
+    pub idle_timeout_minutes: Option<u32>,
+    pub break_reminder_seconds: Option<u32>,
+    pub setup_seconds: Option<u32>,
+    pub allow_out_of_date_start: bool,
+    pub on_block_end: Option<BlockEndBehavior>,
+    pub on_block_delete: BlockDeleteBehavior,
+    pub reminder_minutes: Option<u32>,
+    pub color_by: BlockColorBy,
+    pub load_color_ramp: Vec<String>,
+    pub ignore_declined_events: bool,
+    pub ignore_tentative_events: bool,
     pub respect_suppression: bool,
+    pub skip_past: bool,
+    pub auto_approve: bool,
+    pub round_start_to_minutes: Option<u32>,
+    pub insert_break_blocks: bool,
+    pub auto_start_break: bool,
+    pub auto_start_focus: bool,
 }
 
 impl Default for RuntimePolicy {
@@ -38,23 +119,50 @@ impl Default for RuntimePolicy {
             ]),
             timezone: Tz::UTC,
             auto_enabled: true,
+            auto_time: NaiveTime::from_hms_opt(5, 30, 0).expect("valid fixed time"),
             catch_up_on_app_start: true,
+            catch_up_max_days: DEFAULT_CATCH_UP_MAX_DAYS,
             block_duration_minutes: 60,
             break_duration_minutes: 5,
             min_block_gap_minutes: 0,
+            min_auto_block_minutes: 60,
             max_auto_blocks_per_day: DEFAULT_MAX_AUTO_BLOCKS_PER_DAY,
+            max_daily_focus_minutes: None,
             max_relocations_per_sync: DEFAULT_MAX_RELOCATIONS_PER_SYNC,
+            relocation_grace_minutes: DEFAULT_RELOCATION_GRACE_MINUTES,
+            sync_max_events: None,
+            quiet_hours: None,
+            idle_timeout_minutes: None,
+            break_reminder_seconds: None,
+            setup_seconds: None,
+            allow_out_of_date_start: false,
+            on_block_end: None,
+            on_block_delete: BlockDeleteBehavior::Unassign,
+            reminder_minutes: None,
+            color_by: BlockColorBy::Type,
+            load_color_ramp: DEFAULT_LOAD_COLOR_RAMP.iter().map(|id| id.to_string()).collect(),
+            ignore_declined_events: true,
+            ignore_tentative_events: false,
             respect_suppression: true,
+            skip_past: true,
+            auto_approve: false,
+            round_start_to_minutes: None,
+            insert_break_blocks: false,
+            auto_start_break: true,
+            auto_start_focus: true,
         }
     }
 }
 
 pub fn load_runtime_policy(config_dir: &Path) -> RuntimePolicy {
     let mut policy = RuntimePolicy::default();
-    if let Ok(Some(timezone)) = read_timezone(config_dir) {
-        if let Ok(parsed_timezone) = timezone.parse::<Tz>() {
-            policy.timezone = parsed_timezone;
-        }
+    if let Some(parsed_timezone) = read_timezone(config_dir)
+        .ok()
+        .flatten()
+        .and_then(|timezone| canonical_timezone_name(&timezone))
+        .and_then(|name| name.parse::<Tz>().ok())
+    {
+        policy.timezone = parsed_timezone;
     }
     let path = config_dir.join("policies.json");
     let Ok(raw) = fs::read_to_string(path) else {
@@ -87,6 +195,21 @@ pub fn load_runtime_policy(config_dir: &Path) -> RuntimePolicy {
         }
     }
 
+    if let Some(quiet_hours) = parsed.get("quietHours") {
+        let start = quiet_hours
+            .get("start")
+            .and_then(serde_json::Value::as_str)
+            .and_then(|value| NaiveTime::parse_from_str(value.trim(), "%H:%M").ok());
+        let end = quiet_hours
+            .get("end")
+            .and_then(serde_json::Value::as_str)
+            .and_then(|value| NaiveTime::parse_from_str(value.trim(), "%H:%M").ok());
+        policy.quiet_hours = start
+            .zip(end)
+            .filter(|(start, end)| start != end)
+            .map(|(start, end)| QuietHours { start, end });
+    }
+
     if let Some(value) = parsed
         .get("blockDurationMinutes")
         .and_then(serde_json::Value::as_u64)
@@ -105,6 +228,64 @@ pub fn load_runtime_policy(config_dir: &Path) -> RuntimePolicy {
     {
         policy.min_block_gap_minutes = value as u32;
     }
+    if let Some(value) = parsed
+        .get("autoStartBreak")
+        .and_then(serde_json::Value::as_bool)
+    {
+        policy.auto_start_break = value;
+    }
+    if let Some(value) = parsed
+        .get("autoStartFocus")
+        .and_then(serde_json::Value::as_bool)
+    {
+        policy.auto_start_focus = value;
+    }
+    if let Some(value) = parsed
+        .get("idleTimeoutMinutes")
+        .and_then(serde_json::Value::as_u64)
+    {
+        policy.idle_timeout_minutes = (value > 0).then_some(value as u32);
+    }
+    if let Some(value) = parsed
+        .get("pomodoro")
+        .and_then(|pomodoro| pomodoro.get("breakReminderSeconds"))
+        .and_then(serde_json::Value::as_u64)
+    {
+        policy.break_reminder_seconds = (value > 0).then_some(value as u32);
+    }
+    if let Some(value) = parsed
+        .get("pomodoro")
+        .and_then(|pomodoro| pomodoro.get("setupSeconds"))
+        .and_then(serde_json::Value::as_u64)
+    {
+        policy.setup_seconds = (value > 0).then_some(value as u32);
+    }
+    if let Some(value) = parsed
+        .get("pomodoro")
+        .and_then(|pomodoro| pomodoro.get("allowOutOfDateStart"))
+        .and_then(serde_json::Value::as_bool)
+    {
+        policy.allow_out_of_date_start = value;
+    }
+    if let Some(value) = parsed.get("onBlockEnd").and_then(serde_json::Value::as_str) {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "stop" => policy.on_block_end = Some(BlockEndBehavior::Stop),
+            "continue" => policy.on_block_end = Some(BlockEndBehavior::Continue),
+            _ => {}
+        }
+    }
+    if let Some(value) = parsed.get("onBlockDelete").and_then(serde_json::Value::as_str) {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "unassign" => policy.on_block_delete = BlockDeleteBehavior::Unassign,
+            "carry_over" => policy.on_block_delete = BlockDeleteBehavior::CarryOver,
+            _ => {}
+        }
+    }
+    policy.min_auto_block_minutes = parsed
+        .get("minAutoBlockMinutes")
+        .and_then(serde_json::Value::as_u64)
+        .map(|value| (value.max(1) as u32).min(policy.block_duration_minutes))
+        .unwrap_or(policy.block_duration_minutes);
     if let Some(value) = parsed
         .get("generation")
         .and_then(|generation| generation.get("respectSuppression"))
@@ -112,6 +293,34 @@ pub fn load_runtime_policy(config_dir: &Path) -> RuntimePolicy {
     {
         policy.respect_suppression = value;
     }
+    if let Some(value) = parsed
+        .get("generation")
+        .and_then(|generation| generation.get("insertBreakBlocks"))
+        .and_then(serde_json::Value::as_bool)
+    {
+        policy.insert_break_blocks = value;
+    }
+    if let Some(value) = parsed
+        .get("generation")
+        .and_then(|generation| generation.get("skipPast"))
+        .and_then(serde_json::Value::as_bool)
+    {
+        policy.skip_past = value;
+    }
+    if let Some(value) = parsed
+        .get("generation")
+        .and_then(|generation| generation.get("autoApprove"))
+        .and_then(serde_json::Value::as_bool)
+    {
+        policy.auto_approve = value;
+    }
+    if let Some(value) = parsed
+        .get("generation")
+        .and_then(|generation| generation.get("roundStartToMinutes"))
+        .and_then(serde_json::Value::as_u64)
+    {
+        policy.round_start_to_minutes = (value > 0).then_some(value as u32);
+    }
     if let Some(value) = parsed
         .get("generation")
         .and_then(|generation| generation.get("autoEnabled"))
@@ -126,6 +335,14 @@ pub fn load_runtime_policy(config_dir: &Path) -> RuntimePolicy {
     {
         policy.auto_enabled = value;
     }
+    if let Some(value) = parsed
+        .get("generation")
+        .and_then(|generation| generation.get("autoTime"))
+        .and_then(serde_json::Value::as_str)
+        .and_then(|value| NaiveTime::parse_from_str(value.trim(), "%H:%M").ok())
+    {
+        policy.auto_time = value;
+    }
     if let Some(value) = parsed
         .get("generation")
         .and_then(|generation| generation.get("catchUpOnAppStart"))
@@ -140,6 +357,13 @@ pub fn load_runtime_policy(config_dir: &Path) -> RuntimePolicy {
     {
         policy.catch_up_on_app_start = value;
     }
+    if let Some(value) = parsed
+        .get("generation")
+        .and_then(|generation| generation.get("catchUpMaxDays"))
+        .and_then(serde_json::Value::as_u64)
+    {
+        policy.catch_up_max_days = value.max(1) as u32;
+    }
     if let Some(value) = parsed
         .get("generation")
         .and_then(|generation| generation.get("maxAutoBlocksPerDay"))
@@ -147,6 +371,13 @@ pub fn load_runtime_policy(config_dir: &Path) -> RuntimePolicy {
     {
         policy.max_auto_blocks_per_day = value.max(1) as u32;
     }
+    if let Some(value) = parsed
+        .get("generation")
+        .and_then(|generation| generation.get("maxDailyFocusMinutes"))
+        .and_then(serde_json::Value::as_u64)
+    {
+        policy.max_daily_focus_minutes = Some(value as u32);
+    }
     if let Some(value) = parsed
         .get("generation")
         .and_then(|generation| generation.get("maxRelocationsPerSync"))
@@ -154,10 +385,91 @@ pub fn load_runtime_policy(config_dir: &Path) -> RuntimePolicy {
     {
         policy.max_relocations_per_sync = value.max(1) as u32;
     }
+    if let Some(value) = parsed
+        .get("generation")
+        .and_then(|generation| generation.get("relocationGraceMinutes"))
+        .and_then(serde_json::Value::as_u64)
+    {
+        policy.relocation_grace_minutes = value as u32;
+    }
+    if let Some(value) = parsed
+        .get("sync")
+        .and_then(|sync| sync.get("maxEvents"))
+        .and_then(serde_json::Value::as_u64)
+    {
+        policy.sync_max_events = Some(value.max(1) as usize);
+    }
+    if let Some(value) = parsed
+        .get("calendar")
+        .and_then(|calendar| calendar.get("reminderMinutes"))
+        .and_then(serde_json::Value::as_u64)
+    {
+        policy.reminder_minutes = Some(value as u32);
+    }
+    if let Some(value) = parsed
+        .get("calendar")
+        .and_then(|calendar| calendar.get("colorBy"))
+        .and_then(serde_json::Value::as_str)
+    {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "type" => policy.color_by = BlockColorBy::Type,
+            "load" => policy.color_by = BlockColorBy::Load,
+            _ => {}
+        }
+    }
+    if let Some(ramp) = parsed
+        .get("calendar")
+        .and_then(|calendar| calendar.get("loadColorRamp"))
+        .and_then(serde_json::Value::as_array)
+    {
+        let ramp = ramp
+            .iter()
+            .filter_map(serde_json::Value::as_str)
+            .map(str::trim)
+            .filter(|color_id| !color_id.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        if !ramp.is_empty() {
+            policy.load_color_ramp = ramp;
+        }
+    }
+    if let Some(value) = parsed
+        .get("calendar")
+        .and_then(|calendar| calendar.get("ignoreDeclinedEvents"))
+        .and_then(serde_json::Value::as_bool)
+    {
+        policy.ignore_declined_events = value;
+    }
+    if let Some(value) = parsed
+        .get("calendar")
+        .and_then(|calendar| calendar.get("ignoreTentativeEvents"))
+        .and_then(serde_json::Value::as_bool)
+    {
+        policy.ignore_tentative_events = value;
+    }
 
     policy
 }
 
+/// Describes a configured timezone that `load_runtime_policy` could not use and replaced with UTC.
+pub fn invalid_timezone_warning(config_dir: &Path) -> Option<String> {
+    let timezone = read_timezone(config_dir).ok().flatten()?;
+    canonical_timezone_name(&timezone)
+        .is_none()
+        .then(|| format!("unknown timezone {timezone:?}, falling back to UTC"))
+}
+
+pub fn canonical_timezone_name(value: &str) -> Option<String> {
+    let parsed = value.trim().parse::<Tz>().ok()?;
+    let name = parsed.name();
+    let canonical = DEPRECATED_TIMEZONE_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map(|(_, canonical)| *canonical)
+        .unwrap_or(name);
+    Some(canonical.to_string())
+}
+
 pub fn parse_weekday(value: &str) -> Option<Weekday> {
     match value.trim().to_ascii_lowercase().as_str() {
         "monday" | "mon" | "mo" => Some(Weekday::Mon),
@@ -171,6 +483,27 @@ pub fn parse_weekday(value: &str) -> Option<Weekday> {
     }
 }
 
+/// Next instant strictly after `now` at which `auto_time` falls in the policy timezone.
+pub fn next_auto_generation_at(policy: &RuntimePolicy, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    if !policy.auto_enabled {
+        return None;
+    }
+    let today = now.with_timezone(&policy.timezone).date_naive();
+    // A DST gap can swallow auto_time on one day, so look a couple of days ahead.
+    (0..3)
+        .filter_map(|offset| today.checked_add_days(Days::new(offset)))
+        .filter_map(|date| local_datetime_to_utc(date, policy.auto_time, policy.timezone).ok())
+        .find(|candidate| *candidate > now)
+}
+
+/// Whether `now` falls on a work day inside the work window, read in the policy timezone.
+pub fn is_within_work_hours(policy: &RuntimePolicy, now: DateTime<Utc>) -> bool {
+    let local = now.with_timezone(&policy.timezone);
+    policy.work_days.contains(&local.weekday())
+        && policy.work_start <= local.time()
+        && local.time() < policy.work_end
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,6 +526,37 @@ mod tests {
 
     }
 
+    #[test]
+    fn work_hours_cover_the_window_on_work_days_only() {
+        let policy = RuntimePolicy {
+            timezone: chrono_tz::Asia::Tokyo,
+            ..RuntimePolicy::default()
+        };
+        let at = |value: &str| {
+            DateTime::parse_from_rfc3339(value)
+                .expect("datetime")
+                .with_timezone(&Utc)
+        };
+
+        assert!(is_within_work_hours(&policy, at("2026-02-16T10:00:00+09:00")));
+        assert!(!is_within_work_hours(&policy, at("2026-02-16T08:59:00+09:00")));
+        assert!(!is_within_work_hours(&policy, at("2026-02-16T18:00:00+09:00")));
+        assert!(!is_within_work_hours(&policy, at("2026-02-15T10:00:00+09:00")));
+    }
+
+    #[test]
+    fn quiet_hours_wrap_past_midnight() {
+        let quiet_hours = QuietHours {
+            start: NaiveTime::from_hms_opt(22, 0, 0).expect("time"),
+            end: NaiveTime::from_hms_opt(7, 0, 0).expect("time"),
+        };
+
+        assert!(quiet_hours.contains(NaiveTime::from_hms_opt(23, 30, 0).expect("time")));
+        assert!(quiet_hours.contains(NaiveTime::from_hms_opt(6, 59, 0).expect("time")));
+        assert!(!quiet_hours.contains(NaiveTime::from_hms_opt(7, 0, 0).expect("time")));
+        assert!(!quiet_hours.contains(NaiveTime::from_hms_opt(12, 0, 0).expect("time")));
+    }
+
     #[test]
     fn load_runtime_policy_reads_timezone_and_generation_limits() {
         let config_dir = TempConfigDir::new("policy", "configured");
@@ -238,7 +602,78 @@ mod tests {
         assert_eq!(policy.block_duration_minutes, 45);
         assert_eq!(policy.break_duration_minutes, 7);
         assert_eq!(policy.min_block_gap_minutes, 3);
+        assert_eq!(policy.min_auto_block_minutes, 45);
         assert_eq!(policy.max_auto_blocks_per_day, 12);
         assert_eq!(policy.max_relocations_per_sync, 8);
     }
+
+    #[test]
+    fn canonical_timezone_name_accepts_valid_zone() {
+        assert_eq!(
+            canonical_timezone_name(" Asia/Tokyo "),
+            Some("Asia/Tokyo".to_string())
+        );
+    }
+
+    #[test]
+    fn canonical_timezone_name_rejects_unknown_zone() {
+        assert_eq!(canonical_timezone_name("Asia/Tokio"), None);
+        assert_eq!(canonical_timezone_name(""), None);
+    }
+
+    #[test]
+    fn canonical_timezone_name_maps_deprecated_alias() {
+        assert_eq!(
+            canonical_timezone_name("Asia/Calcutta"),
+            Some("Asia/Kolkata".to_string())
+        );
+        assert_eq!(
+            canonical_timezone_name("US/Eastern"),
+            Some("America/New_York".to_string())
+        );
+    }
+
+    #[test]
+    fn load_runtime_policy_falls_back_to_utc_for_invalid_timezone() {
+        let config_dir = TempConfigDir::new("policy", "invalid-timezone");
+        write_json(
+            &config_dir.join("app.json"),
+            serde_json::json!({
+                "schema": 1,
+                "timezone": "Asia/Tokio"
+            }),
+        );
+
+        let policy = load_runtime_policy(config_dir.path());
+
+        assert_eq!(policy.timezone, Tz::UTC);
+        assert_eq!(
+            invalid_timezone_warning(config_dir.path()).as_deref(),
+            Some("unknown timezone \"Asia/Tokio\", falling back to UTC")
+        );
+    }
+
+    #[test]
+    fn next_auto_generation_rolls_over_to_the_following_day() {
+        let policy = RuntimePolicy {
+            timezone: chrono_tz::Asia::Tokyo,
+            ..RuntimePolicy::default()
+        };
+        // 06:00 in Tokyo on 2026-02-16.
+        let now = DateTime::parse_from_rfc3339("2026-02-15T21:00:00Z")
+            .expect("now")
+            .with_timezone(&Utc);
+
+        let next = next_auto_generation_at(&policy, now).expect("next auto generation");
+
+        assert_eq!(next.to_rfc3339(), "2026-02-16T20:30:00+00:00");
+        assert!(next_auto_generation_at(
+            &RuntimePolicy {
+                auto_enabled: false,
+                ..policy
+            },
+            now
+        )
+        .is_none());
+    }
 }
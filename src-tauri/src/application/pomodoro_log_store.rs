@@ -30,15 +30,17 @@ pub(crate) fn pomodoro_phase_as_str(value: &PomodoroPhase) -> &'static str {
 pub(crate) fn save_pomodoro_log(database_path: &Path, log: &PomodoroLog) -> Result<(), InfraError> {
     let connection = Connection::open(database_path)?;
     connection.execute(
-        "INSERT INTO pomodoro_logs (id, block_id, task_id, start_time, end_time, phase, interruption_reason)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+        "INSERT INTO pomodoro_logs
+           (id, block_id, task_id, start_time, end_time, phase, interruption_reason, resumed_from)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
          ON CONFLICT(id) DO UPDATE SET
            block_id = excluded.block_id,
            task_id = excluded.task_id,
            start_time = excluded.start_time,
            end_time = excluded.end_time,
            phase = excluded.phase,
-           interruption_reason = excluded.interruption_reason",
+           interruption_reason = excluded.interruption_reason,
+           resumed_from = excluded.resumed_from",
         params![
             log.id,
             log.block_id,
@@ -47,6 +49,7 @@ pub(crate) fn save_pomodoro_log(database_path: &Path, log: &PomodoroLog) -> Resu
             log.end_time.map(|value| value.to_rfc3339()),
             pomodoro_phase_as_str(&log.phase),
             log.interruption_reason,
+            log.resumed_from,
         ],
     )?;
     Ok(())
@@ -59,7 +62,8 @@ pub(crate) fn load_pomodoro_logs(
 ) -> Result<Vec<PomodoroLog>, InfraError> {
     let connection = Connection::open(database_path)?;
     let mut statement = connection.prepare(
-        "SELECT id, block_id, task_id, start_time, end_time, phase, interruption_reason
+        "SELECT id, block_id, task_id, start_time, end_time, phase, interruption_reason,
+                resumed_from
          FROM pomodoro_logs
          WHERE start_time >= ?1 AND start_time <= ?2
          ORDER BY start_time ASC",
@@ -67,20 +71,84 @@ pub(crate) fn load_pomodoro_logs(
     let mut rows = statement.query(params![start.to_rfc3339(), end.to_rfc3339()])?;
     let mut logs = Vec::new();
     while let Some(row) = rows.next()? {
-        let start_time = parse_datetime_input(&row.get::<_, String>(3)?, "pomodoro_logs.start_time")?;
-        let end_time = row
-            .get::<_, Option<String>>(4)?
-            .map(|value| parse_datetime_input(&value, "pomodoro_logs.end_time"))
-            .transpose()?;
-        logs.push(PomodoroLog {
-            id: row.get(0)?,
-            block_id: row.get(1)?,
-            task_id: row.get(2)?,
-            start_time,
-            end_time,
-            phase: parse_pomodoro_phase(&row.get::<_, String>(5)?)?,
-            interruption_reason: row.get(6)?,
-        });
+        logs.push(read_pomodoro_log(row)?);
     }
     Ok(logs)
 }
+
+pub(crate) fn load_pomodoro_logs_for_block(
+    database_path: &Path,
+    block_id: &str,
+) -> Result<Vec<PomodoroLog>, InfraError> {
+    let connection = Connection::open(database_path)?;
+    let mut statement = connection.prepare(
+        "SELECT id, block_id, task_id, start_time, end_time, phase, interruption_reason,
+                resumed_from
+         FROM pomodoro_logs
+         WHERE block_id = ?1
+         ORDER BY start_time ASC",
+    )?;
+    let mut rows = statement.query(params![block_id])?;
+    let mut logs = Vec::new();
+    while let Some(row) = rows.next()? {
+        logs.push(read_pomodoro_log(row)?);
+    }
+    Ok(logs)
+}
+
+pub(crate) fn load_task_focus_logs(database_path: &Path) -> Result<Vec<PomodoroLog>, InfraError> {
+    let connection = Connection::open(database_path)?;
+    let mut statement = connection.prepare(
+        "SELECT id, block_id, task_id, start_time, end_time, phase, interruption_reason,
+                resumed_from
+         FROM pomodoro_logs
+         WHERE phase = 'focus' AND task_id IS NOT NULL
+         ORDER BY start_time ASC",
+    )?;
+    let mut rows = statement.query([])?;
+    let mut logs = Vec::new();
+    while let Some(row) = rows.next()? {
+        logs.push(read_pomodoro_log(row)?);
+    }
+    Ok(logs)
+}
+
+fn read_pomodoro_log(row: &rusqlite::Row<'_>) -> Result<PomodoroLog, InfraError> {
+    let start_time = parse_datetime_input(&row.get::<_, String>(3)?, "pomodoro_logs.start_time")?;
+    let end_time = row
+        .get::<_, Option<String>>(4)?
+        .map(|value| parse_datetime_input(&value, "pomodoro_logs.end_time"))
+        .transpose()?;
+    Ok(PomodoroLog {
+        id: row.get(0)?,
+        block_id: row.get(1)?,
+        task_id: row.get(2)?,
+        start_time,
+        end_time,
+        phase: parse_pomodoro_phase(&row.get::<_, String>(5)?)?,
+        interruption_reason: row.get(6)?,
+        resumed_from: row.get(7)?,
+    })
+}
+
+pub(crate) fn load_completed_focus_start_times(
+    database_path: &Path,
+    until: DateTime<Utc>,
+) -> Result<Vec<DateTime<Utc>>, InfraError> {
+    let connection = Connection::open(database_path)?;
+    let mut statement = connection.prepare(
+        "SELECT start_time
+         FROM pomodoro_logs
+         WHERE phase = 'focus' AND interruption_reason IS NULL AND start_time <= ?1
+         ORDER BY start_time ASC",
+    )?;
+    let mut rows = statement.query(params![until.to_rfc3339()])?;
+    let mut start_times = Vec::new();
+    while let Some(row) = rows.next()? {
+        start_times.push(parse_datetime_input(
+            &row.get::<_, String>(0)?,
+            "pomodoro_logs.start_time",
+        )?);
+    }
+    Ok(start_times)
+}
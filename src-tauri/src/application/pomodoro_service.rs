@@ -1,53 +1,73 @@
 use crate::application::audit_log::append_audit_log;
-use crate::application::commands::{lock_runtime, AppState};
+use crate::application::block_generation::BREAK_BLOCK_SOURCE;
+use crate::application::commands::{lock_runtime, AppState, RuntimeState};
 use crate::application::configured_recipes;
 use crate::application::id_factory::next_id;
-use crate::application::policy_service::load_runtime_policy;
+use crate::application::policy_service::{load_runtime_policy, BlockEndBehavior};
 use crate::application::pomodoro_log_store::save_pomodoro_log;
 use crate::application::pomodoro_session_plan;
+use crate::application::pomodoro_session_store::{
+    clear_pomodoro_session, load_pomodoro_session, save_pomodoro_session,
+};
+use crate::application::reflection_service::ReflectionLogItem;
 use crate::application::task_runtime::assign_task_to_block;
 use crate::domain::models::{PomodoroLog, PomodoroPhase, TaskStatus};
 use crate::infrastructure::error::InfraError;
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
 
 const POMODORO_FOCUS_SECONDS: u32 = 25 * 60;
 const POMODORO_BREAK_SECONDS: u32 = 5 * 60;
+pub const BREAK_REMINDER_EVENT: &str = "pomodoro://break-reminder";
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub(crate) enum PomodoroRuntimePhase {
     Idle,
+    Setup,
     Focus,
     Break,
     Paused,
+    AwaitingAdvance,
 }
 
 impl PomodoroRuntimePhase {
     fn as_str(self) -> &'static str {
         match self {
             Self::Idle => "idle",
+            Self::Setup => "setup",
             Self::Focus => "focus",
             Self::Break => "break",
             Self::Paused => "paused",
+            Self::AwaitingAdvance => "awaiting_advance",
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct PomodoroRuntimeState {
     current_block_id: Option<String>,
     pub(crate) current_task_id: Option<String>,
     phase: PomodoroRuntimePhase,
     paused_phase: Option<PomodoroRuntimePhase>,
+    paused_log_id: Option<String>,
+    awaiting_phase: Option<PomodoroRuntimePhase>,
     remaining_seconds: u32,
+    remaining_seconds_at_start: u32,
     start_time: Option<DateTime<Utc>>,
+    last_activity_at: Option<DateTime<Utc>>,
     total_cycles: u32,
     completed_cycles: u32,
     current_cycle: u32,
     focus_seconds: u32,
     break_seconds: u32,
     active_log: Option<PomodoroLog>,
+    #[serde(skip)]
     completed_logs: Vec<PomodoroLog>,
+    #[serde(skip)]
+    session_log_offset: usize,
+    break_reminder_sent: bool,
 }
 
 impl Default for PomodoroRuntimeState {
@@ -57,8 +77,12 @@ impl Default for PomodoroRuntimeState {
             current_task_id: None,
             phase: PomodoroRuntimePhase::Idle,
             paused_phase: None,
+            paused_log_id: None,
+            awaiting_phase: None,
             remaining_seconds: 0,
+            remaining_seconds_at_start: 0,
             start_time: None,
+            last_activity_at: None,
             total_cycles: 0,
             completed_cycles: 0,
             current_cycle: 0,
@@ -66,6 +90,8 @@ impl Default for PomodoroRuntimeState {
             break_seconds: POMODORO_BREAK_SECONDS,
             active_log: None,
             completed_logs: Vec::new(),
+            session_log_offset: 0,
+            break_reminder_sent: false,
         }
     }
 }
@@ -76,6 +102,7 @@ pub struct PomodoroStateResponse {
     pub current_task_id: Option<String>,
     pub phase: String,
     pub remaining_seconds: u32,
+    pub remaining_display: String,
     pub start_time: Option<String>,
     pub total_cycles: u32,
     pub completed_cycles: u32,
@@ -110,6 +137,12 @@ impl<'a> PomodoroService<'a> {
             .get(block_id)
             .map(|stored| stored.block.clone())
             .ok_or_else(|| InfraError::InvalidConfig(format!("block not found: {}", block_id)))?;
+        if block.source == BREAK_BLOCK_SOURCE {
+            return Err(InfraError::InvalidConfig(format!(
+                "break blocks cannot host a pomodoro session: {}",
+                block_id
+            )));
+        }
 
         let normalized_task_id = task_id
             .as_deref()
@@ -127,14 +160,33 @@ impl<'a> PomodoroService<'a> {
                 "timer must be idle before start".to_string(),
             ));
         }
+        let now = Utc::now();
+        let local_time = now.with_timezone(&policy.timezone).time();
+        if let Some(quiet_hours) = policy
+            .quiet_hours
+            .filter(|quiet_hours| quiet_hours.contains(local_time))
+        {
+            return Err(InfraError::InvalidConfig(format!(
+                "focus sessions cannot start during quiet hours ({}-{})",
+                quiet_hours.start.format("%H:%M"),
+                quiet_hours.end.format("%H:%M")
+            )));
+        }
+        let today = now.with_timezone(&policy.timezone).date_naive().to_string();
+        if block.date != today && !policy.allow_out_of_date_start {
+            return Err(InfraError::InvalidConfig(format!(
+                "block {} is dated {}, not today ({}); see pomodoro.allowOutOfDateStart",
+                block_id, block.date, today
+            )));
+        }
 
         let recipes = configured_recipes::load_configured_recipes(self.state.config_dir());
         let session_plan = pomodoro_session_plan::build_pomodoro_session_plan(
             &block,
             policy.break_duration_minutes,
+            policy.setup_seconds,
             &recipes,
         );
-        let now = Utc::now();
         runtime.pomodoro.current_block_id = Some(block_id.to_string());
         runtime.pomodoro.current_task_id = normalized_task_id;
         if let Some(task_id) = runtime.pomodoro.current_task_id.clone() {
@@ -147,11 +199,16 @@ impl<'a> PomodoroService<'a> {
         }
         runtime.pomodoro.total_cycles = session_plan.total_cycles;
         runtime.pomodoro.completed_cycles = 0;
+        runtime.pomodoro.session_log_offset = runtime.pomodoro.completed_logs.len();
         runtime.pomodoro.current_cycle = 1;
         runtime.pomodoro.focus_seconds = session_plan.focus_seconds;
         runtime.pomodoro.break_seconds = session_plan.break_seconds;
         runtime.pomodoro.paused_phase = None;
-        start_pomodoro_phase(&mut runtime.pomodoro, PomodoroRuntimePhase::Focus, now)?;
+        runtime.pomodoro.last_activity_at = Some(now);
+        match session_plan.setup_seconds {
+            Some(setup_seconds) => start_setup_phase(&mut runtime.pomodoro, setup_seconds, now),
+            None => start_pomodoro_phase(&mut runtime.pomodoro, PomodoroRuntimePhase::Focus, now)?,
+        }
 
         if let Some(task_id) = runtime.pomodoro.current_task_id.clone() {
             append_audit_log(
@@ -165,6 +222,7 @@ impl<'a> PomodoroService<'a> {
         }
         self.state
             .log_info("start_pomodoro", &format!("started block_id={}", block_id));
+        self.persist_session(&runtime)?;
         Ok(to_pomodoro_state_response(&runtime.pomodoro))
     }
 
@@ -218,6 +276,93 @@ impl<'a> PomodoroService<'a> {
             "interrupt_timer",
             &format!("interrupted active timer reason={}", interruption_reason),
         );
+        self.persist_session(&runtime)?;
+        Ok(to_pomodoro_state_response(&runtime.pomodoro))
+    }
+
+    pub fn set_current_task(
+        &self,
+        task_id: Option<String>,
+    ) -> Result<PomodoroStateResponse, InfraError> {
+        let normalized_task_id = task_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(ToOwned::to_owned);
+        let mut runtime = lock_runtime(self.state)?;
+        if runtime.pomodoro.phase == PomodoroRuntimePhase::Idle {
+            return Err(InfraError::InvalidConfig("timer is not running".to_string()));
+        }
+        if let Some(task_id) = normalized_task_id
+            .as_deref()
+            .filter(|task_id| !runtime.tasks.contains_key(*task_id))
+        {
+            return Err(InfraError::InvalidConfig(format!("task not found: {}", task_id)));
+        }
+        if runtime.pomodoro.current_task_id == normalized_task_id {
+            return Ok(to_pomodoro_state_response(&runtime.pomodoro));
+        }
+
+        let block_id = runtime
+            .pomodoro
+            .current_block_id
+            .clone()
+            .ok_or_else(|| InfraError::InvalidConfig("current block is missing".to_string()))?;
+        let now = Utc::now();
+        let next_segment = runtime
+            .pomodoro
+            .active_log
+            .as_ref()
+            .map(|active| active.phase.clone());
+        if let Some(log) = finish_active_log(
+            &mut runtime.pomodoro,
+            now,
+            Some("task_switched".to_string()),
+        ) {
+            save_pomodoro_log(self.state.database_path(), &log)?;
+        }
+
+        runtime.pomodoro.current_task_id = normalized_task_id.clone();
+        if let Some(task_id) = normalized_task_id.as_deref() {
+            assign_task_to_block(&mut runtime, task_id, &block_id);
+            if let Some(task) = runtime
+                .tasks
+                .get_mut(task_id)
+                .filter(|task| task.status != TaskStatus::Completed)
+            {
+                task.status = TaskStatus::InProgress;
+            }
+        }
+        if let Some(phase) = next_segment {
+            runtime.pomodoro.active_log = Some(PomodoroLog {
+                id: next_id("pom"),
+                block_id: block_id.clone(),
+                task_id: normalized_task_id.clone(),
+                phase,
+                start_time: now,
+                end_time: None,
+                interruption_reason: None,
+                resumed_from: None,
+            });
+        }
+
+        append_audit_log(
+            self.state.database_path(),
+            "task_selected",
+            &serde_json::json!({
+                "taskId": normalized_task_id,
+                "blockId": block_id,
+            }),
+        )?;
+        self.state.log_info(
+            "set_current_task",
+            &format!(
+                "block_id={} task_id={}",
+                block_id,
+                normalized_task_id.as_deref().unwrap_or("none")
+            ),
+        );
+        self.persist_session(&runtime)?;
         Ok(to_pomodoro_state_response(&runtime.pomodoro))
     }
 
@@ -226,9 +371,12 @@ impl<'a> PomodoroService<'a> {
         reason: Option<String>,
     ) -> Result<PomodoroStateResponse, InfraError> {
         let mut runtime = lock_runtime(self.state)?;
-        if runtime.pomodoro.phase != PomodoroRuntimePhase::Focus
-            && runtime.pomodoro.phase != PomodoroRuntimePhase::Break
-        {
+        if runtime.pomodoro.phase == PomodoroRuntimePhase::Setup {
+            return Err(InfraError::InvalidConfig(
+                "setup cannot be paused; stop the timer instead".to_string(),
+            ));
+        }
+        if !is_counting_down(runtime.pomodoro.phase) {
             return Err(InfraError::InvalidConfig("timer is not running".to_string()));
         }
 
@@ -239,23 +387,13 @@ impl<'a> PomodoroService<'a> {
             .map(ToOwned::to_owned)
             .unwrap_or_else(|| "paused".to_string());
 
-        if let Some(log) = finish_active_log(
-            &mut runtime.pomodoro,
-            Utc::now(),
-            Some(interruption_reason.clone()),
-        ) {
+        if let Some(log) = pause_session(&mut runtime.pomodoro, Utc::now(), interruption_reason) {
             save_pomodoro_log(self.state.database_path(), &log)?;
         }
 
-        runtime.pomodoro.paused_phase = Some(runtime.pomodoro.phase);
-        runtime.pomodoro.phase = PomodoroRuntimePhase::Paused;
-        runtime.pomodoro.remaining_seconds = runtime
-            .pomodoro
-            .remaining_seconds
-            .min(runtime.pomodoro.focus_seconds.max(runtime.pomodoro.break_seconds));
-
         self.state
             .log_info("pause_pomodoro", "paused active pomodoro timer");
+        self.persist_session(&runtime)?;
         Ok(to_pomodoro_state_response(&runtime.pomodoro))
     }
 
@@ -289,6 +427,8 @@ impl<'a> PomodoroService<'a> {
 
         runtime.pomodoro.phase = resume_phase;
         runtime.pomodoro.start_time = Some(now);
+        runtime.pomodoro.last_activity_at = Some(now);
+        runtime.pomodoro.remaining_seconds_at_start = runtime.pomodoro.remaining_seconds;
         runtime.pomodoro.active_log = Some(PomodoroLog {
             id: next_id("pom"),
             block_id,
@@ -297,35 +437,229 @@ impl<'a> PomodoroService<'a> {
             start_time: now,
             end_time: None,
             interruption_reason: None,
+            resumed_from: runtime.pomodoro.paused_log_id.take(),
         });
 
         self.state
             .log_info("resume_pomodoro", "resumed paused pomodoro timer");
+        self.persist_session(&runtime)?;
         Ok(to_pomodoro_state_response(&runtime.pomodoro))
     }
 
     pub fn advance_pomodoro(&self) -> Result<PomodoroStateResponse, InfraError> {
         let mut runtime = lock_runtime(self.state)?;
-        if runtime.pomodoro.phase != PomodoroRuntimePhase::Focus
-            && runtime.pomodoro.phase != PomodoroRuntimePhase::Break
-        {
+        if runtime.pomodoro.phase == PomodoroRuntimePhase::AwaitingAdvance {
+            let awaiting_phase = runtime
+                .pomodoro
+                .awaiting_phase
+                .take()
+                .ok_or_else(|| InfraError::InvalidConfig("awaiting phase is missing".to_string()))?;
+            runtime.pomodoro.phase = awaiting_phase;
+        }
+        if !is_counting_down(runtime.pomodoro.phase) {
             return Err(InfraError::InvalidConfig("timer is not running".to_string()));
         }
 
-        let now = Utc::now();
-        if let Some(log) = finish_active_log(&mut runtime.pomodoro, now, None) {
+        self.advance_phase(&mut runtime.pomodoro, Utc::now())?;
+        self.persist_session(&runtime)?;
+        Ok(to_pomodoro_state_response(&runtime.pomodoro))
+    }
+
+    #[cfg(test)]
+    pub fn tick_pomodoro(&self, now: DateTime<Utc>) -> Result<PomodoroStateResponse, InfraError> {
+        self.tick_pomodoro_with_events(now, |_, _| {})
+    }
+
+    /// Ticks the timer, passing events such as the mid-break reminder to `emit`.
+    pub fn tick_pomodoro_with_events(
+        &self,
+        now: DateTime<Utc>,
+        mut emit: impl FnMut(&'static str, &PomodoroStateResponse),
+    ) -> Result<PomodoroStateResponse, InfraError> {
+        let policy = load_runtime_policy(self.state.config_dir());
+        let mut runtime = lock_runtime(self.state)?;
+        if !is_counting_down(runtime.pomodoro.phase) {
+            return Ok(to_pomodoro_state_response(&runtime.pomodoro));
+        }
+        if self.handle_block_end(&mut runtime, policy.on_block_end, now)? {
+            self.persist_session(&runtime)?;
+            return Ok(to_pomodoro_state_response(&runtime.pomodoro));
+        }
+        let pomodoro = &mut runtime.pomodoro;
+        let Some(start_time) = pomodoro.start_time else {
+            return Ok(to_pomodoro_state_response(pomodoro));
+        };
+
+        let expired_at =
+            start_time + chrono::Duration::seconds(i64::from(pomodoro.remaining_seconds_at_start));
+        let idle_at = policy
+            .idle_timeout_minutes
+            .zip(pomodoro.last_activity_at)
+            .map(|(minutes, last_activity_at)| {
+                last_activity_at + chrono::Duration::minutes(i64::from(minutes))
+            })
+            .filter(|idle_at| {
+                pomodoro.phase == PomodoroRuntimePhase::Focus
+                    && *idle_at <= now
+                    && *idle_at < expired_at
+            });
+        if let Some(idle_at) = idle_at {
+            let elapsed_seconds = (idle_at - start_time).num_seconds().max(0) as u64;
+            pomodoro.remaining_seconds = u64::from(pomodoro.remaining_seconds_at_start)
+                .saturating_sub(elapsed_seconds) as u32;
+            if let Some(log) = pause_session(pomodoro, idle_at, "idle".to_string()) {
+                save_pomodoro_log(self.state.database_path(), &log)?;
+            }
+            self.state
+                .log_info("tick_pomodoro", "paused focus after idle timeout");
+            let response = to_pomodoro_state_response(pomodoro);
+            self.persist_session(&runtime)?;
+            return Ok(response);
+        }
+
+        let elapsed_seconds = (now - start_time).num_seconds().max(0) as u64;
+        pomodoro.remaining_seconds = u64::from(pomodoro.remaining_seconds_at_start)
+            .saturating_sub(elapsed_seconds) as u32;
+        if pomodoro.remaining_seconds > 0 {
+            let remind = pomodoro.phase == PomodoroRuntimePhase::Break
+                && !pomodoro.break_reminder_sent
+                && policy
+                    .break_reminder_seconds
+                    .is_some_and(|offset| elapsed_seconds >= u64::from(offset));
+            let response = to_pomodoro_state_response(pomodoro);
+            if remind {
+                pomodoro.break_reminder_sent = true;
+                emit(BREAK_REMINDER_EVENT, &response);
+            }
+            return Ok(response);
+        }
+
+        let auto_start = match pomodoro.phase {
+            PomodoroRuntimePhase::Setup => true,
+            PomodoroRuntimePhase::Focus => policy.auto_start_break,
+            _ => policy.auto_start_focus,
+        };
+        if auto_start {
+            self.advance_phase(pomodoro, expired_at)?;
+        } else {
+            if let Some(log) = finish_active_log(pomodoro, expired_at, None) {
+                save_pomodoro_log(self.state.database_path(), &log)?;
+            }
+            pomodoro.awaiting_phase = Some(pomodoro.phase);
+            pomodoro.phase = PomodoroRuntimePhase::AwaitingAdvance;
+            self.state.log_info(
+                "tick_pomodoro",
+                "phase ended; awaiting manual advance",
+            );
+        }
+        let response = to_pomodoro_state_response(pomodoro);
+        self.persist_session(&runtime)?;
+        Ok(response)
+    }
+
+    fn handle_block_end(
+        &self,
+        runtime: &mut RuntimeState,
+        behavior: Option<BlockEndBehavior>,
+        now: DateTime<Utc>,
+    ) -> Result<bool, InfraError> {
+        let Some(behavior) = behavior else {
+            return Ok(false);
+        };
+        let Some(block) = runtime
+            .pomodoro
+            .current_block_id
+            .as_deref()
+            .and_then(|block_id| runtime.blocks.get(block_id))
+            .map(|stored| stored.block.clone())
+            .filter(|block| now > block.end_at)
+        else {
+            return Ok(false);
+        };
+        let next_block_id = match behavior {
+            BlockEndBehavior::Stop => None,
+            BlockEndBehavior::Continue => runtime
+                .blocks
+                .values()
+                .filter(|stored| stored.block.id != block.id && stored.block.start_at == block.end_at)
+                .filter(|stored| stored.block.source != BREAK_BLOCK_SOURCE)
+                .map(|stored| stored.block.id.clone())
+                .min(),
+        };
+
+        let Some(next_block_id) = next_block_id else {
+            if let Some(log) = finish_active_log(
+                &mut runtime.pomodoro,
+                block.end_at,
+                Some("block_ended".to_string()),
+            ) {
+                save_pomodoro_log(self.state.database_path(), &log)?;
+            }
+            reset_pomodoro_session(&mut runtime.pomodoro);
+            self.state.log_info(
+                "tick_pomodoro",
+                &format!("stopped session at block end block_id={}", block.id),
+            );
+            return Ok(true);
+        };
+
+        let next_phase = runtime
+            .pomodoro
+            .active_log
+            .as_ref()
+            .map(|active| active.phase.clone());
+        if let Some(log) = finish_active_log(
+            &mut runtime.pomodoro,
+            block.end_at,
+            Some("carried_over".to_string()),
+        ) {
             save_pomodoro_log(self.state.database_path(), &log)?;
         }
-        match runtime.pomodoro.phase {
+        runtime.pomodoro.current_block_id = Some(next_block_id.clone());
+        if let Some(phase) = next_phase {
+            runtime.pomodoro.active_log = Some(PomodoroLog {
+                id: next_id("pom"),
+                block_id: next_block_id.clone(),
+                task_id: runtime.pomodoro.current_task_id.clone(),
+                phase,
+                start_time: block.end_at,
+                end_time: None,
+                interruption_reason: None,
+                resumed_from: None,
+            });
+        }
+        self.state.log_info(
+            "tick_pomodoro",
+            &format!(
+                "carried session from block_id={} into block_id={}",
+                block.id, next_block_id
+            ),
+        );
+        Ok(false)
+    }
+
+    fn advance_phase(
+        &self,
+        pomodoro: &mut PomodoroRuntimeState,
+        now: DateTime<Utc>,
+    ) -> Result<(), InfraError> {
+        if let Some(log) = finish_active_log(pomodoro, now, None) {
+            save_pomodoro_log(self.state.database_path(), &log)?;
+        }
+        match pomodoro.phase {
+            PomodoroRuntimePhase::Setup => {
+                start_pomodoro_phase(pomodoro, PomodoroRuntimePhase::Focus, now)?;
+                self.state
+                    .log_info("advance_pomodoro", "setup finished; advanced to focus phase");
+            }
             PomodoroRuntimePhase::Focus => {
-                let total_cycles = runtime.pomodoro.total_cycles.max(1);
-                runtime.pomodoro.completed_cycles = runtime
-                    .pomodoro
+                let total_cycles = pomodoro.total_cycles.max(1);
+                pomodoro.completed_cycles = pomodoro
                     .completed_cycles
                     .saturating_add(1)
                     .min(total_cycles);
-                start_pomodoro_phase(&mut runtime.pomodoro, PomodoroRuntimePhase::Break, now)?;
-                if runtime.pomodoro.completed_cycles >= total_cycles {
+                start_pomodoro_phase(pomodoro, PomodoroRuntimePhase::Break, now)?;
+                if pomodoro.completed_cycles >= total_cycles {
                     self.state
                         .log_info("advance_pomodoro", "advanced to final break phase");
                 } else {
@@ -334,23 +668,22 @@ impl<'a> PomodoroService<'a> {
                 }
             }
             PomodoroRuntimePhase::Break => {
-                let total_cycles = runtime.pomodoro.total_cycles.max(1);
-                if runtime.pomodoro.completed_cycles >= total_cycles {
-                    reset_pomodoro_session(&mut runtime.pomodoro);
+                let total_cycles = pomodoro.total_cycles.max(1);
+                if pomodoro.completed_cycles >= total_cycles {
+                    reset_pomodoro_session(pomodoro);
                     self.state.log_info(
                         "advance_pomodoro",
                         "completed all cycles in block session",
                     );
                 } else {
-                    start_pomodoro_phase(&mut runtime.pomodoro, PomodoroRuntimePhase::Focus, now)?;
+                    start_pomodoro_phase(pomodoro, PomodoroRuntimePhase::Focus, now)?;
                     self.state
                         .log_info("advance_pomodoro", "advanced to focus phase");
                 }
             }
             _ => {}
         }
-
-        Ok(to_pomodoro_state_response(&runtime.pomodoro))
+        Ok(())
     }
 
     pub fn complete_pomodoro(&self) -> Result<PomodoroStateResponse, InfraError> {
@@ -374,15 +707,151 @@ impl<'a> PomodoroService<'a> {
 
         self.state
             .log_info("complete_pomodoro", "completed pomodoro session");
+        self.persist_session(&runtime)?;
         Ok(to_pomodoro_state_response(&runtime.pomodoro))
     }
 
+    pub fn report_activity(&self, at: DateTime<Utc>) -> Result<PomodoroStateResponse, InfraError> {
+        let mut runtime = lock_runtime(self.state)?;
+        let pomodoro = &mut runtime.pomodoro;
+        if pomodoro.phase != PomodoroRuntimePhase::Idle {
+            pomodoro.last_activity_at = pomodoro.last_activity_at.max(Some(at));
+        }
+        Ok(to_pomodoro_state_response(pomodoro))
+    }
+
+    pub fn get_session_logs(&self) -> Result<Vec<ReflectionLogItem>, InfraError> {
+        let runtime = lock_runtime(self.state)?;
+        let pomodoro = &runtime.pomodoro;
+        let Some(block_id) = pomodoro
+            .current_block_id
+            .as_deref()
+            .filter(|_| pomodoro.phase != PomodoroRuntimePhase::Idle)
+        else {
+            return Ok(Vec::new());
+        };
+        Ok(pomodoro
+            .completed_logs
+            .iter()
+            .skip(pomodoro.session_log_offset)
+            .filter(|log| log.block_id == block_id)
+            .cloned()
+            .map(ReflectionLogItem::from)
+            .collect())
+    }
+
+    /// Records a focus segment that happened without the timer running.
+    pub fn log_pomodoro(
+        &self,
+        block_id: String,
+        task_id: Option<String>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        interrupted: bool,
+    ) -> Result<PomodoroLog, InfraError> {
+        let block_id = block_id.trim();
+        if block_id.is_empty() {
+            return Err(InfraError::InvalidConfig(
+                "block_id must not be empty".to_string(),
+            ));
+        }
+        if end <= start {
+            return Err(InfraError::InvalidConfig(
+                "end must be after start".to_string(),
+            ));
+        }
+        let task_id = task_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(ToOwned::to_owned);
+
+        let mut runtime = lock_runtime(self.state)?;
+        if !runtime.blocks.contains_key(block_id) {
+            return Err(InfraError::InvalidConfig(format!("block not found: {}", block_id)));
+        }
+        if let Some(missing) = task_id
+            .as_deref()
+            .filter(|task_id| !runtime.tasks.contains_key(*task_id))
+        {
+            return Err(InfraError::InvalidConfig(format!("task not found: {}", missing)));
+        }
+
+        let log = PomodoroLog {
+            id: next_id("pom"),
+            block_id: block_id.to_string(),
+            task_id: task_id.clone(),
+            phase: PomodoroPhase::Focus,
+            start_time: start,
+            end_time: Some(end),
+            interruption_reason: interrupted.then(|| "manual".to_string()),
+            resumed_from: None,
+        };
+        save_pomodoro_log(self.state.database_path(), &log)?;
+        if let Some(task) = task_id
+            .as_deref()
+            .filter(|_| !interrupted)
+            .and_then(|task_id| runtime.tasks.get_mut(task_id))
+        {
+            task.completed_pomodoros = task.completed_pomodoros.saturating_add(1);
+        }
+        drop(runtime);
+
+        self.state.log_info(
+            "log_pomodoro",
+            &format!("logged focus log_id={} block_id={block_id}", log.id),
+        );
+        Ok(log)
+    }
+
+    fn persist_session(&self, runtime: &RuntimeState) -> Result<(), InfraError> {
+        if runtime.pomodoro.phase == PomodoroRuntimePhase::Idle {
+            return clear_pomodoro_session(self.state.database_path());
+        }
+        let block_end_at = runtime
+            .pomodoro
+            .current_block_id
+            .as_deref()
+            .and_then(|block_id| runtime.blocks.get(block_id))
+            .map(|stored| stored.block.end_at);
+        save_pomodoro_session(self.state.database_path(), &runtime.pomodoro, block_end_at)
+    }
+
     pub fn get_state(&self) -> Result<PomodoroStateResponse, InfraError> {
         let runtime = lock_runtime(self.state)?;
         Ok(to_pomodoro_state_response(&runtime.pomodoro))
     }
 }
 
+fn is_counting_down(phase: PomodoroRuntimePhase) -> bool {
+    matches!(
+        phase,
+        PomodoroRuntimePhase::Setup | PomodoroRuntimePhase::Focus | PomodoroRuntimePhase::Break
+    )
+}
+
+fn enter_phase(
+    runtime: &mut PomodoroRuntimeState,
+    phase: PomodoroRuntimePhase,
+    seconds: u32,
+    now: DateTime<Utc>,
+) {
+    runtime.phase = phase;
+    runtime.paused_phase = None;
+    runtime.awaiting_phase = None;
+    runtime.break_reminder_sent = false;
+    runtime.remaining_seconds = seconds;
+    runtime.remaining_seconds_at_start = seconds;
+    runtime.start_time = Some(now);
+}
+
+/// Setup is not logged and does not count towards cycles; focus starts when it runs out.
+fn start_setup_phase(runtime: &mut PomodoroRuntimeState, setup_seconds: u32, now: DateTime<Utc>) {
+    enter_phase(runtime, PomodoroRuntimePhase::Setup, setup_seconds, now);
+    runtime.current_cycle = 0;
+    runtime.active_log = None;
+}
+
 fn start_pomodoro_phase(
     runtime: &mut PomodoroRuntimeState,
     phase: PomodoroRuntimePhase,
@@ -402,14 +871,12 @@ fn start_pomodoro_phase(
         }
     };
 
-    runtime.phase = phase;
-    runtime.paused_phase = None;
-    runtime.remaining_seconds = match phase {
+    let phase_seconds = match phase {
         PomodoroRuntimePhase::Focus => runtime.focus_seconds,
         PomodoroRuntimePhase::Break => runtime.break_seconds,
         _ => 0,
     };
-    runtime.start_time = Some(now);
+    enter_phase(runtime, phase, phase_seconds, now);
     let total_cycles = runtime.total_cycles.max(1);
     runtime.current_cycle = match phase {
         PomodoroRuntimePhase::Focus => runtime.completed_cycles.saturating_add(1).min(total_cycles),
@@ -424,6 +891,7 @@ fn start_pomodoro_phase(
         start_time: now,
         end_time: None,
         interruption_reason: None,
+        resumed_from: None,
     });
     Ok(())
 }
@@ -442,19 +910,72 @@ fn finish_active_log(
     None
 }
 
+fn pause_session(
+    runtime: &mut PomodoroRuntimeState,
+    at: DateTime<Utc>,
+    interruption_reason: String,
+) -> Option<PomodoroLog> {
+    let log = finish_active_log(runtime, at, Some(interruption_reason));
+    runtime.paused_log_id = log.as_ref().map(|log| log.id.clone());
+    runtime.paused_phase = Some(runtime.phase);
+    runtime.phase = PomodoroRuntimePhase::Paused;
+    runtime.remaining_seconds = runtime
+        .remaining_seconds
+        .min(runtime.focus_seconds.max(runtime.break_seconds));
+    log
+}
+
+/// Resumes the session saved before a restart. A session whose block has already ended is
+/// closed at that point instead of being resumed.
+pub(crate) fn restore_pomodoro_session(
+    database_path: &Path,
+    pomodoro: &mut PomodoroRuntimeState,
+    now: DateTime<Utc>,
+) -> Result<(), InfraError> {
+    let Some((mut session, block_end_at)) = load_pomodoro_session(database_path)? else {
+        return Ok(());
+    };
+    if let Some(block_end_at) = block_end_at.filter(|block_end_at| *block_end_at <= now) {
+        let end_time = session
+            .start_time
+            .map(|start_time| {
+                let remaining_seconds = i64::from(session.remaining_seconds_at_start);
+                start_time + chrono::Duration::seconds(remaining_seconds)
+            })
+            .map_or(block_end_at, |expired_at| expired_at.min(block_end_at));
+        if let Some(log) = finish_active_log(&mut session, end_time, None) {
+            save_pomodoro_log(database_path, &log)?;
+        }
+        return clear_pomodoro_session(database_path);
+    }
+
+    if let Some(start_time) = session.start_time.filter(|_| is_counting_down(session.phase)) {
+        let elapsed_seconds = (now - start_time).num_seconds().max(0) as u64;
+        session.remaining_seconds = u64::from(session.remaining_seconds_at_start)
+            .saturating_sub(elapsed_seconds) as u32;
+    }
+    *pomodoro = session;
+    Ok(())
+}
+
 fn reset_pomodoro_session(runtime: &mut PomodoroRuntimeState) {
     runtime.current_block_id = None;
     runtime.current_task_id = None;
     runtime.phase = PomodoroRuntimePhase::Idle;
     runtime.paused_phase = None;
+    runtime.paused_log_id = None;
+    runtime.awaiting_phase = None;
     runtime.remaining_seconds = 0;
+    runtime.remaining_seconds_at_start = 0;
     runtime.start_time = None;
+    runtime.last_activity_at = None;
     runtime.total_cycles = 0;
     runtime.completed_cycles = 0;
     runtime.current_cycle = 0;
     runtime.focus_seconds = POMODORO_FOCUS_SECONDS;
     runtime.break_seconds = POMODORO_BREAK_SECONDS;
     runtime.active_log = None;
+    runtime.break_reminder_sent = false;
 }
 
 fn to_pomodoro_state_response(state: &PomodoroRuntimeState) -> PomodoroStateResponse {
@@ -463,6 +984,7 @@ fn to_pomodoro_state_response(state: &PomodoroRuntimeState) -> PomodoroStateResp
         current_task_id: state.current_task_id.clone(),
         phase: state.phase.as_str().to_string(),
         remaining_seconds: state.remaining_seconds,
+        remaining_display: format_remaining(state.remaining_seconds),
         start_time: state.start_time.map(|value| value.to_rfc3339()),
         total_cycles: state.total_cycles,
         completed_cycles: state.completed_cycles,
@@ -470,12 +992,82 @@ fn to_pomodoro_state_response(state: &PomodoroRuntimeState) -> PomodoroStateResp
     }
 }
 
+/// `MM:SS`, or `H:MM:SS` once an hour or more remains.
+fn format_remaining(seconds: u32) -> String {
+    let (hours, minutes, seconds) = (seconds / 3600, seconds % 3600 / 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::application::block_service::BlockService;
     use crate::application::reflection_service::ReflectionService;
+    use crate::application::test_support::config_fs::{
+        allow_out_of_date_pomodoro_start, write_json,
+    };
     use crate::application::test_support::workspace::TempWorkspace;
+    use crate::application::commands::StoredBlock;
+    use crate::domain::models::{AutoDriveMode, Block, BlockContents, Firmness};
+
+    fn insert_block(state: &AppState, id: &str, start_at: DateTime<Utc>, end_at: DateTime<Utc>) {
+        lock_runtime(state).expect("runtime lock").blocks.insert(
+            id.to_string(),
+            StoredBlock {
+                block: Block {
+                    id: id.to_string(),
+                    instance: format!("manual:{id}"),
+                    date: start_at.date_naive().to_string(),
+                    start_at,
+                    end_at,
+                    firmness: Firmness::Draft,
+                    planned_pomodoros: 2,
+                    source: "manual".to_string(),
+                    source_id: None,
+                    project: None,
+                    title: None,
+                    recipe_id: "rcp-missing".to_string(),
+                    auto_drive_mode: AutoDriveMode::Manual,
+                    contents: BlockContents::default(),
+                },
+                calendar_event_id: None,
+                calendar_account_id: None,
+            },
+        );
+    }
+
+    fn start_at_block_boundary(state: &AppState, on_block_end: &str) -> DateTime<Utc> {
+        write_json(
+            &state.config_dir().join("policies.json"),
+            serde_json::json!({
+                "schema": 1,
+                "onBlockEnd": on_block_end
+            }),
+        );
+        let now = Utc::now();
+        insert_block(
+            state,
+            "blk-current",
+            now - chrono::Duration::minutes(20),
+            now + chrono::Duration::minutes(10),
+        );
+        insert_block(
+            state,
+            "blk-next",
+            now + chrono::Duration::minutes(10),
+            now + chrono::Duration::minutes(70),
+        );
+        let started = PomodoroService::new(state)
+            .start_pomodoro("blk-current".to_string(), None)
+            .expect("start pomodoro");
+        DateTime::parse_from_rfc3339(started.start_time.as_deref().expect("start"))
+            .expect("parse start")
+            .with_timezone(&Utc)
+    }
 
     #[tokio::test]
     async fn property_16_break_phase_starts_automatically_after_focus_ends() {
@@ -487,6 +1079,7 @@ mod tests {
             .expect("generate blocks");
         let service = PomodoroService::new(&state);
 
+        allow_out_of_date_pomodoro_start(state.config_dir());
         let started = service
             .start_pomodoro(blocks[0].id.clone(), None)
             .expect("start pomodoro");
@@ -508,6 +1101,7 @@ mod tests {
             .expect("generate blocks");
         let service = PomodoroService::new(&state);
 
+        allow_out_of_date_pomodoro_start(state.config_dir());
         let _ = service
             .start_pomodoro(blocks[0].id.clone(), None)
             .expect("start first pomodoro");
@@ -523,7 +1117,7 @@ mod tests {
         let _ = service.complete_pomodoro().expect("complete second pomodoro");
 
         let summary = ReflectionService::new(&state)
-            .get_summary(None, None)
+            .get_summary(None, None, None)
             .expect("reflection summary");
 
         assert!(summary.logs.iter().any(|log| {
@@ -533,4 +1127,344 @@ mod tests {
             log.interruption_reason.as_deref() == Some("context-switch")
         }));
     }
+
+    #[tokio::test]
+    async fn tick_auto_starts_break_and_focus_by_default() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        let blocks = BlockService::new(&state)
+            .generate_blocks("2026-02-16".to_string(), None)
+            .await
+            .expect("generate blocks");
+        let service = PomodoroService::new(&state);
+
+        allow_out_of_date_pomodoro_start(state.config_dir());
+        let started = service
+            .start_pomodoro(blocks[0].id.clone(), None)
+            .expect("start pomodoro");
+        let started_at = DateTime::parse_from_rfc3339(started.start_time.as_deref().expect("start"))
+            .expect("parse start")
+            .with_timezone(&Utc);
+
+        let mid_focus = service
+            .tick_pomodoro(started_at + chrono::Duration::seconds(60))
+            .expect("tick mid focus");
+        assert_eq!(mid_focus.phase, "focus");
+        assert_eq!(mid_focus.remaining_seconds, started.remaining_seconds - 60);
+
+        let on_break = service
+            .tick_pomodoro(started_at + chrono::Duration::seconds(i64::from(started.remaining_seconds)))
+            .expect("tick focus end");
+        assert_eq!(on_break.phase, "break");
+        assert_eq!(on_break.completed_cycles, 1);
+    }
+
+    #[tokio::test]
+    async fn break_reminder_is_emitted_once_at_the_configured_offset() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        write_json(
+            &state.config_dir().join("policies.json"),
+            serde_json::json!({
+                "schema": 1,
+                "pomodoro": { "breakReminderSeconds": 150 }
+            }),
+        );
+        let blocks = BlockService::new(&state)
+            .generate_blocks("2026-02-16".to_string(), None)
+            .await
+            .expect("generate blocks");
+        let service = PomodoroService::new(&state);
+        allow_out_of_date_pomodoro_start(state.config_dir());
+        let started = service
+            .start_pomodoro(blocks[0].id.clone(), None)
+            .expect("start pomodoro");
+        let started_at = DateTime::parse_from_rfc3339(started.start_time.as_deref().expect("start"))
+            .expect("parse start")
+            .with_timezone(&Utc);
+        let break_start =
+            started_at + chrono::Duration::seconds(i64::from(started.remaining_seconds));
+
+        let mut emitted = Vec::new();
+        for offset in [0, 60, 150, 200] {
+            service
+                .tick_pomodoro_with_events(
+                    break_start + chrono::Duration::seconds(offset),
+                    |event, payload| emitted.push((event, payload.phase.clone())),
+                )
+                .expect("tick break");
+        }
+
+        assert_eq!(emitted, vec![(BREAK_REMINDER_EVENT, "break".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn configured_setup_runs_before_the_first_focus_phase() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        write_json(
+            &state.config_dir().join("policies.json"),
+            serde_json::json!({
+                "schema": 1,
+                "pomodoro": { "setupSeconds": 120 }
+            }),
+        );
+        let blocks = BlockService::new(&state)
+            .generate_blocks("2026-02-16".to_string(), None)
+            .await
+            .expect("generate blocks");
+        let service = PomodoroService::new(&state);
+
+        allow_out_of_date_pomodoro_start(state.config_dir());
+        let started = service
+            .start_pomodoro(blocks[0].id.clone(), None)
+            .expect("start pomodoro");
+        assert_eq!(started.phase, "setup");
+        assert_eq!(started.remaining_seconds, 120);
+        assert_eq!(started.completed_cycles, 0);
+        let started_at = DateTime::parse_from_rfc3339(started.start_time.as_deref().expect("start"))
+            .expect("parse start")
+            .with_timezone(&Utc);
+
+        let focus = service
+            .tick_pomodoro(started_at + chrono::Duration::seconds(130))
+            .expect("tick past setup");
+        assert_eq!(focus.phase, "focus");
+        assert_eq!(focus.current_cycle, 1);
+        assert_eq!(focus.remaining_seconds, POMODORO_FOCUS_SECONDS);
+    }
+
+    #[tokio::test]
+    async fn setup_phase_cannot_be_paused() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        write_json(
+            &state.config_dir().join("policies.json"),
+            serde_json::json!({
+                "schema": 1,
+                "pomodoro": { "setupSeconds": 120 }
+            }),
+        );
+        let blocks = BlockService::new(&state)
+            .generate_blocks("2026-02-16".to_string(), None)
+            .await
+            .expect("generate blocks");
+        let service = PomodoroService::new(&state);
+
+        allow_out_of_date_pomodoro_start(state.config_dir());
+        service
+            .start_pomodoro(blocks[0].id.clone(), None)
+            .expect("start pomodoro");
+
+        let paused = service.pause_pomodoro(None);
+        assert!(matches!(
+            paused,
+            Err(InfraError::InvalidConfig(ref message)) if message.contains("setup")
+        ));
+        assert_eq!(service.get_state().expect("state").phase, "setup");
+    }
+
+    #[tokio::test]
+    async fn tick_waits_for_manual_advance_when_auto_start_is_disabled() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        write_json(
+            &state.config_dir().join("policies.json"),
+            serde_json::json!({
+                "schema": 1,
+                "autoStartBreak": false,
+                "autoStartFocus": false
+            }),
+        );
+        let blocks = BlockService::new(&state)
+            .generate_blocks("2026-02-16".to_string(), None)
+            .await
+            .expect("generate blocks");
+        let service = PomodoroService::new(&state);
+
+        allow_out_of_date_pomodoro_start(state.config_dir());
+        let started = service
+            .start_pomodoro(blocks[0].id.clone(), None)
+            .expect("start pomodoro");
+        let started_at = DateTime::parse_from_rfc3339(started.start_time.as_deref().expect("start"))
+            .expect("parse start")
+            .with_timezone(&Utc);
+        let focus_end = started_at + chrono::Duration::seconds(i64::from(started.remaining_seconds));
+
+        let awaiting_break = service.tick_pomodoro(focus_end).expect("tick focus end");
+        assert_eq!(awaiting_break.phase, "awaiting_advance");
+        assert_eq!(awaiting_break.remaining_seconds, 0);
+        let still_awaiting = service
+            .tick_pomodoro(focus_end + chrono::Duration::seconds(120))
+            .expect("tick while awaiting");
+        assert_eq!(still_awaiting.phase, "awaiting_advance");
+
+        let on_break = service.advance_pomodoro().expect("advance to break");
+        assert_eq!(on_break.phase, "break");
+        assert_eq!(on_break.completed_cycles, 1);
+
+        let break_started_at =
+            DateTime::parse_from_rfc3339(on_break.start_time.as_deref().expect("break start"))
+                .expect("parse break start")
+                .with_timezone(&Utc);
+        let awaiting_focus = service
+            .tick_pomodoro(
+                break_started_at + chrono::Duration::seconds(i64::from(on_break.remaining_seconds)),
+            )
+            .expect("tick break end");
+        assert_eq!(awaiting_focus.phase, "awaiting_advance");
+    }
+
+    #[tokio::test]
+    async fn tick_pauses_focus_after_idle_timeout_and_resume_restarts_it() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        write_json(
+            &state.config_dir().join("policies.json"),
+            serde_json::json!({
+                "schema": 1,
+                "idleTimeoutMinutes": 5
+            }),
+        );
+        let blocks = BlockService::new(&state)
+            .generate_blocks("2026-02-16".to_string(), None)
+            .await
+            .expect("generate blocks");
+        let service = PomodoroService::new(&state);
+
+        allow_out_of_date_pomodoro_start(state.config_dir());
+        let started = service
+            .start_pomodoro(blocks[0].id.clone(), None)
+            .expect("start pomodoro");
+        let started_at = DateTime::parse_from_rfc3339(started.start_time.as_deref().expect("start"))
+            .expect("parse start")
+            .with_timezone(&Utc);
+
+        service
+            .report_activity(started_at + chrono::Duration::minutes(3))
+            .expect("report activity");
+        let active = service
+            .tick_pomodoro(started_at + chrono::Duration::minutes(7))
+            .expect("tick before idle timeout");
+        assert_eq!(active.phase, "focus");
+
+        let idle = service
+            .tick_pomodoro(started_at + chrono::Duration::minutes(10))
+            .expect("tick after idle timeout");
+        assert_eq!(idle.phase, "paused");
+        assert_eq!(idle.remaining_seconds, started.remaining_seconds - 8 * 60);
+        let summary = ReflectionService::new(&state)
+            .get_summary(None, None, None)
+            .expect("summary");
+        assert!(summary
+            .logs
+            .iter()
+            .any(|log| log.interruption_reason.as_deref() == Some("idle")));
+
+        let resumed = service.resume_pomodoro().expect("resume after idle");
+        assert_eq!(resumed.phase, "focus");
+        assert_eq!(resumed.remaining_seconds, idle.remaining_seconds);
+    }
+
+    #[test]
+    fn tick_stops_session_when_block_ends_with_stop_policy() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        let started_at = start_at_block_boundary(&state, "stop");
+        let service = PomodoroService::new(&state);
+
+        let stopped = service
+            .tick_pomodoro(started_at + chrono::Duration::minutes(12))
+            .expect("tick past block end");
+
+        assert_eq!(stopped.phase, "idle");
+        assert_eq!(stopped.current_block_id, None);
+        let summary = ReflectionService::new(&state)
+            .get_summary(None, None, None)
+            .expect("summary");
+        assert!(summary
+            .logs
+            .iter()
+            .any(|log| log.interruption_reason.as_deref() == Some("block_ended")));
+    }
+
+    #[test]
+    fn tick_carries_session_into_contiguous_block_with_continue_policy() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        let started_at = start_at_block_boundary(&state, "continue");
+        let service = PomodoroService::new(&state);
+
+        let carried = service
+            .tick_pomodoro(started_at + chrono::Duration::minutes(12))
+            .expect("tick past block end");
+
+        assert_eq!(carried.phase, "focus");
+        assert_eq!(carried.current_block_id.as_deref(), Some("blk-next"));
+        assert_eq!(carried.remaining_seconds, 25 * 60 - 12 * 60);
+        let summary = ReflectionService::new(&state)
+            .get_summary(None, None, None)
+            .expect("summary");
+        assert!(summary.logs.iter().any(|log| {
+            log.block_id == "blk-current" && log.interruption_reason.as_deref() == Some("carried_over")
+        }));
+    }
+
+    #[test]
+    fn active_session_is_restored_with_elapsed_time_deducted() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        let started_at = start_at_block_boundary(&state, "stop");
+
+        let mut restored = PomodoroRuntimeState::default();
+        restore_pomodoro_session(
+            state.database_path(),
+            &mut restored,
+            started_at + chrono::Duration::minutes(5),
+        )
+        .expect("restore session");
+        assert_eq!(restored.phase, PomodoroRuntimePhase::Focus);
+        assert_eq!(restored.current_block_id.as_deref(), Some("blk-current"));
+        assert_eq!(restored.remaining_seconds, 25 * 60 - 5 * 60);
+
+        let restarted = workspace.app_state();
+        let current = PomodoroService::new(&restarted).get_state().expect("state");
+        assert_eq!(current.phase, "focus");
+    }
+
+    #[test]
+    fn stale_session_is_closed_at_block_end_on_restore() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        let started_at = start_at_block_boundary(&state, "stop");
+
+        let mut restored = PomodoroRuntimeState::default();
+        restore_pomodoro_session(
+            state.database_path(),
+            &mut restored,
+            started_at + chrono::Duration::hours(2),
+        )
+        .expect("restore session");
+
+        assert_eq!(restored.phase, PomodoroRuntimePhase::Idle);
+        assert!(load_pomodoro_session(state.database_path())
+            .expect("load session")
+            .is_none());
+        let summary = ReflectionService::new(&state)
+            .get_summary(None, None, None)
+            .expect("summary");
+        assert!(summary.logs.iter().any(|log| log.block_id == "blk-current"));
+    }
+
+    #[test]
+    fn remaining_display_formats_minutes_and_hours() {
+        assert_eq!(format_remaining(1500), "25:00");
+        assert_eq!(format_remaining(59), "00:59");
+        assert_eq!(format_remaining(3725), "1:02:05");
+        let runtime = PomodoroRuntimeState {
+            remaining_seconds: 1500,
+            ..PomodoroRuntimeState::default()
+        };
+        assert_eq!(to_pomodoro_state_response(&runtime).remaining_display, "25:00");
+    }
 }
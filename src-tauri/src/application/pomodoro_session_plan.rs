@@ -8,11 +8,14 @@ pub struct PomodoroSessionPlan {
     pub total_cycles: u32,
     pub focus_seconds: u32,
     pub break_seconds: u32,
+    /// Uncounted lead-in before the first focus phase.
+    pub setup_seconds: Option<u32>,
 }
 
 pub fn build_pomodoro_session_plan(
     block: &Block,
     break_duration_minutes: u32,
+    setup_seconds: Option<u32>,
     recipes: &[Recipe],
 ) -> PomodoroSessionPlan {
     let fallback_cycles = u32::try_from(block.planned_pomodoros)
@@ -40,7 +43,9 @@ pub fn build_pomodoro_session_plan(
         .map(|pomodoro| pomodoro.cycles.max(1))
         .unwrap_or(fallback_cycles);
     let cycle_seconds = focus_seconds.saturating_add(break_seconds).max(1);
-    let block_seconds = (block.end_at - block.start_at).num_seconds().max(0) as u32;
+    let setup_seconds = setup_seconds.filter(|seconds| *seconds > 0);
+    let block_seconds = ((block.end_at - block.start_at).num_seconds().max(0) as u32)
+        .saturating_sub(setup_seconds.unwrap_or(0));
     let max_cycles_by_duration = (block_seconds / cycle_seconds).max(1);
     let total_cycles = requested_cycles.min(max_cycles_by_duration).max(1);
 
@@ -48,6 +53,7 @@ pub fn build_pomodoro_session_plan(
         total_cycles,
         focus_seconds,
         break_seconds,
+        setup_seconds,
     }
 }
 
@@ -74,6 +80,8 @@ mod tests {
             planned_pomodoros,
             source: "routine".to_string(),
             source_id: Some("rtn-1".to_string()),
+            project: None,
+            title: None,
             recipe_id: recipe_id.to_string(),
             auto_drive_mode: AutoDriveMode::Manual,
             contents: BlockContents::default(),
@@ -108,7 +116,7 @@ mod tests {
             studio_meta: None,
         }];
 
-        let plan = build_pomodoro_session_plan(&block, 5, &recipes);
+        let plan = build_pomodoro_session_plan(&block, 5, None, &recipes);
 
         assert_eq!(plan.focus_seconds, 1500);
         assert_eq!(plan.break_seconds, 300);
@@ -119,7 +127,7 @@ mod tests {
     fn session_plan_falls_back_to_block_estimate_when_recipe_missing() {
         let block = sample_block(2, "missing", "2026-02-16T10:00:00Z");
 
-        let plan = build_pomodoro_session_plan(&block, 10, &[]);
+        let plan = build_pomodoro_session_plan(&block, 10, None, &[]);
 
         assert_eq!(plan.focus_seconds, DEFAULT_POMODORO_FOCUS_SECONDS);
         assert_eq!(plan.break_seconds, 600);
@@ -0,0 +1,58 @@
+use crate::application::pomodoro_service::PomodoroRuntimeState;
+use crate::infrastructure::error::InfraError;
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::path::Path;
+
+type StoredSession = (PomodoroRuntimeState, Option<DateTime<Utc>>);
+
+/// Blocks only live in memory, so a restored session keeps the block end saved before the restart.
+pub(crate) fn save_pomodoro_session(
+    database_path: &Path,
+    session: &PomodoroRuntimeState,
+    block_end_at: Option<DateTime<Utc>>,
+) -> Result<(), InfraError> {
+    let connection = Connection::open(database_path)?;
+    connection.execute(
+        "INSERT INTO pomodoro_session (id, block_end_time, payload_json, updated_at)
+         VALUES (1, ?1, ?2, ?3)
+         ON CONFLICT(id) DO UPDATE SET
+           block_end_time = COALESCE(excluded.block_end_time, pomodoro_session.block_end_time),
+           payload_json = excluded.payload_json,
+           updated_at = excluded.updated_at",
+        params![
+            block_end_at.map(|value| value.to_rfc3339()),
+            serde_json::to_string(session)?,
+            Utc::now().to_rfc3339(),
+        ],
+    )?;
+    Ok(())
+}
+
+pub(crate) fn clear_pomodoro_session(database_path: &Path) -> Result<(), InfraError> {
+    let connection = Connection::open(database_path)?;
+    connection.execute("DELETE FROM pomodoro_session WHERE id = 1", [])?;
+    Ok(())
+}
+
+pub(crate) fn load_pomodoro_session(
+    database_path: &Path,
+) -> Result<Option<StoredSession>, InfraError> {
+    let connection = Connection::open(database_path)?;
+    let row = connection
+        .query_row(
+            "SELECT payload_json, block_end_time FROM pomodoro_session WHERE id = 1",
+            [],
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?)),
+        )
+        .optional()?;
+    let Some((payload_json, block_end_time)) = row else {
+        return Ok(None);
+    };
+    let session = serde_json::from_str(&payload_json)?;
+    let block_end_at = block_end_time
+        .as_deref()
+        .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+        .map(|value| value.with_timezone(&Utc));
+    Ok(Some((session, block_end_at)))
+}
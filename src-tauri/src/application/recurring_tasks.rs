@@ -0,0 +1,171 @@
+use crate::application::commands::AppState;
+use crate::application::configured_block_plans::rrule_matches_date;
+use crate::application::task_service::TaskService;
+use crate::application::time_slots::parse_date_input;
+use crate::domain::models::Task;
+use crate::infrastructure::error::InfraError;
+use chrono::{NaiveDate, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::fs;
+use std::path::Path;
+
+const RECURRING_TASKS_FILE_NAME: &str = "recurring_tasks.json";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurringTaskDefinition {
+    pub id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub estimated_pomodoros: Option<u32>,
+    pub rrule: String,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+}
+
+impl RecurringTaskDefinition {
+    fn is_due_on(&self, date: NaiveDate) -> bool {
+        if self.start_date.is_some_and(|start| date < start)
+            || self.end_date.is_some_and(|end| date > end)
+        {
+            return false;
+        }
+        rrule_matches_date(&self.rrule, date)
+    }
+}
+
+pub fn load_recurring_task_definitions(config_dir: &Path) -> Vec<RecurringTaskDefinition> {
+    let path = config_dir.join(RECURRING_TASKS_FILE_NAME);
+    let Ok(raw) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&raw) else {
+        return Vec::new();
+    };
+    parsed
+        .get("recurringTasks")
+        .and_then(serde_json::Value::as_array)
+        .map(|values| values.iter().filter_map(parse_definition).collect())
+        .unwrap_or_default()
+}
+
+pub fn materialize_recurring_tasks(state: &AppState, date: String) -> Result<Vec<Task>, InfraError> {
+    let date = parse_date_input(&date, "date")?;
+    let definitions = load_recurring_task_definitions(state.config_dir());
+    let mut created = Vec::new();
+
+    for definition in definitions.iter().filter(|definition| definition.is_due_on(date)) {
+        if is_materialized(state.database_path(), &definition.id, date)? {
+            continue;
+        }
+        let task = TaskService::new(state).create_task(
+            definition.title.clone(),
+            definition.description.clone(),
+            definition.estimated_pomodoros,
+        )?;
+        record_materialization(state.database_path(), &definition.id, date, &task.id)?;
+        created.push(task);
+    }
+
+    if !created.is_empty() {
+        state.log_info(
+            "materialize_recurring_tasks",
+            &format!("created_count={} date={}", created.len(), date),
+        );
+    }
+    Ok(created)
+}
+
+fn parse_definition(value: &serde_json::Value) -> Option<RecurringTaskDefinition> {
+    let object = value.as_object()?;
+    let text = |key: &str| {
+        object
+            .get(key)
+            .and_then(serde_json::Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(ToOwned::to_owned)
+    };
+    let date = |key: &str| {
+        text(key).and_then(|value| NaiveDate::parse_from_str(&value, "%Y-%m-%d").ok())
+    };
+
+    Some(RecurringTaskDefinition {
+        id: text("id")?,
+        title: text("title")?,
+        description: text("description"),
+        estimated_pomodoros: object
+            .get("estimatedPomodoros")
+            .and_then(serde_json::Value::as_u64)
+            .map(|value| value as u32),
+        rrule: text("rrule")?,
+        start_date: date("startDate"),
+        end_date: date("endDate"),
+    })
+}
+
+fn is_materialized(
+    database_path: &Path,
+    recurring_id: &str,
+    date: NaiveDate,
+) -> Result<bool, InfraError> {
+    let connection = Connection::open(database_path)?;
+    let existing = connection
+        .query_row(
+            "SELECT task_id FROM recurring_task_instances WHERE recurring_id = ?1 AND date = ?2",
+            params![recurring_id, date.to_string()],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()?;
+    Ok(existing.is_some())
+}
+
+fn record_materialization(
+    database_path: &Path,
+    recurring_id: &str,
+    date: NaiveDate,
+    task_id: &str,
+) -> Result<(), InfraError> {
+    let connection = Connection::open(database_path)?;
+    connection.execute(
+        "INSERT OR IGNORE INTO recurring_task_instances (recurring_id, date, task_id, created_at)
+         VALUES (?1, ?2, ?3, ?4)",
+        params![recurring_id, date.to_string(), task_id, Utc::now().to_rfc3339()],
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_support::config_fs::{write_json, TempConfigDir};
+
+    #[test]
+    fn load_recurring_task_definitions_skips_incomplete_entries() {
+        let config_dir = TempConfigDir::new("recurring-tasks", "parse");
+        write_json(
+            &config_dir.join(RECURRING_TASKS_FILE_NAME),
+            serde_json::json!({
+                "schema": 1,
+                "recurringTasks": [
+                    {
+                        "id": "standup-prep",
+                        "title": "Standup prep",
+                        "rrule": "FREQ=WEEKLY;BYDAY=MO,WE",
+                        "estimatedPomodoros": 1,
+                        "endDate": "2026-03-31"
+                    },
+                    { "id": "missing-rrule", "title": "No schedule" }
+                ]
+            }),
+        );
+
+        let definitions = load_recurring_task_definitions(config_dir.path());
+
+        assert_eq!(definitions.len(), 1);
+        let definition = &definitions[0];
+        assert_eq!(definition.estimated_pomodoros, Some(1));
+        assert!(definition.is_due_on(NaiveDate::from_ymd_opt(2026, 2, 16).expect("date")));
+        assert!(!definition.is_due_on(NaiveDate::from_ymd_opt(2026, 2, 17).expect("date")));
+        assert!(!definition.is_due_on(NaiveDate::from_ymd_opt(2026, 4, 6).expect("date")));
+    }
+}
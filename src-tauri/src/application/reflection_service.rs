@@ -1,10 +1,17 @@
 use crate::application::calendar_window::parse_datetime_input;
-use crate::application::commands::AppState;
-use crate::application::pomodoro_log_store::load_pomodoro_logs;
-use crate::domain::models::PomodoroPhase;
+use crate::application::commands::{lock_runtime, AppState};
+use crate::application::policy_service::load_runtime_policy;
+use crate::application::pomodoro_log_store::{
+    load_completed_focus_start_times, load_pomodoro_logs, load_pomodoro_logs_for_block,
+    load_task_focus_logs, pomodoro_phase_as_str,
+};
+use crate::domain::models::{PomodoroLog, PomodoroPhase};
 use crate::infrastructure::error::InfraError;
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use chrono_tz::Tz;
 use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap};
 
 pub struct ReflectionService<'a> {
     state: &'a AppState,
@@ -19,6 +26,22 @@ pub struct ReflectionLogItem {
     pub start_time: String,
     pub end_time: Option<String>,
     pub interruption_reason: Option<String>,
+    pub resumed_from: Option<String>,
+}
+
+impl From<PomodoroLog> for ReflectionLogItem {
+    fn from(log: PomodoroLog) -> Self {
+        Self {
+            id: log.id,
+            block_id: log.block_id,
+            task_id: log.task_id,
+            phase: pomodoro_phase_as_str(&log.phase).to_string(),
+            start_time: log.start_time.to_rfc3339(),
+            end_time: log.end_time.map(|value| value.to_rfc3339()),
+            interruption_reason: log.interruption_reason,
+            resumed_from: log.resumed_from,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -27,10 +50,36 @@ pub struct ReflectionSummaryResponse {
     pub end: String,
     pub completed_count: u32,
     pub interrupted_count: u32,
+    pub break_interrupted_count: u32,
     pub total_focus_minutes: i64,
+    pub current_focus_streak_days: u32,
+    pub longest_focus_streak_days: u32,
     pub logs: Vec<ReflectionLogItem>,
 }
 
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct BlockReflection {
+    pub block_id: String,
+    pub completed_focus: u32,
+    pub interrupted_focus: u32,
+    pub focus_minutes: i64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct LongestFocus {
+    pub minutes: i64,
+    pub block_id: String,
+    pub start_time: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct TaskInvestment {
+    pub task_id: String,
+    pub title: String,
+    pub focus_minutes: i64,
+    pub completed_pomodoros: u32,
+}
+
 impl<'a> ReflectionService<'a> {
     pub fn new(state: &'a AppState) -> Self {
         Self { state }
@@ -40,6 +89,7 @@ impl<'a> ReflectionService<'a> {
         &self,
         start: Option<String>,
         end: Option<String>,
+        log_limit: Option<usize>,
     ) -> Result<ReflectionSummaryResponse, InfraError> {
         let default_start = Utc::now() - Duration::days(7);
         let start = match start {
@@ -57,6 +107,8 @@ impl<'a> ReflectionService<'a> {
         }
 
         let logs_in_range = load_pomodoro_logs(self.state.database_path(), start, end)?;
+        let timezone = load_runtime_policy(self.state.config_dir()).timezone;
+        let now = Utc::now();
 
         let completed_count = logs_in_range
             .iter()
@@ -64,7 +116,11 @@ impl<'a> ReflectionService<'a> {
             .count() as u32;
         let interrupted_count = logs_in_range
             .iter()
-            .filter(|log| log.interruption_reason.is_some())
+            .filter(|log| log.phase == PomodoroPhase::Focus && log.interruption_reason.is_some())
+            .count() as u32;
+        let break_interrupted_count = logs_in_range
+            .iter()
+            .filter(|log| log.phase != PomodoroPhase::Focus && log.interruption_reason.is_some())
             .count() as u32;
 
         let total_focus_minutes = logs_in_range
@@ -74,23 +130,30 @@ impl<'a> ReflectionService<'a> {
             .filter(|duration_minutes| *duration_minutes > 0)
             .sum();
 
+        let range_focus_days = focus_days(
+            logs_in_range
+                .iter()
+                .filter(|log| log.phase == PomodoroPhase::Focus && log.interruption_reason.is_none())
+                .map(|log| log.start_time),
+            timezone,
+        );
+        let longest_focus_streak_days = longest_streak(&range_focus_days);
+        let all_focus_days = focus_days(
+            load_completed_focus_start_times(self.state.database_path(), now)?,
+            timezone,
+        );
+        let current_focus_streak_days =
+            streak_ending_on(&all_focus_days, now.with_timezone(&timezone).date_naive());
+
+        // Totals above cover the whole window; only the returned logs are limited.
+        let mut logs_in_range = logs_in_range;
+        logs_in_range.sort_by_key(|log| Reverse(log.start_time));
+        if let Some(log_limit) = log_limit {
+            logs_in_range.truncate(log_limit);
+        }
         let logs = logs_in_range
             .into_iter()
-            .map(|log| ReflectionLogItem {
-                id: log.id,
-                block_id: log.block_id,
-                task_id: log.task_id,
-                phase: match log.phase {
-                    PomodoroPhase::Focus => "focus",
-                    PomodoroPhase::Break => "break",
-                    PomodoroPhase::LongBreak => "long_break",
-                    PomodoroPhase::Paused => "paused",
-                }
-                .to_string(),
-                start_time: log.start_time.to_rfc3339(),
-                end_time: log.end_time.map(|value| value.to_rfc3339()),
-                interruption_reason: log.interruption_reason,
-            })
+            .map(ReflectionLogItem::from)
             .collect::<Vec<_>>();
 
         Ok(ReflectionSummaryResponse {
@@ -98,8 +161,198 @@ impl<'a> ReflectionService<'a> {
             end: end.to_rfc3339(),
             completed_count,
             interrupted_count,
+            break_interrupted_count,
             total_focus_minutes,
+            current_focus_streak_days,
+            longest_focus_streak_days,
             logs,
         })
     }
+
+    /// Longest focus log in the window that ran to completion; ties go to the earliest one.
+    pub fn get_longest_focus(
+        &self,
+        start: String,
+        end: String,
+    ) -> Result<Option<LongestFocus>, InfraError> {
+        let start = parse_datetime_input(&start, "start")?;
+        let end = parse_datetime_input(&end, "end")?;
+        if end <= start {
+            return Err(InfraError::InvalidConfig(
+                "end must be greater than start".to_string(),
+            ));
+        }
+
+        let longest = load_pomodoro_logs(self.state.database_path(), start, end)?
+            .into_iter()
+            .filter(|log| log.phase == PomodoroPhase::Focus && log.interruption_reason.is_none())
+            .filter_map(|log| {
+                let minutes = (log.end_time? - log.start_time).num_minutes();
+                (minutes > 0).then_some((minutes, log))
+            })
+            .min_by_key(|(minutes, _)| Reverse(*minutes))
+            .map(|(minutes, log)| LongestFocus {
+                minutes,
+                block_id: log.block_id,
+                start_time: log.start_time.to_rfc3339(),
+            });
+        Ok(longest)
+    }
+
+    /// Focus time per existing task over the whole log history, largest first.
+    pub fn get_task_investment(&self) -> Result<Vec<TaskInvestment>, InfraError> {
+        let logs = load_task_focus_logs(self.state.database_path())?;
+        let mut totals = HashMap::<String, (i64, u32)>::new();
+        for log in logs {
+            let Some(task_id) = log.task_id else {
+                continue;
+            };
+            let minutes = log
+                .end_time
+                .map(|end_time| (end_time - log.start_time).num_minutes())
+                .filter(|minutes| *minutes > 0)
+                .unwrap_or(0);
+            let entry = totals.entry(task_id).or_default();
+            entry.0 += minutes;
+            if log.interruption_reason.is_none() {
+                entry.1 = entry.1.saturating_add(1);
+            }
+        }
+
+        let runtime = lock_runtime(self.state)?;
+        let mut investments = totals
+            .into_iter()
+            .filter(|(_, (focus_minutes, _))| *focus_minutes > 0)
+            .filter_map(|(task_id, (focus_minutes, completed_pomodoros))| {
+                let title = runtime.tasks.get(task_id.as_str())?.title.clone();
+                Some(TaskInvestment {
+                    task_id,
+                    title,
+                    focus_minutes,
+                    completed_pomodoros,
+                })
+            })
+            .collect::<Vec<_>>();
+        investments.sort_by(|left, right| {
+            right
+                .focus_minutes
+                .cmp(&left.focus_minutes)
+                .then_with(|| left.task_id.cmp(&right.task_id))
+        });
+        Ok(investments)
+    }
+
+    pub fn get_block_reflection(&self, block_id: String) -> Result<BlockReflection, InfraError> {
+        let block_id = block_id.trim();
+        if block_id.is_empty() {
+            return Err(InfraError::InvalidConfig(
+                "block_id must not be empty".to_string(),
+            ));
+        }
+        let focus_logs = load_pomodoro_logs_for_block(self.state.database_path(), block_id)?
+            .into_iter()
+            .filter(|log| log.phase == PomodoroPhase::Focus)
+            .collect::<Vec<_>>();
+
+        Ok(BlockReflection {
+            block_id: block_id.to_string(),
+            completed_focus: focus_logs
+                .iter()
+                .filter(|log| log.interruption_reason.is_none())
+                .count() as u32,
+            interrupted_focus: focus_logs
+                .iter()
+                .filter(|log| log.interruption_reason.is_some())
+                .count() as u32,
+            focus_minutes: focus_logs
+                .iter()
+                .filter_map(|log| {
+                    log.end_time
+                        .map(|end_time| (end_time - log.start_time).num_minutes())
+                })
+                .filter(|duration_minutes| *duration_minutes > 0)
+                .sum(),
+        })
+    }
+}
+
+fn focus_days(
+    start_times: impl IntoIterator<Item = DateTime<Utc>>,
+    timezone: Tz,
+) -> BTreeSet<NaiveDate> {
+    start_times
+        .into_iter()
+        .map(|start_time| start_time.with_timezone(&timezone).date_naive())
+        .collect()
+}
+
+fn streak_ending_on(days: &BTreeSet<NaiveDate>, last_day: NaiveDate) -> u32 {
+    let mut streak = 0;
+    let mut day = last_day;
+    while days.contains(&day) {
+        streak += 1;
+        let Some(previous) = day.pred_opt() else {
+            break;
+        };
+        day = previous;
+    }
+    streak
+}
+
+fn longest_streak(days: &BTreeSet<NaiveDate>) -> u32 {
+    let mut longest = 0;
+    let mut current = 0;
+    let mut previous: Option<NaiveDate> = None;
+    for day in days {
+        current = match previous {
+            Some(previous) if previous.succ_opt() == Some(*day) => current + 1,
+            _ => 1,
+        };
+        longest = longest.max(current);
+        previous = Some(*day);
+    }
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").expect("date")
+    }
+
+    #[test]
+    fn streaks_count_consecutive_days() {
+        let days = ["2026-02-14", "2026-02-15", "2026-02-16"]
+            .into_iter()
+            .map(day)
+            .collect::<BTreeSet<_>>();
+
+        assert_eq!(streak_ending_on(&days, day("2026-02-16")), 3);
+        assert_eq!(longest_streak(&days), 3);
+    }
+
+    #[test]
+    fn streaks_reset_after_a_gap() {
+        let days = ["2026-02-10", "2026-02-11", "2026-02-12", "2026-02-15", "2026-02-16"]
+            .into_iter()
+            .map(day)
+            .collect::<BTreeSet<_>>();
+
+        assert_eq!(streak_ending_on(&days, day("2026-02-16")), 2);
+        assert_eq!(streak_ending_on(&days, day("2026-02-17")), 0);
+        assert_eq!(longest_streak(&days), 3);
+    }
+
+    #[test]
+    fn focus_days_use_policy_timezone() {
+        let start_time = DateTime::parse_from_rfc3339("2026-02-15T20:00:00Z")
+            .expect("datetime")
+            .with_timezone(&Utc);
+
+        let days = focus_days([start_time], chrono_tz::Asia::Tokyo);
+
+        assert!(days.contains(&day("2026-02-16")));
+    }
 }
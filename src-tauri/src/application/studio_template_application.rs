@@ -13,11 +13,11 @@ use crate::application::id_factory::next_id;
 use crate::application::policy_service::load_runtime_policy;
 use crate::application::time_slots::{
     event_to_interval, free_slots, intervals_overlap, local_datetime_to_utc, merge_intervals,
-    Interval,
+    parse_date_input, Interval,
 };
 use crate::domain::models::{Block, BlockContents, Firmness};
 use crate::infrastructure::error::InfraError;
-use chrono::{Duration, NaiveDate, NaiveTime};
+use chrono::{Duration, NaiveTime};
 use serde::Serialize;
 
 #[derive(Debug, Clone, Serialize)]
@@ -51,9 +51,8 @@ pub async fn apply_studio_template_to_today(
         InfraError::InvalidConfig(format!("trigger_time must be HH:MM: {error}"))
     })?;
     let policy = load_runtime_policy(state.config_dir());
-    let account_id = normalize_account_id(account_id);
-    let date = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
-        .map_err(|error| InfraError::InvalidConfig(format!("date must be YYYY-MM-DD: {error}")))?;
+    let account_id = normalize_account_id(state.config_dir(), account_id);
+    let date = parse_date_input(&date, "date")?;
     let resolved_conflict_policy = conflict_policy
         .as_deref()
         .map(str::trim)
@@ -175,6 +174,8 @@ pub async fn apply_studio_template_to_today(
             planned_pomodoros: planned_pomodoros(duration_minutes, policy.break_duration_minutes),
             source: "routine_studio".to_string(),
             source_id: Some(template_id.to_string()),
+            project: None,
+            title: None,
             recipe_id: template.id.clone(),
             auto_drive_mode: template.auto_drive_mode.clone(),
             contents: BlockContents::default(),
@@ -183,7 +184,7 @@ pub async fn apply_studio_template_to_today(
         calendar_account_id: Some(account_id.clone()),
     }];
 
-    let access_token = try_access_token(Some(account_id.clone())).await?;
+    let access_token = try_access_token(&account_id).await?;
     let calendar_id = resolve_cached_blocks_calendar_id(
         state,
         access_token.as_deref(),
@@ -191,14 +192,23 @@ pub async fn apply_studio_template_to_today(
         &mut blocks_calendar_ids,
     )
     .await?;
+    let mut event_creation = Ok(());
     if let (Some(token), Some(calendar_id)) = (access_token.as_deref(), calendar_id.as_deref()) {
-        let sync_service = std::sync::Arc::new(build_reqwest_calendar_sync_service(state));
-        create_calendar_events_for_generated_blocks(sync_service, token, calendar_id, &mut generated)
-            .await?;
+        let sync_service =
+            std::sync::Arc::new(build_reqwest_calendar_sync_service(state, &account_id));
+        event_creation = create_calendar_events_for_generated_blocks(
+            sync_service,
+            token,
+            calendar_id,
+            &policy,
+            &mut generated,
+        )
+        .await;
     }
 
     let created = generated.remove(0);
     persist_generated_block(state, &account_id, &blocks_calendar_ids, created.clone())?;
+    event_creation?;
 
     state.log_info(
         "apply_studio_template_to_today",
@@ -1,6 +1,7 @@
 use crate::application::commands::RuntimeState;
 use crate::domain::models::TaskStatus;
 use crate::infrastructure::error::InfraError;
+use crate::infrastructure::event_mapper::{decode_block_event, GoogleCalendarEvent};
 
 pub(crate) fn parse_task_status(value: &str) -> Result<TaskStatus, InfraError> {
     match value.trim().to_ascii_lowercase().as_str() {
@@ -46,3 +47,30 @@ pub(crate) fn unassign_task(runtime: &mut RuntimeState, task_id: &str) -> Option
         .remove(previous_block_id.as_str());
     Some(previous_block_id)
 }
+
+/// Re-links tasks recorded on block events and takes each block's title from its event
+/// summary, so renames made in the calendar survive. Returns the restored assignments.
+pub(crate) fn restore_task_assignments_from_events(
+    runtime: &mut RuntimeState,
+    events: &[GoogleCalendarEvent],
+) -> usize {
+    let mut restored = 0usize;
+    for decoded in events.iter().filter_map(decode_block_event) {
+        let Some(stored) = runtime.blocks.get_mut(decoded.block_id.as_str()) else {
+            continue;
+        };
+        stored.block.title = decoded.title;
+        let Some(task_id) = decoded.task_id else {
+            continue;
+        };
+        if !runtime.tasks.contains_key(task_id.as_str()) {
+            continue;
+        }
+        if runtime.task_assignments_by_block.get(decoded.block_id.as_str()) == Some(&task_id) {
+            continue;
+        }
+        assign_task_to_block(runtime, &task_id, &decoded.block_id);
+        restored = restored.saturating_add(1);
+    }
+    restored
+}
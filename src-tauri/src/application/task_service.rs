@@ -1,12 +1,17 @@
 use crate::application::audit_log::append_audit_log;
-use crate::application::commands::{lock_runtime, AppState};
+use crate::application::block_calendar_events::suggested_pomodoros;
+use crate::application::block_generation::BREAK_BLOCK_SOURCE;
+use crate::application::commands::{lock_runtime, normalize_account_id, AppState, RuntimeState};
 use crate::application::id_factory::next_id;
+use crate::application::policy_service::load_runtime_policy;
+use crate::application::recurring_tasks;
 use crate::application::task_runtime::{
     assign_task_to_block, parse_task_status, task_status_as_str, unassign_task,
 };
-use crate::domain::models::Task;
+use crate::application::time_slots::parse_date_input;
+use crate::domain::models::{Block, Task};
 use crate::infrastructure::error::InfraError;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 use std::collections::HashSet;
 
@@ -52,6 +57,7 @@ impl<'a> TaskService<'a> {
             completed_pomodoros: 0,
             status: crate::domain::models::TaskStatus::Pending,
             created_at: Utc::now(),
+            preferred_block_type: None,
         };
 
         {
@@ -65,17 +71,86 @@ impl<'a> TaskService<'a> {
         Ok(task)
     }
 
-    pub fn list_tasks(&self) -> Result<Vec<Task>, InfraError> {
+    pub fn create_tasks_bulk(
+        &self,
+        lines: String,
+        default_estimate: Option<u32>,
+    ) -> Result<Vec<Task>, InfraError> {
+        let created_at = Utc::now();
+        let tasks = lines
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|title| Task {
+                id: next_id("tsk"),
+                title: title.to_string(),
+                description: None,
+                estimated_pomodoros: default_estimate,
+                completed_pomodoros: 0,
+                status: crate::domain::models::TaskStatus::Pending,
+                created_at,
+                preferred_block_type: None,
+            })
+            .collect::<Vec<_>>();
+
+        {
+            let mut runtime = lock_runtime(self.state)?;
+            for task in &tasks {
+                runtime.task_order.push(task.id.clone());
+                runtime.tasks.insert(task.id.clone(), task.clone());
+            }
+        }
+
+        self.state.log_info(
+            "create_tasks_bulk",
+            &format!("created task_count={}", tasks.len()),
+        );
+        Ok(tasks)
+    }
+
+    pub fn materialize_recurring_tasks(&self, date: String) -> Result<Vec<Task>, InfraError> {
+        recurring_tasks::materialize_recurring_tasks(self.state, date)
+    }
+
+    /// `query` keeps tasks whose title or description contains it, ignoring case.
+    pub fn list_tasks(&self, query: Option<String>) -> Result<Vec<Task>, InfraError> {
+        let query = query
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_lowercase);
         let runtime = lock_runtime(self.state)?;
         let mut tasks = runtime
             .task_order
             .iter()
-            .filter_map(|task_id| runtime.tasks.get(task_id).cloned())
+            .filter_map(|task_id| runtime.tasks.get(task_id))
+            .filter(|task| {
+                query.as_deref().is_none_or(|query| {
+                    task.title.to_lowercase().contains(query)
+                        || task
+                            .description
+                            .as_deref()
+                            .is_some_and(|description| description.to_lowercase().contains(query))
+                })
+            })
+            .cloned()
             .collect::<Vec<_>>();
         tasks.sort_by(|left, right| left.created_at.cmp(&right.created_at));
         Ok(tasks)
     }
 
+    pub fn list_unassigned_tasks(&self) -> Result<Vec<Task>, InfraError> {
+        let runtime = lock_runtime(self.state)?;
+        Ok(runtime
+            .task_order
+            .iter()
+            .filter(|task_id| !runtime.task_assignments_by_task.contains_key(task_id.as_str()))
+            .filter_map(|task_id| runtime.tasks.get(task_id))
+            .filter(|task| task.status != crate::domain::models::TaskStatus::Completed)
+            .cloned()
+            .collect())
+    }
+
     pub fn update_task(
         &self,
         task_id: String,
@@ -83,6 +158,7 @@ impl<'a> TaskService<'a> {
         description: Option<String>,
         estimated_pomodoros: Option<u32>,
         status: Option<String>,
+        preferred_block_type: Option<String>,
     ) -> Result<Task, InfraError> {
         let task_id = task_id.trim();
         if task_id.is_empty() {
@@ -92,7 +168,7 @@ impl<'a> TaskService<'a> {
         }
 
         let mut runtime = lock_runtime(self.state)?;
-        let Some(task) = runtime.tasks.get_mut(task_id) else {
+        let Some(mut task) = runtime.tasks.get(task_id).cloned() else {
             return Err(InfraError::InvalidConfig(format!("task not found: {}", task_id)));
         };
 
@@ -123,11 +199,18 @@ impl<'a> TaskService<'a> {
             task.status = parse_task_status(&status)?;
         }
 
-        let updated = task.clone();
+        if let Some(preferred_block_type) = preferred_block_type {
+            let preferred_block_type = preferred_block_type.trim();
+            task.preferred_block_type =
+                (!preferred_block_type.is_empty()).then(|| preferred_block_type.to_string());
+        }
+
+        task.validate().map_err(InfraError::InvalidConfig)?;
+        runtime.tasks.insert(task_id.to_string(), task.clone());
         drop(runtime);
         self.state
             .log_info("update_task", &format!("updated task_id={task_id}"));
-        Ok(updated)
+        Ok(task)
     }
 
     pub fn delete_task(&self, task_id: String) -> Result<bool, InfraError> {
@@ -154,6 +237,43 @@ impl<'a> TaskService<'a> {
         Ok(true)
     }
 
+    /// `up`/`down` swap with the neighbouring task and are no-ops at the ends of the order.
+    pub fn move_task(&self, task_id: String, position: String) -> Result<Vec<Task>, InfraError> {
+        let task_id = task_id.trim();
+        let mut runtime = lock_runtime(self.state)?;
+        let Some(index) = runtime
+            .task_order
+            .iter()
+            .position(|candidate| candidate == task_id)
+        else {
+            return Err(InfraError::InvalidConfig(format!("task not found: {}", task_id)));
+        };
+        let last = runtime.task_order.len() - 1;
+        let target = match position.trim().to_ascii_lowercase().as_str() {
+            "top" => 0,
+            "bottom" => last,
+            "up" => index.saturating_sub(1),
+            "down" => (index + 1).min(last),
+            other => {
+                return Err(InfraError::InvalidConfig(format!(
+                    "position must be top, bottom, up or down: {other}"
+                )));
+            }
+        };
+        let moved = runtime.task_order.remove(index);
+        runtime.task_order.insert(target, moved);
+
+        self.state.log_info(
+            "move_task",
+            &format!("moved task_id={task_id} from={index} to={target}"),
+        );
+        Ok(runtime
+            .task_order
+            .iter()
+            .filter_map(|task_id| runtime.tasks.get(task_id).cloned())
+            .collect())
+    }
+
     pub fn split_task(&self, task_id: String, parts: u32) -> Result<Vec<Task>, InfraError> {
         let task_id = task_id.trim();
         if task_id.is_empty() {
@@ -171,6 +291,7 @@ impl<'a> TaskService<'a> {
         };
         let parent_title = parent.title.clone();
         let parent_description = parent.description.clone();
+        let parent_preferred_block_type = parent.preferred_block_type.clone();
         let child_estimated_pomodoros = parent
             .estimated_pomodoros
             .map(|value| value.div_ceil(parts).max(1));
@@ -192,6 +313,7 @@ impl<'a> TaskService<'a> {
                 completed_pomodoros: 0,
                 status: crate::domain::models::TaskStatus::Pending,
                 created_at: now,
+                preferred_block_type: parent_preferred_block_type.clone(),
             };
             runtime.task_order.push(child.id.clone());
             runtime.tasks.insert(child.id.clone(), child.clone());
@@ -214,6 +336,51 @@ impl<'a> TaskService<'a> {
         Ok(children)
     }
 
+    pub fn begin_task(&self, task_id: String, now: DateTime<Utc>) -> Result<Task, InfraError> {
+        let task_id = task_id.trim();
+        if task_id.is_empty() {
+            return Err(InfraError::InvalidConfig(
+                "task_id must not be empty".to_string(),
+            ));
+        }
+
+        let mut runtime = lock_runtime(self.state)?;
+        if !runtime.tasks.contains_key(task_id) {
+            return Err(InfraError::InvalidConfig(format!("task not found: {}", task_id)));
+        }
+        let current_block_id = runtime
+            .blocks
+            .values()
+            .filter(|stored| stored.block.source != BREAK_BLOCK_SOURCE)
+            .filter(|stored| stored.block.start_at <= now && now < stored.block.end_at)
+            .min_by(|left, right| {
+                left.block
+                    .start_at
+                    .cmp(&right.block.start_at)
+                    .then_with(|| left.block.id.cmp(&right.block.id))
+            })
+            .map(|stored| stored.block.id.clone());
+        if let Some(block_id) = current_block_id.as_deref() {
+            assign_task_to_block(&mut runtime, task_id, block_id);
+        }
+        let Some(task) = runtime.tasks.get_mut(task_id) else {
+            return Err(InfraError::InvalidConfig(format!("task not found: {}", task_id)));
+        };
+        task.status = crate::domain::models::TaskStatus::InProgress;
+        let task = task.clone();
+        drop(runtime);
+
+        self.state.log_info(
+            "begin_task",
+            &format!(
+                "began task_id={} block_id={}",
+                task.id,
+                current_block_id.as_deref().unwrap_or("none")
+            ),
+        );
+        Ok(task)
+    }
+
     pub fn carry_over_task(
         &self,
         task_id: String,
@@ -251,22 +418,11 @@ impl<'a> TaskService<'a> {
             )));
         };
 
-        let mut candidates = runtime
-            .blocks
-            .values()
-            .map(|stored| stored.block.clone())
-            .filter(|block| block.id != from_block.id)
-            .filter(|block| block.date == from_block.date)
-            .filter(|block| block.start_at >= from_block.end_at)
-            .filter(|block| {
+        let next_block = carry_over_candidates(&runtime, &from_block)
+            .into_iter()
+            .find(|block| {
                 normalized_candidates.is_empty() || normalized_candidates.contains(block.id.as_str())
             })
-            .collect::<Vec<_>>();
-        candidates.sort_by(|left, right| left.start_at.cmp(&right.start_at));
-
-        let next_block = candidates
-            .into_iter()
-            .find(|block| !runtime.task_assignments_by_block.contains_key(block.id.as_str()))
             .ok_or_else(|| InfraError::InvalidConfig("no available block for carry-over".to_string()))?;
 
         assign_task_to_block(&mut runtime, task_id, next_block.id.as_str());
@@ -305,17 +461,170 @@ impl<'a> TaskService<'a> {
         );
         Ok(response)
     }
+
+    pub fn carry_over_candidates(&self, from_block_id: String) -> Result<Vec<Block>, InfraError> {
+        let from_block_id = from_block_id.trim();
+        if from_block_id.is_empty() {
+            return Err(InfraError::InvalidConfig(
+                "from_block_id must not be empty".to_string(),
+            ));
+        }
+        let runtime = lock_runtime(self.state)?;
+        let Some(from_block) = runtime.blocks.get(from_block_id).map(|stored| stored.block.clone()) else {
+            return Err(InfraError::InvalidConfig(format!(
+                "block not found: {}",
+                from_block_id
+            )));
+        };
+        Ok(carry_over_candidates(&runtime, &from_block))
+    }
+
+    pub fn suggest_pomodoros(&self, estimated_minutes: u32) -> Result<u32, InfraError> {
+        if estimated_minutes == 0 {
+            return Err(InfraError::InvalidConfig(
+                "estimated_minutes must be greater than 0".to_string(),
+            ));
+        }
+        let policy = load_runtime_policy(self.state.config_dir());
+        Ok(suggested_pomodoros(estimated_minutes, policy.break_duration_minutes))
+    }
+
+    pub fn get_estimated_completion(
+        &self,
+        task_id: String,
+        account_id: Option<String>,
+        now: DateTime<Utc>,
+    ) -> Result<Option<String>, InfraError> {
+        let task_id = task_id.trim();
+        if task_id.is_empty() {
+            return Err(InfraError::InvalidConfig(
+                "task_id must not be empty".to_string(),
+            ));
+        }
+        let default_account_id = normalize_account_id(self.state.config_dir(), None);
+        let account_id = normalize_account_id(self.state.config_dir(), account_id);
+
+        let runtime = lock_runtime(self.state)?;
+        let Some(task) = runtime.tasks.get(task_id) else {
+            return Err(InfraError::InvalidConfig(format!("task not found: {}", task_id)));
+        };
+        let Some(remaining) = task
+            .estimated_pomodoros
+            .map(|estimated| estimated.saturating_sub(task.completed_pomodoros))
+            .filter(|remaining| *remaining > 0)
+        else {
+            return Ok(None);
+        };
+
+        let mut upcoming = runtime
+            .blocks
+            .values()
+            .filter(|stored| {
+                stored.calendar_account_id.as_deref().unwrap_or(&default_account_id) == account_id
+            })
+            .filter(|stored| stored.block.start_at >= now)
+            .filter(|stored| stored.block.source != BREAK_BLOCK_SOURCE)
+            .filter(|stored| {
+                runtime
+                    .task_assignments_by_block
+                    .get(stored.block.id.as_str())
+                    .is_none_or(|assigned| assigned == task_id)
+            })
+            .map(|stored| &stored.block)
+            .collect::<Vec<_>>();
+        upcoming.sort_by_key(|block| block.start_at);
+
+        let mut capacity = 0u32;
+        for block in upcoming {
+            capacity = capacity.saturating_add(block.planned_pomodoros.max(0) as u32);
+            if capacity >= remaining {
+                return Ok(Some(block.end_at.to_rfc3339()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Earliest free block on `date` big enough for the task's remaining pomodoros. Blocks
+    /// whose title or source id matches the task's preferred block type win over earlier ones.
+    pub fn suggest_block_for_task(
+        &self,
+        task_id: String,
+        date: String,
+        account_id: Option<String>,
+    ) -> Result<Option<Block>, InfraError> {
+        let task_id = task_id.trim();
+        if task_id.is_empty() {
+            return Err(InfraError::InvalidConfig(
+                "task_id must not be empty".to_string(),
+            ));
+        }
+        let date = parse_date_input(&date, "date")?;
+        let date = date.to_string();
+        let default_account_id = normalize_account_id(self.state.config_dir(), None);
+        let account_id = normalize_account_id(self.state.config_dir(), account_id);
+
+        let runtime = lock_runtime(self.state)?;
+        let Some(task) = runtime.tasks.get(task_id) else {
+            return Err(InfraError::InvalidConfig(format!("task not found: {}", task_id)));
+        };
+        let needed_pomodoros = task
+            .estimated_pomodoros
+            .map(|estimated| estimated.saturating_sub(task.completed_pomodoros))
+            .unwrap_or(0)
+            .max(1);
+        let matches_type = |block: &Block| {
+            task.preferred_block_type.as_deref().is_some_and(|block_type| {
+                [Some(block.recipe_id.as_str()), block.source_id.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .any(|value| value.trim().eq_ignore_ascii_case(block_type))
+            })
+        };
+
+        let mut candidates = runtime
+            .blocks
+            .values()
+            .filter(|stored| {
+                stored.calendar_account_id.as_deref().unwrap_or(&default_account_id) == account_id
+            })
+            .map(|stored| &stored.block)
+            .filter(|block| block.date == date)
+            .filter(|block| block.source != BREAK_BLOCK_SOURCE)
+            .filter(|block| block.planned_pomodoros.max(0) as u32 >= needed_pomodoros)
+            .filter(|block| !runtime.task_assignments_by_block.contains_key(block.id.as_str()))
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|block| (!matches_type(block), block.start_at));
+        Ok(candidates.first().map(|block| (*block).clone()))
+    }
+}
+
+/// Later focus blocks on the same day that have no task yet, earliest first.
+fn carry_over_candidates(runtime: &RuntimeState, from_block: &Block) -> Vec<Block> {
+    let mut candidates = runtime
+        .blocks
+        .values()
+        .map(|stored| &stored.block)
+        .filter(|block| block.id != from_block.id)
+        .filter(|block| block.source != BREAK_BLOCK_SOURCE)
+        .filter(|block| block.date == from_block.date)
+        .filter(|block| block.start_at >= from_block.end_at)
+        .filter(|block| !runtime.task_assignments_by_block.contains_key(block.id.as_str()))
+        .cloned()
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|block| block.start_at);
+    candidates
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::application::block_service::BlockService;
-    use crate::application::commands::lock_runtime;
+    use crate::application::commands::{lock_runtime, StoredBlock};
     use crate::application::pomodoro_service::PomodoroService;
+    use crate::application::test_support::config_fs::allow_out_of_date_pomodoro_start;
     use crate::application::test_support::db_assertions::load_audit_logs;
     use crate::application::test_support::workspace::TempWorkspace;
-    use crate::domain::models::TaskStatus;
+    use crate::domain::models::{AutoDriveMode, Block, BlockContents, Firmness, TaskStatus};
 
     #[tokio::test]
     async fn property_19_20_task_assignment_links_task_to_block_and_records_history_audit() {
@@ -332,6 +641,7 @@ mod tests {
             .generate_blocks("2026-02-16".to_string(), None)
             .await
             .expect("generate blocks");
+        allow_out_of_date_pomodoro_start(state.config_dir());
         let started = pomodoro_service
             .start_pomodoro(blocks[0].id.clone(), Some(task.id.clone()))
             .expect("start pomodoro with task");
@@ -372,6 +682,7 @@ mod tests {
             .expect("generate blocks");
         blocks.sort_by(|left, right| left.start_at.cmp(&right.start_at));
 
+        allow_out_of_date_pomodoro_start(state.config_dir());
         let _ = pomodoro_service
             .start_pomodoro(blocks[0].id.clone(), Some(task.id.clone()))
             .expect("start pomodoro with task");
@@ -401,6 +712,67 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn estimated_completion_projects_into_block_covering_remaining_pomodoros() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        let task_service = TaskService::new(&state);
+        let task = task_service
+            .create_task("Estimate task".to_string(), None, Some(4))
+            .expect("create task");
+        let now = DateTime::parse_from_rfc3339("2026-02-16T08:00:00Z")
+            .expect("now")
+            .with_timezone(&Utc);
+        {
+            let mut runtime = lock_runtime(&state).expect("runtime lock");
+            runtime
+                .tasks
+                .get_mut(task.id.as_str())
+                .expect("stored task")
+                .completed_pomodoros = 1;
+            for (id, start_hour) in [("blk-past", 7), ("blk-first", 9), ("blk-second", 11)] {
+                let start_at = now.date_naive().and_hms_opt(start_hour, 0, 0).expect("time").and_utc();
+                runtime.blocks.insert(
+                    id.to_string(),
+                    StoredBlock {
+                        block: Block {
+                            id: id.to_string(),
+                            instance: format!("test:{id}"),
+                            date: "2026-02-16".to_string(),
+                            start_at,
+                            end_at: start_at + chrono::Duration::minutes(50),
+                            firmness: Firmness::Draft,
+                            planned_pomodoros: 2,
+                            source: "routine".to_string(),
+                            source_id: None,
+                            project: None,
+                            title: None,
+                            recipe_id: "rcp-default".to_string(),
+                            auto_drive_mode: AutoDriveMode::Manual,
+                            contents: BlockContents::default(),
+                        },
+                        calendar_event_id: None,
+                        calendar_account_id: None,
+                    },
+                );
+            }
+        }
+
+        let estimate = task_service
+            .get_estimated_completion(task.id.clone(), None, now)
+            .expect("estimate");
+        assert_eq!(estimate.as_deref(), Some("2026-02-16T11:50:00+00:00"));
+
+        lock_runtime(&state)
+            .expect("runtime lock")
+            .blocks
+            .remove("blk-second");
+        let insufficient = task_service
+            .get_estimated_completion(task.id, None, now)
+            .expect("estimate");
+        assert_eq!(insufficient, None);
+    }
+
     #[test]
     fn property_25_26_split_creates_children_and_records_history() {
         let workspace = TempWorkspace::new();
@@ -413,7 +785,7 @@ mod tests {
         let children = task_service
             .split_task(parent.id.clone(), 4)
             .expect("split task");
-        let listed = task_service.list_tasks().expect("list tasks");
+        let listed = task_service.list_tasks(None).expect("list tasks");
         let audit_logs = load_audit_logs(state.database_path(), 100).expect("load audit logs");
 
         assert_eq!(children.len(), 4);
@@ -47,3 +47,23 @@ pub(crate) fn write_json(path: &Path, value: serde_json::Value) {
     fs::write(path, format!("{}\n", serde_json::to_string_pretty(&value).expect("json")))
         .expect("write json");
 }
+
+/// Lets sessions start on the fixed-date blocks most tests generate.
+pub(crate) fn allow_out_of_date_pomodoro_start(config_dir: &Path) {
+    let path = config_dir.join("policies.json");
+    let mut policies = fs::read_to_string(&path)
+        .ok()
+        .and_then(|raw| serde_json::from_str::<serde_json::Value>(&raw).ok())
+        .filter(serde_json::Value::is_object)
+        .unwrap_or_else(|| serde_json::json!({ "schema": 1 }));
+    let pomodoro = policies
+        .as_object_mut()
+        .expect("policies object")
+        .entry("pomodoro")
+        .or_insert_with(|| serde_json::json!({}));
+    if !pomodoro.is_object() {
+        *pomodoro = serde_json::json!({});
+    }
+    pomodoro["allowOutOfDateStart"] = serde_json::Value::Bool(true);
+    write_json(&path, policies);
+}
@@ -1,6 +1,6 @@
 use crate::infrastructure::error::InfraError;
 use crate::infrastructure::event_mapper::GoogleCalendarEvent;
-use chrono::{DateTime, LocalResult, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono::{DateTime, Duration, LocalResult, NaiveDate, NaiveTime, Offset, TimeZone, Utc};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Interval {
@@ -30,6 +30,12 @@ pub fn intervals_overlap(left: &Interval, right: &Interval) -> bool {
     left.start < right.end && right.start < left.end
 }
 
+pub fn parse_date_input(value: &str, field_name: &str) -> Result<NaiveDate, InfraError> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").map_err(|error| {
+        InfraError::InvalidConfig(format!("{field_name} must be YYYY-MM-DD: {error}"))
+    })
+}
+
 pub fn parse_rfc3339_input(value: &str, field_name: &str) -> Result<DateTime<Utc>, InfraError> {
     DateTime::parse_from_rfc3339(value)
         .map(|value| value.with_timezone(&Utc))
@@ -105,6 +111,28 @@ pub fn free_slots(
     slots
 }
 
+/// Moves `value` forward to the next multiple of `minutes` on the local clock of `timezone`.
+pub fn round_up_to_minutes(
+    value: DateTime<Utc>,
+    minutes: u32,
+    timezone: chrono_tz::Tz,
+) -> DateTime<Utc> {
+    let step_seconds = i64::from(minutes.max(1)) * 60;
+    let offset_seconds = i64::from(
+        value
+            .with_timezone(&timezone)
+            .offset()
+            .fix()
+            .local_minus_utc(),
+    );
+    let truncated = value - Duration::nanoseconds(i64::from(value.timestamp_subsec_nanos()));
+    let remainder = (truncated.timestamp() + offset_seconds).rem_euclid(step_seconds);
+    if remainder == 0 && truncated == value {
+        return value;
+    }
+    truncated + Duration::seconds(step_seconds - remainder)
+}
+
 pub fn merge_intervals(mut intervals: Vec<Interval>) -> Vec<Interval> {
     if intervals.is_empty() {
         return intervals;
@@ -179,6 +207,7 @@ mod tests {
                 time_zone: None,
             },
             extended_properties: None,
+            ..Default::default()
         };
 
         assert!(event_to_interval(&event).is_none());
@@ -240,6 +240,10 @@ pub struct Block {
     pub planned_pomodoros: i32,
     pub source: String,
     pub source_id: Option<String>,
+    #[serde(default)]
+    pub project: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
     #[serde(default = "default_recipe_id")]
     pub recipe_id: String,
     #[serde(default)]
@@ -266,6 +270,26 @@ pub struct Task {
     pub completed_pomodoros: u32,
     pub status: TaskStatus,
     pub created_at: DateTime<Utc>,
+    /// Block title or source id this task would rather run in, e.g. "Deep".
+    #[serde(default)]
+    pub preferred_block_type: Option<String>,
+}
+
+impl Task {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_non_empty(&self.id, "task.id")?;
+        validate_non_empty(&self.title, "task.title")?;
+        if let Some(estimated) = self
+            .estimated_pomodoros
+            .filter(|estimated| self.completed_pomodoros > *estimated)
+        {
+            return Err(format!(
+                "task.estimated_pomodoros ({estimated}) must not be below completed_pomodoros ({})",
+                self.completed_pomodoros
+            ));
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -286,6 +310,8 @@ pub struct PomodoroLog {
     pub start_time: DateTime<Utc>,
     pub end_time: Option<DateTime<Utc>>,
     pub interruption_reason: Option<String>,
+    #[serde(default)]
+    pub resumed_from: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -295,6 +321,9 @@ pub struct OAuthToken {
     pub expires_at: DateTime<Utc>,
     pub token_type: String,
     pub scope: Option<String>,
+    /// When the account last completed a sign-in; refreshes keep the previous value.
+    #[serde(default)]
+    pub authenticated_at: Option<DateTime<Utc>>,
 }
 
 impl OAuthToken {
@@ -336,6 +365,8 @@ mod tests {
             planned_pomodoros: 2,
             source: "template".to_string(),
             source_id: Some("tpl-deep-1".to_string()),
+            project: None,
+            title: None,
             recipe_id: "rcp-deep-default".to_string(),
             auto_drive_mode: AutoDriveMode::Manual,
             contents: BlockContents::default(),
@@ -351,6 +382,7 @@ mod tests {
             completed_pomodoros: 1,
             status: TaskStatus::InProgress,
             created_at: fixed_time("2026-02-16T08:00:00Z"),
+            preferred_block_type: None,
         }
     }
 
@@ -363,6 +395,7 @@ mod tests {
             start_time: fixed_time("2026-02-16T09:00:00Z"),
             end_time: Some(fixed_time("2026-02-16T09:25:00Z")),
             interruption_reason: None,
+            resumed_from: None,
         }
     }
 
@@ -1,12 +1,17 @@
 use crate::infrastructure::error::InfraError;
 use crate::infrastructure::event_mapper::GoogleCalendarEvent;
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
 pub trait CalendarCacheRepository: Send + Sync {
     fn get_by_id(&self, event_id: &str) -> Result<Option<GoogleCalendarEvent>, InfraError>;
     fn upsert(&self, event: &GoogleCalendarEvent) -> Result<(), InfraError>;
     fn remove(&self, event_id: &str) -> Result<(), InfraError>;
+    fn list(&self) -> Result<Vec<GoogleCalendarEvent>, InfraError>;
+    fn clear(&self) -> Result<(), InfraError>;
 }
 
 #[derive(Debug, Default)]
@@ -62,4 +67,270 @@ impl CalendarCacheRepository for InMemoryCalendarCacheRepository {
         events.remove(&event_id);
         Ok(())
     }
+
+    fn list(&self) -> Result<Vec<GoogleCalendarEvent>, InfraError> {
+        let events = self
+            .events
+            .lock()
+            .map_err(|error| InfraError::InvalidConfig(format!("calendar cache lock poisoned: {error}")))?;
+        Ok(events.values().cloned().collect())
+    }
+
+    fn clear(&self) -> Result<(), InfraError> {
+        let mut events = self
+            .events
+            .lock()
+            .map_err(|error| InfraError::InvalidConfig(format!("calendar cache lock poisoned: {error}")))?;
+        events.clear();
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CachedAccountEvents {
+    pub calendar_id: Option<String>,
+    pub events: Vec<GoogleCalendarEvent>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteCalendarCacheRepository {
+    db_path: PathBuf,
+    account_id: String,
+}
+
+impl SqliteCalendarCacheRepository {
+    pub fn new(db_path: impl AsRef<Path>, account_id: impl Into<String>) -> Self {
+        Self {
+            db_path: db_path.as_ref().to_path_buf(),
+            account_id: account_id.into(),
+        }
+    }
+
+    pub fn replace_all(
+        &self,
+        events: &[GoogleCalendarEvent],
+        calendar_id: Option<&str>,
+    ) -> Result<(), InfraError> {
+        let mut connection = self.connect()?;
+        let transaction = connection.transaction()?;
+        transaction.execute(
+            "DELETE FROM calendar_event_cache WHERE account_id = ?1",
+            params![self.account_id],
+        )?;
+        let updated_at = Utc::now().to_rfc3339();
+        for event in events {
+            let Some(event_id) = event.id.as_deref().and_then(InMemoryCalendarCacheRepository::normalized_id)
+            else {
+                continue;
+            };
+            transaction.execute(
+                "INSERT OR REPLACE INTO calendar_event_cache
+                   (account_id, event_id, calendar_id, payload_json, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    self.account_id,
+                    event_id,
+                    calendar_id,
+                    serialize_event(event)?,
+                    updated_at
+                ],
+            )?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    pub fn load_all_accounts(
+        db_path: impl AsRef<Path>,
+    ) -> Result<HashMap<String, CachedAccountEvents>, InfraError> {
+        let connection = Connection::open(db_path.as_ref())?;
+        let mut statement = connection.prepare(
+            "SELECT account_id, calendar_id, payload_json
+             FROM calendar_event_cache
+             ORDER BY account_id ASC, rowid ASC",
+        )?;
+        let mut rows = statement.query([])?;
+        let mut accounts = HashMap::<String, CachedAccountEvents>::new();
+        while let Some(row) = rows.next()? {
+            let account_id: String = row.get(0)?;
+            let calendar_id: Option<String> = row.get(1)?;
+            let event = deserialize_event(&row.get::<_, String>(2)?)?;
+            let cached = accounts.entry(account_id).or_default();
+            if cached.calendar_id.is_none() {
+                cached.calendar_id = calendar_id;
+            }
+            cached.events.push(event);
+        }
+        Ok(accounts)
+    }
+
+    fn connect(&self) -> Result<Connection, InfraError> {
+        Connection::open(&self.db_path).map_err(InfraError::from)
+    }
+}
+
+impl CalendarCacheRepository for SqliteCalendarCacheRepository {
+    fn get_by_id(&self, event_id: &str) -> Result<Option<GoogleCalendarEvent>, InfraError> {
+        let Some(event_id) = InMemoryCalendarCacheRepository::normalized_id(event_id) else {
+            return Ok(None);
+        };
+        let connection = self.connect()?;
+        let payload: Option<String> = connection
+            .query_row(
+                "SELECT payload_json FROM calendar_event_cache WHERE account_id = ?1 AND event_id = ?2",
+                params![self.account_id, event_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        payload.as_deref().map(deserialize_event).transpose()
+    }
+
+    fn upsert(&self, event: &GoogleCalendarEvent) -> Result<(), InfraError> {
+        let event_id = event
+            .id
+            .as_deref()
+            .and_then(InMemoryCalendarCacheRepository::normalized_id)
+            .ok_or_else(|| InfraError::InvalidConfig("event id is required for cache upsert".to_string()))?;
+        let connection = self.connect()?;
+        connection.execute(
+            "INSERT INTO calendar_event_cache (account_id, event_id, calendar_id, payload_json, updated_at)
+             VALUES (?1, ?2, NULL, ?3, ?4)
+             ON CONFLICT(account_id, event_id) DO UPDATE SET
+               payload_json = excluded.payload_json,
+               updated_at = excluded.updated_at",
+            params![
+                self.account_id,
+                event_id,
+                serialize_event(event)?,
+                Utc::now().to_rfc3339()
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn remove(&self, event_id: &str) -> Result<(), InfraError> {
+        let Some(event_id) = InMemoryCalendarCacheRepository::normalized_id(event_id) else {
+            return Ok(());
+        };
+        let connection = self.connect()?;
+        connection.execute(
+            "DELETE FROM calendar_event_cache WHERE account_id = ?1 AND event_id = ?2",
+            params![self.account_id, event_id],
+        )?;
+        Ok(())
+    }
+
+    fn list(&self) -> Result<Vec<GoogleCalendarEvent>, InfraError> {
+        let connection = self.connect()?;
+        let mut statement = connection.prepare(
+            "SELECT payload_json FROM calendar_event_cache WHERE account_id = ?1 ORDER BY rowid ASC",
+        )?;
+        let mut rows = statement.query(params![self.account_id])?;
+        let mut events = Vec::new();
+        while let Some(row) = rows.next()? {
+            events.push(deserialize_event(&row.get::<_, String>(0)?)?);
+        }
+        Ok(events)
+    }
+
+    fn clear(&self) -> Result<(), InfraError> {
+        let connection = self.connect()?;
+        connection.execute(
+            "DELETE FROM calendar_event_cache WHERE account_id = ?1",
+            params![self.account_id],
+        )?;
+        Ok(())
+    }
+}
+
+fn serialize_event(event: &GoogleCalendarEvent) -> Result<String, InfraError> {
+    serde_json::to_string(event)
+        .map_err(|error| InfraError::InvalidConfig(format!("failed to encode cached event: {error}")))
+}
+
+fn deserialize_event(payload: &str) -> Result<GoogleCalendarEvent, InfraError> {
+    serde_json::from_str(payload)
+        .map_err(|error| InfraError::InvalidConfig(format!("invalid cached event payload: {error}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_support::workspace::TempWorkspace;
+    use crate::infrastructure::event_mapper::CalendarEventDateTime;
+
+    fn sample_event(id: &str, summary: &str) -> GoogleCalendarEvent {
+        GoogleCalendarEvent {
+            id: Some(id.to_string()),
+            summary: Some(summary.to_string()),
+            description: None,
+            status: Some("confirmed".to_string()),
+            updated: None,
+            etag: None,
+            start: CalendarEventDateTime {
+                date_time: "2026-02-16T09:00:00Z".to_string(),
+                time_zone: None,
+            },
+            end: CalendarEventDateTime {
+                date_time: "2026-02-16T10:00:00Z".to_string(),
+                time_zone: None,
+            },
+            extended_properties: None,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn sqlite_cache_roundtrips_upsert_get_and_remove() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        let repository = SqliteCalendarCacheRepository::new(state.database_path(), "default");
+        let other_account = SqliteCalendarCacheRepository::new(state.database_path(), "work");
+
+        repository
+            .upsert(&sample_event("evt-1", "Planning"))
+            .expect("upsert event");
+        repository
+            .upsert(&sample_event("evt-1", "Planning (moved)"))
+            .expect("upsert updated event");
+
+        let stored = repository.get_by_id("evt-1").expect("get event");
+        assert_eq!(
+            stored.and_then(|event| event.summary),
+            Some("Planning (moved)".to_string())
+        );
+        assert_eq!(other_account.get_by_id("evt-1").expect("get other"), None);
+
+        repository.remove("evt-1").expect("remove event");
+        assert_eq!(repository.get_by_id("evt-1").expect("get removed"), None);
+        assert!(repository.list().expect("list").is_empty());
+    }
+
+    #[test]
+    fn sqlite_cache_replace_all_is_visible_to_fresh_loads() {
+        let workspace = TempWorkspace::new();
+        let state = workspace.app_state();
+        let repository = SqliteCalendarCacheRepository::new(state.database_path(), "default");
+        repository
+            .upsert(&sample_event("evt-stale", "Stale"))
+            .expect("seed stale event");
+
+        repository
+            .replace_all(
+                &[sample_event("evt-a", "A"), sample_event("evt-b", "B")],
+                Some("blocks-calendar"),
+            )
+            .expect("replace all");
+
+        let loaded = SqliteCalendarCacheRepository::load_all_accounts(state.database_path())
+            .expect("load all accounts");
+        let cached = loaded.get("default").expect("default account");
+        assert_eq!(cached.calendar_id.as_deref(), Some("blocks-calendar"));
+        let ids = cached
+            .events
+            .iter()
+            .filter_map(|event| event.id.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec!["evt-a".to_string(), "evt-b".to_string()]);
+    }
 }
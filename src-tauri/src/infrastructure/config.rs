@@ -278,6 +278,25 @@ pub fn read_timezone(config_dir: &Path) -> Result<Option<String>, InfraError> {
         .map(ToOwned::to_owned))
 }
 
+pub fn read_default_account_id(config_dir: &Path) -> Result<Option<String>, InfraError> {
+    let app = read_config(&config_dir.join(APP_JSON))?;
+    Ok(app
+        .get("defaultAccountId")
+        .and_then(serde_json::Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(ToOwned::to_owned))
+}
+
+pub fn read_sso_timeout_seconds(config_dir: &Path) -> Result<Option<u64>, InfraError> {
+    let app = read_config(&config_dir.join(APP_JSON))?;
+    Ok(app
+        .get("oauth")
+        .and_then(|oauth| oauth.get("ssoTimeoutSeconds"))
+        .and_then(serde_json::Value::as_u64)
+        .filter(|value| *value > 0))
+}
+
 pub fn save_blocks_calendar_id(
     config_dir: &Path,
     account_id: &str,
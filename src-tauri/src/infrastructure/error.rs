@@ -14,6 +14,8 @@ pub enum InfraError {
     Credential(String),
     #[error("OAuth error: {0}")]
     OAuth(String),
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
     #[error("Sync token expired")]
     SyncTokenExpired,
 }
@@ -10,13 +10,16 @@ const KEY_SOURCE_ID: &str = "bs_source_id";
 const KEY_PLANNED_POMODOROS: &str = "bs_planned_pomodoros";
 const KEY_RECIPE_ID: &str = "bs_recipe_id";
 const KEY_AUTO_DRIVE_MODE: &str = "bs_auto_drive_mode";
+const KEY_TASK_ID: &str = "bs_task_id";
+const KEY_PROJECT: &str = "bs_project";
 const KEY_VERSION: &str = "bs_v";
 const KEY_APP: &str = "bs_app";
 const KEY_KIND: &str = "bs_kind";
+const DEFAULT_BLOCK_SUMMARY: &str = "[PomoBlock] Work Block";
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, Default)]
 pub struct CalendarEventDateTime {
-    #[serde(rename = "dateTime")]
+    #[serde(rename = "dateTime", default)]
     pub date_time: String,
     #[serde(rename = "timeZone", skip_serializing_if = "Option::is_none")]
     pub time_zone: Option<String>,
@@ -28,7 +31,7 @@ pub struct CalendarEventExtendedProperties {
     pub private: HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, Default)]
 pub struct GoogleCalendarEvent {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
@@ -42,13 +45,73 @@ pub struct GoogleCalendarEvent {
     pub updated: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub etag: Option<String>,
+    #[serde(default)]
     pub start: CalendarEventDateTime,
+    #[serde(default)]
     pub end: CalendarEventDateTime,
     #[serde(rename = "extendedProperties", skip_serializing_if = "Option::is_none")]
     pub extended_properties: Option<CalendarEventExtendedProperties>,
+    #[serde(rename = "recurringEventId", skip_serializing_if = "Option::is_none")]
+    pub recurring_event_id: Option<String>,
+    #[serde(rename = "originalStartTime", skip_serializing_if = "Option::is_none")]
+    pub original_start_time: Option<CalendarEventDateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reminders: Option<CalendarEventReminders>,
+    #[serde(rename = "colorId", default, skip_serializing_if = "Option::is_none")]
+    pub color_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attendees: Vec<CalendarEventAttendee>,
+}
+
+impl GoogleCalendarEvent {
+    /// Response of the attendee marked `self`, when the user was invited to the event.
+    pub fn self_response_status(&self) -> Option<&str> {
+        self.attendees
+            .iter()
+            .find(|attendee| attendee.is_self)
+            .and_then(|attendee| attendee.response_status.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, Default)]
+pub struct CalendarEventAttendee {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(rename = "self", default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_self: bool,
+    #[serde(rename = "responseStatus", default, skip_serializing_if = "Option::is_none")]
+    pub response_status: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, Default)]
+pub struct CalendarEventReminders {
+    #[serde(rename = "useDefault", default)]
+    pub use_default: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overrides: Vec<CalendarEventReminderOverride>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct CalendarEventReminderOverride {
+    pub method: String,
+    pub minutes: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedBlockEvent {
+    pub block_id: String,
+    pub instance: Option<String>,
+    pub task_id: Option<String>,
+    /// Event summary, unless it is the generic label written for untitled blocks.
+    pub title: Option<String>,
 }
 
+#[cfg(any(test, feature = "debug-tools"))]
 pub fn encode_block_event(block: &Block) -> GoogleCalendarEvent {
+    encode_block_event_with_task(block, None)
+}
+
+pub fn encode_block_event_with_task(block: &Block, task_id: Option<&str>) -> GoogleCalendarEvent {
     let mut private = HashMap::new();
     private.insert(KEY_BLOCK_ID.to_string(), block.id.clone());
     private.insert(KEY_INSTANCE.to_string(), block.instance.clone());
@@ -74,10 +137,29 @@ pub fn encode_block_event(block: &Block) -> GoogleCalendarEvent {
     {
         private.insert(KEY_SOURCE_ID.to_string(), source_id.to_string());
     }
+    if let Some(project) = block
+        .project
+        .as_deref()
+        .map(str::trim)
+        .filter(|project| !project.is_empty())
+    {
+        private.insert(KEY_PROJECT.to_string(), project.to_string());
+    }
+    if let Some(task_id) = task_id.map(str::trim).filter(|id| !id.is_empty()) {
+        private.insert(KEY_TASK_ID.to_string(), task_id.to_string());
+    }
 
     GoogleCalendarEvent {
         id: None,
-        summary: Some("[PomoBlock] Work Block".to_string()),
+        summary: Some(
+            block
+                .title
+                .as_deref()
+                .map(str::trim)
+                .filter(|title| !title.is_empty())
+                .unwrap_or(DEFAULT_BLOCK_SUMMARY)
+                .to_string(),
+        ),
         description: Some(format!(
             "instance: {}, firmness: {}",
             block.instance,
@@ -95,7 +177,96 @@ pub fn encode_block_event(block: &Block) -> GoogleCalendarEvent {
             time_zone: None,
         },
         extended_properties: Some(CalendarEventExtendedProperties { private }),
+        recurring_event_id: None,
+        original_start_time: None,
+        reminders: None,
+        color_id: None,
+        attendees: Vec::new(),
+    }
+}
+
+pub fn decode_block_event(event: &GoogleCalendarEvent) -> Option<DecodedBlockEvent> {
+    let private = &event.extended_properties.as_ref()?.private;
+    let value = |key: &str| {
+        private
+            .get(key)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+            .map(ToOwned::to_owned)
+    };
+    if value(KEY_KIND).is_some_and(|kind| kind != "block") {
+        return None;
     }
+
+    Some(DecodedBlockEvent {
+        block_id: value(KEY_BLOCK_ID)?,
+        instance: value(KEY_INSTANCE),
+        task_id: value(KEY_TASK_ID),
+        title: event
+            .summary
+            .as_deref()
+            .map(str::trim)
+            .filter(|summary| !summary.is_empty() && *summary != DEFAULT_BLOCK_SUMMARY)
+            .map(ToOwned::to_owned),
+    })
+}
+
+/// Popup reminder for a block event; `None` leaves the calendar's default reminders.
+pub fn popup_reminders(minutes: Option<u32>) -> Option<CalendarEventReminders> {
+    minutes.map(|minutes| CalendarEventReminders {
+        use_default: false,
+        overrides: vec![CalendarEventReminderOverride {
+            method: "popup".to_string(),
+            minutes,
+        }],
+    })
+}
+
+/// Rebuilds a block from its managed metadata. Block contents are never
+/// written to the calendar, so they always decode as empty.
+#[cfg(any(test, feature = "debug-tools"))]
+pub fn decode_block(event: &GoogleCalendarEvent) -> Option<Block> {
+    use crate::domain::models::BlockContents;
+    use chrono::{DateTime, Utc};
+
+    let decoded = decode_block_event(event)?;
+    let private = &event.extended_properties.as_ref()?.private;
+    let value = |key: &str| {
+        private
+            .get(key)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    };
+    let parse_time = |value: &str| {
+        DateTime::parse_from_rfc3339(value.trim())
+            .ok()
+            .map(|value| value.with_timezone(&Utc))
+    };
+
+    Some(Block {
+        id: decoded.block_id,
+        instance: decoded.instance?,
+        date: value(KEY_DATE)?.to_string(),
+        start_at: parse_time(&event.start.date_time)?,
+        end_at: parse_time(&event.end.date_time)?,
+        firmness: value(KEY_FIRMNESS)
+            .and_then(firmness_from_string)
+            .unwrap_or(Firmness::Draft),
+        planned_pomodoros: value(KEY_PLANNED_POMODOROS)
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(0),
+        source: value(KEY_SOURCE).unwrap_or_default().to_string(),
+        source_id: value(KEY_SOURCE_ID).map(ToOwned::to_owned),
+        project: value(KEY_PROJECT).map(ToOwned::to_owned),
+        title: decoded.title,
+        recipe_id: value(KEY_RECIPE_ID)
+            .unwrap_or("rcp-default")
+            .to_string(),
+        auto_drive_mode: value(KEY_AUTO_DRIVE_MODE)
+            .and_then(auto_drive_mode_from_string)
+            .unwrap_or_default(),
+        contents: BlockContents::default(),
+    })
 }
 
 fn firmness_to_string(value: &Firmness) -> &'static str {
@@ -114,6 +285,26 @@ fn auto_drive_mode_to_string(value: &AutoDriveMode) -> &'static str {
     }
 }
 
+#[cfg(any(test, feature = "debug-tools"))]
+fn firmness_from_string(value: &str) -> Option<Firmness> {
+    match value {
+        "draft" => Some(Firmness::Draft),
+        "soft" => Some(Firmness::Soft),
+        "hard" => Some(Firmness::Hard),
+        _ => None,
+    }
+}
+
+#[cfg(any(test, feature = "debug-tools"))]
+fn auto_drive_mode_from_string(value: &str) -> Option<AutoDriveMode> {
+    match value {
+        "manual" => Some(AutoDriveMode::Manual),
+        "auto" => Some(AutoDriveMode::Auto),
+        "auto-silent" => Some(AutoDriveMode::AutoSilent),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,6 +326,8 @@ mod tests {
             planned_pomodoros: 2,
             source: "routine".to_string(),
             source_id: Some("routine-abc".to_string()),
+            project: None,
+            title: None,
             recipe_id: "rcp-deep-default".to_string(),
             auto_drive_mode: AutoDriveMode::Manual,
             contents: BlockContents::default(),
@@ -179,4 +372,119 @@ mod tests {
         assert_eq!(private.get(KEY_APP).map(String::as_str), Some("blocksched"));
         assert_eq!(private.get(KEY_KIND).map(String::as_str), Some("block"));
     }
+
+    #[test]
+    fn encode_writes_task_id_only_when_assigned() {
+        let block = sample_block();
+
+        let unassigned = encode_block_event(&block)
+            .extended_properties
+            .expect("extended properties")
+            .private;
+        let assigned = encode_block_event_with_task(&block, Some("task-042"))
+            .extended_properties
+            .expect("extended properties")
+            .private;
+
+        assert!(!unassigned.contains_key(KEY_TASK_ID));
+        assert_eq!(assigned.get(KEY_TASK_ID).map(String::as_str), Some("task-042"));
+    }
+
+    #[test]
+    fn decode_round_trips_block_and_task_ids() {
+        let block = sample_block();
+
+        let decoded = decode_block_event(&encode_block_event_with_task(&block, Some("task-042")))
+            .expect("decoded block event");
+        assert_eq!(decoded.block_id, block.id);
+        assert_eq!(decoded.instance.as_deref(), Some(block.instance.as_str()));
+        assert_eq!(decoded.task_id.as_deref(), Some("task-042"));
+
+        let decoded = decode_block_event(&encode_block_event(&block)).expect("decoded block event");
+        assert_eq!(decoded.task_id, None);
+    }
+
+    #[test]
+    fn decode_ignores_events_without_block_metadata() {
+        let mut event = encode_block_event(&sample_block());
+        event.extended_properties = None;
+
+        assert_eq!(decode_block_event(&event), None);
+    }
+
+    #[test]
+    fn decode_block_round_trips_everything_but_contents() {
+        let mut block = sample_block();
+        block.firmness = Firmness::Hard;
+        block.auto_drive_mode = AutoDriveMode::AutoSilent;
+        block.contents.memo = Some("not stored on the calendar".to_string());
+
+        let decoded = decode_block(&encode_block_event(&block)).expect("decoded block");
+
+        assert_eq!(
+            decoded,
+            Block {
+                contents: BlockContents::default(),
+                ..block
+            }
+        );
+    }
+
+    #[test]
+    fn project_round_trips_through_extended_properties() {
+        let mut block = sample_block();
+        let untagged = encode_block_event(&block)
+            .extended_properties
+            .expect("extended properties")
+            .private;
+        assert!(!untagged.contains_key(KEY_PROJECT));
+
+        block.project = Some("Project X".to_string());
+        let encoded = encode_block_event(&block);
+        assert_eq!(
+            encoded
+                .extended_properties
+                .as_ref()
+                .and_then(|properties| properties.private.get(KEY_PROJECT))
+                .map(String::as_str),
+            Some("Project X")
+        );
+        let decoded = decode_block(&encoded).expect("decoded block");
+        assert_eq!(decoded.project.as_deref(), Some("Project X"));
+    }
+
+    #[test]
+    fn decode_preserves_task_specific_summary_as_title() {
+        let mut event = encode_block_event(&sample_block());
+        assert_eq!(decode_block_event(&event).expect("decoded").title, None);
+
+        event.summary = Some("Write quarterly report".to_string());
+        assert_eq!(
+            decode_block_event(&event).expect("decoded").title.as_deref(),
+            Some("Write quarterly report")
+        );
+        let decoded = decode_block(&event).expect("decoded block");
+        assert_eq!(decoded.title.as_deref(), Some("Write quarterly report"));
+        assert_eq!(
+            encode_block_event(&decoded).summary.as_deref(),
+            Some("Write quarterly report")
+        );
+    }
+
+    #[test]
+    fn popup_reminders_serialize_only_when_configured() {
+        let mut event = encode_block_event(&sample_block());
+        let unset = serde_json::to_value(&event).expect("serialize event");
+        assert!(unset.get("reminders").is_none());
+
+        event.reminders = popup_reminders(Some(10));
+        let configured = serde_json::to_value(&event).expect("serialize event");
+        assert_eq!(
+            configured["reminders"],
+            serde_json::json!({
+                "useDefault": false,
+                "overrides": [{ "method": "popup", "minutes": 10 }]
+            })
+        );
+    }
 }
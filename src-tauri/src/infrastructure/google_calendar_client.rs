@@ -13,6 +13,15 @@ const CALENDAR_API_BASE: &str = "https://www.googleapis.com/calendar/v3/";
 pub struct GoogleCalendarSummary {
     pub id: String,
     pub summary: String,
+    pub access_role: Option<String>,
+}
+
+impl GoogleCalendarSummary {
+    pub fn is_writable(&self) -> bool {
+        self.access_role
+            .as_deref()
+            .is_none_or(|role| role == "owner" || role == "writer")
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -20,12 +29,14 @@ pub struct ListEventsRequest {
     pub time_min: Option<DateTime<Utc>>,
     pub time_max: Option<DateTime<Utc>>,
     pub sync_token: Option<String>,
+    pub page_token: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct ListEventsResponse {
     pub events: Vec<GoogleCalendarEvent>,
     pub next_sync_token: Option<String>,
+    pub next_page_token: Option<String>,
 }
 
 #[async_trait]
@@ -42,6 +53,12 @@ pub trait GoogleCalendarClient: Send + Sync {
         time_zone: Option<&str>,
     ) -> Result<GoogleCalendarSummary, InfraError>;
 
+    async fn get_calendar_timezone(
+        &self,
+        access_token: &str,
+        calendar_id: &str,
+    ) -> Result<Option<String>, InfraError>;
+
     async fn list_events(
         &self,
         access_token: &str,
@@ -49,6 +66,14 @@ pub trait GoogleCalendarClient: Send + Sync {
         request: ListEventsRequest,
     ) -> Result<ListEventsResponse, InfraError>;
 
+    #[cfg(any(test, feature = "debug-tools"))]
+    async fn get_event(
+        &self,
+        access_token: &str,
+        calendar_id: &str,
+        event_id: &str,
+    ) -> Result<Option<GoogleCalendarEvent>, InfraError>;
+
     async fn create_event(
         &self,
         access_token: &str,
@@ -97,10 +122,13 @@ impl ReqwestGoogleCalendarClient {
         } else {
             format!("google calendar api error: http {}; body={body}", status.as_u16())
         };
+        if status == reqwest::StatusCode::UNAUTHORIZED {
+            return InfraError::Unauthorized(message);
+        }
         InfraError::OAuth(message)
     }
 
-    fn events_endpoint(calendar_id: &str) -> Result<Url, InfraError> {
+    fn calendar_endpoint(calendar_id: &str) -> Result<Url, InfraError> {
         let mut url = Url::parse(CALENDAR_API_BASE)
             .map_err(|error| InfraError::OAuth(format!("invalid calendar api base url: {error}")))?;
         {
@@ -109,6 +137,16 @@ impl ReqwestGoogleCalendarClient {
             })?;
             segments.push("calendars");
             segments.push(calendar_id);
+        }
+        Ok(url)
+    }
+
+    fn events_endpoint(calendar_id: &str) -> Result<Url, InfraError> {
+        let mut url = Self::calendar_endpoint(calendar_id)?;
+        {
+            let mut segments = url.path_segments_mut().map_err(|_| {
+                InfraError::OAuth("calendar URL cannot be a base".to_string())
+            })?;
             segments.push("events");
         }
         Ok(url)
@@ -135,6 +173,8 @@ struct CalendarListResponse {
 struct CalendarListItem {
     id: String,
     summary: Option<String>,
+    #[serde(rename = "accessRole")]
+    access_role: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -148,6 +188,8 @@ struct CreateCalendarRequest<'a> {
 struct CalendarResourceResponse {
     id: Option<String>,
     summary: Option<String>,
+    #[serde(rename = "timeZone")]
+    time_zone: Option<String>,
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -207,6 +249,10 @@ impl GoogleCalendarClient for ReqwestGoogleCalendarClient {
                 Some(GoogleCalendarSummary {
                     id: id.to_string(),
                     summary,
+                    access_role: item
+                        .access_role
+                        .map(|role| role.trim().to_string())
+                        .filter(|role| !role.is_empty()),
                 })
             })
             .collect())
@@ -264,9 +310,46 @@ impl GoogleCalendarClient for ReqwestGoogleCalendarClient {
         Ok(GoogleCalendarSummary {
             id,
             summary: created_summary,
+            access_role: Some("owner".to_string()),
         })
     }
 
+    async fn get_calendar_timezone(
+        &self,
+        access_token: &str,
+        calendar_id: &str,
+    ) -> Result<Option<String>, InfraError> {
+        Self::ensure_non_empty(access_token, "access token")?;
+        Self::ensure_non_empty(calendar_id, "calendar id")?;
+
+        let endpoint = Self::calendar_endpoint(calendar_id)?;
+        let response = self
+            .client
+            .get(endpoint)
+            .bearer_auth(access_token)
+            .send()
+            .await
+            .map_err(|error| InfraError::OAuth(format!("network error while reading calendar: {error}")))?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|error| InfraError::OAuth(format!("failed reading calendar response: {error}")))?;
+
+        if !status.is_success() {
+            return Err(Self::oauth_http_error(status, &body));
+        }
+
+        let parsed: CalendarResourceResponse = serde_json::from_str(&body).map_err(|error| {
+            InfraError::OAuth(format!("invalid calendar payload: {error}; body={body}"))
+        })?;
+        Ok(parsed
+            .time_zone
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty()))
+    }
+
     async fn list_events(
         &self,
         access_token: &str,
@@ -277,68 +360,90 @@ impl GoogleCalendarClient for ReqwestGoogleCalendarClient {
         Self::ensure_non_empty(calendar_id, "calendar id")?;
 
         let endpoint = Self::events_endpoint(calendar_id)?;
-        let mut page_token: Option<String> = None;
-        let mut next_sync_token: Option<String> = None;
-        let mut events = Vec::new();
-        let sync_token = request.sync_token.clone();
-
-        loop {
-            let mut req = self.client.get(endpoint.clone()).bearer_auth(access_token);
-            req = req.query(&[("showDeleted", "true"), ("maxResults", "2500")]);
-
-            if let Some(sync_token) = sync_token.as_deref() {
-                req = req.query(&[("syncToken", sync_token)]);
-            } else {
-                if let Some(time_min) = request.time_min {
-                    req = req.query(&[("timeMin", time_min.to_rfc3339())]);
-                }
-                if let Some(time_max) = request.time_max {
-                    req = req.query(&[("timeMax", time_max.to_rfc3339())]);
-                }
-            }
-
-            if let Some(page_token) = page_token.as_deref() {
-                req = req.query(&[("pageToken", page_token)]);
-            }
-
-            let response = req.send().await.map_err(|error| {
-                InfraError::OAuth(format!("network error while listing calendar events: {error}"))
-            })?;
-
-            let status = response.status();
-            let body = response.text().await.map_err(|error| {
-                InfraError::OAuth(format!("failed reading events list response: {error}"))
-            })?;
+        let mut req = self.client.get(endpoint).bearer_auth(access_token);
+        req = req.query(&[("showDeleted", "true"), ("maxResults", "2500")]);
 
-            if status == reqwest::StatusCode::GONE {
-                return Err(InfraError::SyncTokenExpired);
+        if let Some(sync_token) = request.sync_token.as_deref() {
+            req = req.query(&[("syncToken", sync_token)]);
+        } else {
+            if let Some(time_min) = request.time_min {
+                req = req.query(&[("timeMin", time_min.to_rfc3339())]);
             }
-            if !status.is_success() {
-                return Err(Self::oauth_http_error(status, &body));
+            if let Some(time_max) = request.time_max {
+                req = req.query(&[("timeMax", time_max.to_rfc3339())]);
             }
+        }
 
-            let mut parsed: EventsPageResponse = serde_json::from_str(&body).map_err(|error| {
-                InfraError::OAuth(format!("invalid events list payload: {error}; body={body}"))
-            })?;
+        if let Some(page_token) = request.page_token.as_deref() {
+            req = req.query(&[("pageToken", page_token)]);
+        }
 
-            events.extend(parsed.items.take().unwrap_or_default());
-            if parsed.next_sync_token.is_some() {
-                next_sync_token = parsed.next_sync_token.take();
-            }
+        let response = req.send().await.map_err(|error| {
+            InfraError::OAuth(format!("network error while listing calendar events: {error}"))
+        })?;
 
-            if let Some(next_page_token) = parsed.next_page_token.take() {
-                page_token = Some(next_page_token);
-                continue;
-            }
-            break;
+        let status = response.status();
+        let body = response.text().await.map_err(|error| {
+            InfraError::OAuth(format!("failed reading events list response: {error}"))
+        })?;
+
+        if status == reqwest::StatusCode::GONE {
+            return Err(InfraError::SyncTokenExpired);
+        }
+        if !status.is_success() {
+            return Err(Self::oauth_http_error(status, &body));
         }
 
+        let parsed: EventsPageResponse = serde_json::from_str(&body).map_err(|error| {
+            InfraError::OAuth(format!("invalid events list payload: {error}; body={body}"))
+        })?;
+
         Ok(ListEventsResponse {
-            events,
-            next_sync_token,
+            events: parsed.items.unwrap_or_default(),
+            next_sync_token: parsed.next_sync_token,
+            next_page_token: parsed.next_page_token,
         })
     }
 
+    #[cfg(any(test, feature = "debug-tools"))]
+    async fn get_event(
+        &self,
+        access_token: &str,
+        calendar_id: &str,
+        event_id: &str,
+    ) -> Result<Option<GoogleCalendarEvent>, InfraError> {
+        Self::ensure_non_empty(access_token, "access token")?;
+        Self::ensure_non_empty(calendar_id, "calendar id")?;
+        Self::ensure_non_empty(event_id, "event id")?;
+
+        let endpoint = Self::event_endpoint(calendar_id, event_id)?;
+        let response = self
+            .client
+            .get(endpoint)
+            .bearer_auth(access_token)
+            .send()
+            .await
+            .map_err(|error| InfraError::OAuth(format!("network error while reading event: {error}")))?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|error| InfraError::OAuth(format!("failed reading event response: {error}")))?;
+
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            return Err(Self::oauth_http_error(status, &body));
+        }
+
+        let parsed: GoogleCalendarEvent = serde_json::from_str(&body).map_err(|error| {
+            InfraError::OAuth(format!("invalid event payload: {error}; body={body}"))
+        })?;
+        Ok(Some(parsed))
+    }
+
     async fn create_event(
         &self,
         access_token: &str,
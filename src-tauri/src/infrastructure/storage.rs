@@ -7,5 +7,27 @@ const SCHEMA_SQL: &str = include_str!("../../sql/schema.sql");
 pub fn initialize_database(path: &Path) -> Result<(), InfraError> {
     let connection = Connection::open(path)?;
     connection.execute_batch(SCHEMA_SQL)?;
+    ensure_column(&connection, "suppressions", "expires_at", "TEXT")?;
+    ensure_column(&connection, "pomodoro_logs", "resumed_from", "TEXT")?;
+    Ok(())
+}
+
+fn ensure_column(
+    connection: &Connection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> Result<(), InfraError> {
+    let mut statement = connection.prepare(&format!("PRAGMA table_info({table})"))?;
+    let mut rows = statement.query([])?;
+    while let Some(row) = rows.next()? {
+        let name: String = row.get(1)?;
+        if name == column {
+            return Ok(());
+        }
+    }
+    drop(rows);
+    drop(statement);
+    connection.execute_batch(&format!("ALTER TABLE {table} ADD COLUMN {column} {definition}"))?;
     Ok(())
 }
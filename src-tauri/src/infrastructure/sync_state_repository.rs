@@ -14,17 +14,20 @@ pub struct SyncState {
 pub trait SyncStateRepository: Send + Sync {
     fn load(&self) -> Result<Option<SyncState>, InfraError>;
     fn save(&self, sync_token: Option<&str>, last_sync_time: DateTime<Utc>) -> Result<(), InfraError>;
+    fn clear(&self) -> Result<(), InfraError>;
 }
 
 #[derive(Debug, Clone)]
 pub struct SqliteSyncStateRepository {
     db_path: PathBuf,
+    account_id: String,
 }
 
 impl SqliteSyncStateRepository {
-    pub fn new(db_path: impl AsRef<Path>) -> Self {
+    pub fn new(db_path: impl AsRef<Path>, account_id: impl Into<String>) -> Self {
         Self {
             db_path: db_path.as_ref().to_path_buf(),
+            account_id: account_id.into(),
         }
     }
 
@@ -38,8 +41,8 @@ impl SyncStateRepository for SqliteSyncStateRepository {
         let connection = self.connect()?;
         let row: Option<(Option<String>, String)> = connection
             .query_row(
-                "SELECT sync_token, last_sync_time FROM sync_state WHERE id = 1",
-                [],
+                "SELECT sync_token, last_sync_time FROM account_sync_state WHERE account_id = ?1",
+                params![self.account_id],
                 |row| Ok((row.get(0)?, row.get(1)?)),
             )
             .optional()?;
@@ -50,7 +53,7 @@ impl SyncStateRepository for SqliteSyncStateRepository {
 
         let parsed = DateTime::parse_from_rfc3339(&last_sync_time_raw).map_err(|error| {
             InfraError::InvalidConfig(format!(
-                "invalid sync_state.last_sync_time '{}': {error}",
+                "invalid account_sync_state.last_sync_time '{}': {error}",
                 last_sync_time_raw
             ))
         })?;
@@ -64,12 +67,21 @@ impl SyncStateRepository for SqliteSyncStateRepository {
     fn save(&self, sync_token: Option<&str>, last_sync_time: DateTime<Utc>) -> Result<(), InfraError> {
         let connection = self.connect()?;
         connection.execute(
-            "INSERT INTO sync_state (id, sync_token, last_sync_time)
-             VALUES (1, ?1, ?2)
-             ON CONFLICT(id) DO UPDATE SET
+            "INSERT INTO account_sync_state (account_id, sync_token, last_sync_time)
+             VALUES (?1, ?2, ?3)
+             ON CONFLICT(account_id) DO UPDATE SET
                sync_token = excluded.sync_token,
                last_sync_time = excluded.last_sync_time",
-            params![sync_token, last_sync_time.to_rfc3339()],
+            params![self.account_id, sync_token, last_sync_time.to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    fn clear(&self) -> Result<(), InfraError> {
+        let connection = self.connect()?;
+        connection.execute(
+            "DELETE FROM account_sync_state WHERE account_id = ?1",
+            params![self.account_id],
         )?;
         Ok(())
     }
@@ -102,4 +114,13 @@ impl SyncStateRepository for InMemorySyncStateRepository {
         });
         Ok(())
     }
+
+    fn clear(&self) -> Result<(), InfraError> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|error| InfraError::InvalidConfig(format!("sync state lock poisoned: {error}")))?;
+        *state = None;
+        Ok(())
+    }
 }
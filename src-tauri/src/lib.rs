@@ -2,31 +2,67 @@ mod application;
 mod domain;
 mod infrastructure;
 
+use application::auto_generation::run_auto_generation;
 use application::bootstrap::bootstrap_workspace;
 use application::commands::{
-    adjust_block_time_impl, advance_pomodoro_impl, approve_blocks_impl, authenticate_google_impl,
-    authenticate_google_sso_impl, carry_over_task_impl, complete_pomodoro_impl,
+    add_holiday_impl, adjust_block_time_impl, advance_pomodoro_impl, approve_blocks_impl,
+    authenticate_google_impl,
+    authenticate_google_sso_impl, begin_task_impl, build_authorization_url_impl, cancel_sso_impl,
+    carry_over_candidates_impl, carry_over_task_impl, cleanup_orphaned_events_impl,
+    clear_day_impl, complete_pomodoro_impl,
     create_module_folder_impl, create_module_impl, create_recipe_impl, create_task_impl,
+    create_tasks_bulk_impl,
     delete_block_impl, delete_module_folder_impl, delete_module_impl, delete_recipe_impl,
-    delete_routine_schedule_impl, delete_task_impl, generate_blocks_impl, generate_one_block_impl,
-    generate_today_blocks_impl, get_pomodoro_state_impl, get_reflection_summary_impl,
-    interrupt_timer_impl, list_blocks_impl, list_module_folders_impl, list_modules_impl,
-    list_recipes_impl, list_routine_schedules_impl, list_routines_impl, list_synced_events_impl,
-    list_tasks_impl,
-    move_module_folder_impl, move_module_impl, next_step_impl,
+    delete_routine_schedule_impl, delete_task_impl, export_blocks_ics_impl,
+    export_day_markdown_impl, generate_blocks_impl,
+    generate_from_source_impl, generate_one_block_impl, generate_today_blocks_impl,
+    get_effective_config_impl,
+    get_block_reflection_impl, get_blocks_localized_impl,
+    get_calendar_timezone_impl,
+    get_estimated_completion_impl, get_longest_focus_impl, get_timezone_impl,
+    get_busy_summary_impl, get_metrics_impl, get_overlapping_blocks_impl, get_pomodoro_state_impl,
+    get_session_logs_impl,
+    get_reflection_summary_impl, get_task_investment_impl, get_week_capacity_impl,
+    get_workspace_paths_impl,
+    interrupt_timer_impl, is_work_time_impl, list_blocks_impl, list_module_folders_impl,
+    list_modules_impl,
+    list_holidays_impl, list_timezones_impl, list_recipes_impl, list_routine_schedules_impl,
+    list_routines_impl,
+    list_synced_events_impl, list_synced_events_range_impl,
+    list_tasks_impl, list_unassigned_tasks_impl, log_pomodoro_impl, lint_config_impl,
+    materialize_recurring_tasks_impl,
+    move_module_folder_impl, move_module_impl, move_task_impl, next_auto_generation_impl,
+    next_step_impl,
     pause_pomodoro_impl,
-    pause_timer_impl, relocate_if_needed_impl, resume_pomodoro_impl, resume_timer_impl,
-    save_routine_schedule_group_impl, save_routine_schedule_impl, split_task_impl,
-    start_block_timer_impl, start_pomodoro_impl,
-    sync_calendar_impl, update_module_impl, update_recipe_impl, update_task_impl, AppState,
+    pause_timer_impl, reauthenticate_account_impl, reflow_day_impl, report_activity_impl,
+    regenerate_auto_blocks_impl, remove_holiday_impl,
+    relocate_blocks_impl, relocate_if_needed_impl,
+    remaining_capacity_impl, reschedule_block_impl, reset_day_impl, reset_sync_impl,
+    run_startup_catch_up_impl,
+    resume_pomodoro_impl, resume_timer_impl,
+    save_routine_schedule_group_impl, save_routine_schedule_impl, set_block_project_impl,
+    set_blocks_calendar_impl, set_current_task_impl, snooze_block_impl,
+    split_task_impl, start_block_timer_impl, start_pomodoro_impl, suggest_block_for_task_impl,
+    suggest_pomodoros_impl,
+    sync_calendar_impl, sync_pending_block_events_impl, tick_pomodoro_impl, update_module_impl,
+    update_recipe_impl,
+    update_task_impl, validate_rrule_impl, validate_timezone_impl, AppState,
     apply_studio_template_to_today_impl, ApplyStudioResult, AuthenticateGoogleResponse,
-    CarryOverTaskResponse, PomodoroStateResponse,
-    ReflectionSummaryResponse, SyncedEventSlotResponse, SyncCalendarResponse,
+    AuthorizationUrlResponse, BlockReflection, BusySummary, LocalizedBlock,
+    CarryOverTaskResponse, ConfigIssue, EffectiveConfig, LongestFocus, Metrics,
+    PomodoroStateResponse, RelocateBlocksResult,
+    ReflectionLogItem, ReflectionSummaryResponse, RrulePreview, SyncedEventSlotResponse,
+    SyncCalendarResponse, TaskInvestment, ValidateTimezoneResponse, WeekCapacity, WorkspacePaths,
 };
-use domain::models::{Block, Module, ModuleFolder, Recipe, Task};
+#[cfg(feature = "debug-tools")]
+use application::commands::{debug_roundtrip_block_impl, get_block_calendar_event_impl};
+use domain::models::{Block, Module, ModuleFolder, PomodoroLog, Recipe, Task};
+#[cfg(feature = "debug-tools")]
+use infrastructure::event_mapper::GoogleCalendarEvent;
 use serde_json::Value;
 use serde::Serialize;
 use std::path::{Path, PathBuf};
+use tauri::{Emitter, Manager};
 
 #[derive(Debug, Serialize)]
 struct BootstrapResponse {
@@ -92,13 +128,20 @@ fn ping() -> &'static str {
     "pong"
 }
 
+#[tauri::command]
+fn get_workspace_paths(state: tauri::State<'_, AppState>) -> Result<WorkspacePaths, String> {
+    get_workspace_paths_impl(state.inner())
+        .map_err(|error| state.command_error("get_workspace_paths", &error))
+}
+
 #[tauri::command]
 async fn authenticate_google(
     state: tauri::State<'_, AppState>,
     account_id: Option<String>,
     authorization_code: Option<String>,
+    oauth_state: Option<String>,
 ) -> Result<AuthenticateGoogleResponse, String> {
-    authenticate_google_impl(state.inner(), account_id, authorization_code)
+    authenticate_google_impl(state.inner(), account_id, authorization_code, oauth_state)
         .await
         .map_err(|error| state.command_error("authenticate_google", &error))
 }
@@ -114,6 +157,41 @@ async fn authenticate_google_sso(
         .map_err(|error| state.command_error("authenticate_google_sso", &error))
 }
 
+#[tauri::command]
+async fn reauthenticate_account(
+    state: tauri::State<'_, AppState>,
+    account_id: String,
+) -> Result<AuthenticateGoogleResponse, String> {
+    reauthenticate_account_impl(state.inner(), account_id)
+        .await
+        .map_err(|error| state.command_error("reauthenticate_account", &error))
+}
+
+#[tauri::command]
+fn cancel_sso(state: tauri::State<'_, AppState>) -> Result<bool, String> {
+    cancel_sso_impl(state.inner()).map_err(|error| state.command_error("cancel_sso", &error))
+}
+
+#[tauri::command]
+fn build_authorization_url(
+    state: tauri::State<'_, AppState>,
+    account_id: Option<String>,
+) -> Result<AuthorizationUrlResponse, String> {
+    build_authorization_url_impl(state.inner(), account_id)
+        .map_err(|error| state.command_error("build_authorization_url", &error))
+}
+
+#[tauri::command]
+async fn set_blocks_calendar(
+    state: tauri::State<'_, AppState>,
+    account_id: Option<String>,
+    calendar_id: String,
+) -> Result<String, String> {
+    set_blocks_calendar_impl(state.inner(), account_id, calendar_id)
+        .await
+        .map_err(|error| state.command_error("set_blocks_calendar", &error))
+}
+
 #[tauri::command]
 async fn sync_calendar(
     state: tauri::State<'_, AppState>,
@@ -126,6 +204,16 @@ async fn sync_calendar(
         .map_err(|error| state.command_error("sync_calendar", &error))
 }
 
+#[tauri::command]
+async fn get_calendar_timezone(
+    state: tauri::State<'_, AppState>,
+    account_id: Option<String>,
+) -> Result<Option<String>, String> {
+    get_calendar_timezone_impl(state.inner(), account_id)
+        .await
+        .map_err(|error| state.command_error("get_calendar_timezone", &error))
+}
+
 #[tauri::command]
 async fn generate_blocks(
     state: tauri::State<'_, AppState>,
@@ -147,6 +235,17 @@ async fn generate_today_blocks(
         .map_err(|error| state.command_error("generate_today_blocks", &error))
 }
 
+#[tauri::command]
+async fn run_startup_catch_up(
+    state: tauri::State<'_, AppState>,
+    last_run_date: Option<String>,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, String> {
+    run_startup_catch_up_impl(state.inner(), last_run_date, account_id)
+        .await
+        .map_err(|error| state.command_error("run_startup_catch_up", &error))
+}
+
 #[tauri::command]
 async fn generate_one_block(
     state: tauri::State<'_, AppState>,
@@ -158,6 +257,29 @@ async fn generate_one_block(
         .map_err(|error| state.command_error("generate_one_block", &error))
 }
 
+#[tauri::command]
+async fn generate_from_source(
+    state: tauri::State<'_, AppState>,
+    date: String,
+    source_id: String,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, String> {
+    generate_from_source_impl(state.inner(), date, source_id, account_id)
+        .await
+        .map_err(|error| state.command_error("generate_from_source", &error))
+}
+
+#[tauri::command]
+async fn regenerate_auto_blocks(
+    state: tauri::State<'_, AppState>,
+    date: String,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, String> {
+    regenerate_auto_blocks_impl(state.inner(), date, account_id)
+        .await
+        .map_err(|error| state.command_error("regenerate_auto_blocks", &error))
+}
+
 #[tauri::command]
 async fn approve_blocks(
     state: tauri::State<'_, AppState>,
@@ -169,12 +291,39 @@ async fn approve_blocks(
 }
 
 #[tauri::command]
-async fn delete_block(state: tauri::State<'_, AppState>, block_id: String) -> Result<bool, String> {
-    delete_block_impl(state.inner(), block_id)
+async fn delete_block(
+    state: tauri::State<'_, AppState>,
+    block_id: String,
+    expires_at: Option<String>,
+) -> Result<bool, String> {
+    delete_block_impl(state.inner(), block_id, expires_at)
         .await
         .map_err(|error| state.command_error("delete_block", &error))
 }
 
+#[tauri::command]
+async fn clear_day(
+    state: tauri::State<'_, AppState>,
+    date: String,
+    account_id: Option<String>,
+    suppress: bool,
+) -> Result<usize, String> {
+    clear_day_impl(state.inner(), date, account_id, suppress)
+        .await
+        .map_err(|error| state.command_error("clear_day", &error))
+}
+
+#[tauri::command]
+async fn reset_day(
+    state: tauri::State<'_, AppState>,
+    date: String,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, String> {
+    reset_day_impl(state.inner(), date, account_id)
+        .await
+        .map_err(|error| state.command_error("reset_day", &error))
+}
+
 #[tauri::command]
 async fn adjust_block_time(
     state: tauri::State<'_, AppState>,
@@ -188,8 +337,137 @@ async fn adjust_block_time(
 }
 
 #[tauri::command]
-fn list_blocks(state: tauri::State<'_, AppState>, date: Option<String>) -> Result<Vec<Block>, String> {
-    list_blocks_impl(state.inner(), date).map_err(|error| state.command_error("list_blocks", &error))
+fn get_blocks_localized(
+    state: tauri::State<'_, AppState>,
+    date: Option<String>,
+    project: Option<String>,
+) -> Result<Vec<LocalizedBlock>, String> {
+    get_blocks_localized_impl(state.inner(), date, project)
+        .map_err(|error| state.command_error("get_blocks_localized", &error))
+}
+
+#[tauri::command]
+fn list_blocks(
+    state: tauri::State<'_, AppState>,
+    date: Option<String>,
+    project: Option<String>,
+) -> Result<Vec<Block>, String> {
+    list_blocks_impl(state.inner(), date, project)
+        .map_err(|error| state.command_error("list_blocks", &error))
+}
+
+#[tauri::command]
+async fn set_block_project(
+    state: tauri::State<'_, AppState>,
+    block_id: String,
+    project: Option<String>,
+) -> Result<Block, String> {
+    set_block_project_impl(state.inner(), block_id, project)
+        .await
+        .map_err(|error| state.command_error("set_block_project", &error))
+}
+
+#[tauri::command]
+fn remaining_capacity(
+    state: tauri::State<'_, AppState>,
+    date: String,
+    account_id: Option<String>,
+) -> Result<u32, String> {
+    remaining_capacity_impl(state.inner(), date, account_id)
+        .map_err(|error| state.command_error("remaining_capacity", &error))
+}
+
+#[tauri::command]
+fn get_week_capacity(
+    state: tauri::State<'_, AppState>,
+    week_start: String,
+    account_id: Option<String>,
+) -> Result<WeekCapacity, String> {
+    get_week_capacity_impl(state.inner(), week_start, account_id)
+        .map_err(|error| state.command_error("get_week_capacity", &error))
+}
+
+#[tauri::command]
+async fn sync_pending_block_events(
+    state: tauri::State<'_, AppState>,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, String> {
+    sync_pending_block_events_impl(state.inner(), account_id)
+        .await
+        .map_err(|error| state.command_error("sync_pending_block_events", &error))
+}
+
+#[cfg(feature = "debug-tools")]
+#[tauri::command]
+async fn get_block_calendar_event(
+    state: tauri::State<'_, AppState>,
+    block_id: String,
+    account_id: Option<String>,
+) -> Result<Option<GoogleCalendarEvent>, String> {
+    get_block_calendar_event_impl(state.inner(), block_id, account_id)
+        .await
+        .map_err(|error| state.command_error("get_block_calendar_event", &error))
+}
+
+#[tauri::command]
+async fn cleanup_orphaned_events(
+    state: tauri::State<'_, AppState>,
+    account_id: Option<String>,
+) -> Result<usize, String> {
+    cleanup_orphaned_events_impl(state.inner(), account_id)
+        .await
+        .map_err(|error| state.command_error("cleanup_orphaned_events", &error))
+}
+
+#[tauri::command]
+fn get_busy_summary(
+    state: tauri::State<'_, AppState>,
+    date: String,
+    account_id: Option<String>,
+) -> Result<BusySummary, String> {
+    get_busy_summary_impl(state.inner(), date, account_id)
+        .map_err(|error| state.command_error("get_busy_summary", &error))
+}
+
+#[tauri::command]
+fn get_overlapping_blocks(
+    state: tauri::State<'_, AppState>,
+    date: String,
+) -> Result<Vec<(String, String)>, String> {
+    get_overlapping_blocks_impl(state.inner(), date)
+        .map_err(|error| state.command_error("get_overlapping_blocks", &error))
+}
+
+#[tauri::command]
+fn export_blocks_ics(
+    state: tauri::State<'_, AppState>,
+    start_date: String,
+    end_date: String,
+    path: String,
+) -> Result<usize, String> {
+    export_blocks_ics_impl(state.inner(), start_date, end_date, path)
+        .map_err(|error| state.command_error("export_blocks_ics", &error))
+}
+
+#[tauri::command]
+fn export_day_markdown(
+    state: tauri::State<'_, AppState>,
+    date: String,
+    account_id: Option<String>,
+) -> Result<String, String> {
+    export_day_markdown_impl(state.inner(), date, account_id)
+        .map_err(|error| state.command_error("export_day_markdown", &error))
+}
+
+#[tauri::command]
+async fn reset_sync(
+    state: tauri::State<'_, AppState>,
+    account_id: Option<String>,
+    calendar_id: String,
+) -> Result<(), String> {
+    reset_sync_impl(state.inner(), account_id, calendar_id)
+        .await
+        .map_err(|error| state.command_error("reset_sync", &error))
 }
 
 #[tauri::command]
@@ -203,6 +481,17 @@ fn list_synced_events(
         .map_err(|error| state.command_error("list_synced_events", &error))
 }
 
+#[tauri::command]
+fn list_synced_events_range(
+    state: tauri::State<'_, AppState>,
+    account_id: Option<String>,
+    start_date: String,
+    end_date: String,
+) -> Result<Vec<SyncedEventSlotResponse>, String> {
+    list_synced_events_range_impl(state.inner(), account_id, start_date, end_date)
+        .map_err(|error| state.command_error("list_synced_events_range", &error))
+}
+
 #[tauri::command]
 fn start_pomodoro(
     state: tauri::State<'_, AppState>,
@@ -222,6 +511,21 @@ fn pause_pomodoro(
         .map_err(|error| state.command_error("pause_pomodoro", &error))
 }
 
+#[tauri::command]
+fn set_current_task(
+    state: tauri::State<'_, AppState>,
+    task_id: Option<String>,
+) -> Result<PomodoroStateResponse, String> {
+    set_current_task_impl(state.inner(), task_id)
+        .map_err(|error| state.command_error("set_current_task", &error))
+}
+
+#[tauri::command]
+fn get_session_logs(state: tauri::State<'_, AppState>) -> Result<Vec<ReflectionLogItem>, String> {
+    get_session_logs_impl(state.inner())
+        .map_err(|error| state.command_error("get_session_logs", &error))
+}
+
 #[tauri::command]
 fn get_pomodoro_state(state: tauri::State<'_, AppState>) -> Result<PomodoroStateResponse, String> {
     get_pomodoro_state_impl(state.inner())
@@ -233,6 +537,28 @@ fn advance_pomodoro(state: tauri::State<'_, AppState>) -> Result<PomodoroStateRe
     advance_pomodoro_impl(state.inner()).map_err(|error| state.command_error("advance_pomodoro", &error))
 }
 
+#[tauri::command]
+fn tick_pomodoro(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<PomodoroStateResponse, String> {
+    tick_pomodoro_impl(state.inner(), |event, payload| {
+        if let Err(error) = app.emit(event, payload) {
+            state.log_error("tick_pomodoro", &format!("failed to emit {event}: {error}"));
+        }
+    })
+    .map_err(|error| state.command_error("tick_pomodoro", &error))
+}
+
+#[tauri::command]
+fn report_activity(
+    state: tauri::State<'_, AppState>,
+    at: Option<String>,
+) -> Result<PomodoroStateResponse, String> {
+    report_activity_impl(state.inner(), at)
+        .map_err(|error| state.command_error("report_activity", &error))
+}
+
 #[tauri::command]
 fn resume_pomodoro(state: tauri::State<'_, AppState>) -> Result<PomodoroStateResponse, String> {
     resume_pomodoro_impl(state.inner()).map_err(|error| state.command_error("resume_pomodoro", &error))
@@ -245,8 +571,49 @@ fn complete_pomodoro(state: tauri::State<'_, AppState>) -> Result<PomodoroStateR
 }
 
 #[tauri::command]
-fn list_tasks(state: tauri::State<'_, AppState>) -> Result<Vec<Task>, String> {
-    list_tasks_impl(state.inner()).map_err(|error| state.command_error("list_tasks", &error))
+fn log_pomodoro(
+    state: tauri::State<'_, AppState>,
+    block_id: String,
+    task_id: Option<String>,
+    start: String,
+    end: String,
+    interrupted: Option<bool>,
+) -> Result<PomodoroLog, String> {
+    log_pomodoro_impl(state.inner(), block_id, task_id, start, end, interrupted.unwrap_or(false))
+        .map_err(|error| state.command_error("log_pomodoro", &error))
+}
+
+#[tauri::command]
+fn create_tasks_bulk(
+    state: tauri::State<'_, AppState>,
+    lines: String,
+    default_estimate: Option<u32>,
+) -> Result<Vec<Task>, String> {
+    create_tasks_bulk_impl(state.inner(), lines, default_estimate)
+        .map_err(|error| state.command_error("create_tasks_bulk", &error))
+}
+
+#[tauri::command]
+fn list_tasks(
+    state: tauri::State<'_, AppState>,
+    query: Option<String>,
+) -> Result<Vec<Task>, String> {
+    list_tasks_impl(state.inner(), query).map_err(|error| state.command_error("list_tasks", &error))
+}
+
+#[tauri::command]
+fn list_unassigned_tasks(state: tauri::State<'_, AppState>) -> Result<Vec<Task>, String> {
+    list_unassigned_tasks_impl(state.inner())
+        .map_err(|error| state.command_error("list_unassigned_tasks", &error))
+}
+
+#[tauri::command]
+fn materialize_recurring_tasks(
+    state: tauri::State<'_, AppState>,
+    date: String,
+) -> Result<Vec<Task>, String> {
+    materialize_recurring_tasks_impl(state.inner(), date)
+        .map_err(|error| state.command_error("materialize_recurring_tasks", &error))
 }
 
 #[tauri::command]
@@ -302,6 +669,23 @@ fn save_routine_schedule_group(
         .map_err(|error| state.command_error("save_routine_schedule_group", &error))
 }
 
+#[tauri::command]
+fn list_holidays(state: tauri::State<'_, AppState>) -> Result<Vec<String>, String> {
+    list_holidays_impl(state.inner()).map_err(|error| state.command_error("list_holidays", &error))
+}
+
+#[tauri::command]
+fn add_holiday(state: tauri::State<'_, AppState>, date: String) -> Result<Vec<String>, String> {
+    add_holiday_impl(state.inner(), date)
+        .map_err(|error| state.command_error("add_holiday", &error))
+}
+
+#[tauri::command]
+fn remove_holiday(state: tauri::State<'_, AppState>, date: String) -> Result<bool, String> {
+    remove_holiday_impl(state.inner(), date)
+        .map_err(|error| state.command_error("remove_holiday", &error))
+}
+
 #[tauri::command]
 fn delete_routine_schedule(state: tauri::State<'_, AppState>, routine_id: String) -> Result<bool, String> {
     delete_routine_schedule_impl(state.inner(), routine_id)
@@ -420,6 +804,7 @@ fn update_task(
     description: Option<String>,
     estimated_pomodoros: Option<u32>,
     status: Option<String>,
+    preferred_block_type: Option<String>,
 ) -> Result<Task, String> {
     update_task_impl(
         state.inner(),
@@ -428,6 +813,7 @@ fn update_task(
         description,
         estimated_pomodoros,
         status,
+        preferred_block_type,
     )
     .map_err(|error| state.command_error("update_task", &error))
 }
@@ -437,6 +823,16 @@ fn delete_task(state: tauri::State<'_, AppState>, task_id: String) -> Result<boo
     delete_task_impl(state.inner(), task_id).map_err(|error| state.command_error("delete_task", &error))
 }
 
+#[tauri::command]
+fn move_task(
+    state: tauri::State<'_, AppState>,
+    task_id: String,
+    position: String,
+) -> Result<Vec<Task>, String> {
+    move_task_impl(state.inner(), task_id, position)
+        .map_err(|error| state.command_error("move_task", &error))
+}
+
 #[tauri::command]
 fn split_task(
     state: tauri::State<'_, AppState>,
@@ -447,6 +843,12 @@ fn split_task(
         .map_err(|error| state.command_error("split_task", &error))
 }
 
+#[tauri::command]
+fn begin_task(state: tauri::State<'_, AppState>, task_id: String) -> Result<Task, String> {
+    begin_task_impl(state.inner(), task_id)
+        .map_err(|error| state.command_error("begin_task", &error))
+}
+
 #[tauri::command]
 fn carry_over_task(
     state: tauri::State<'_, AppState>,
@@ -458,6 +860,42 @@ fn carry_over_task(
         .map_err(|error| state.command_error("carry_over_task", &error))
 }
 
+#[tauri::command]
+fn suggest_block_for_task(
+    state: tauri::State<'_, AppState>,
+    task_id: String,
+    date: String,
+    account_id: Option<String>,
+) -> Result<Option<Block>, String> {
+    suggest_block_for_task_impl(state.inner(), task_id, date, account_id)
+        .map_err(|error| state.command_error("suggest_block_for_task", &error))
+}
+
+#[tauri::command]
+fn carry_over_candidates(
+    state: tauri::State<'_, AppState>,
+    from_block_id: String,
+) -> Result<Vec<Block>, String> {
+    carry_over_candidates_impl(state.inner(), from_block_id)
+        .map_err(|error| state.command_error("carry_over_candidates", &error))
+}
+
+#[tauri::command]
+fn get_estimated_completion(
+    state: tauri::State<'_, AppState>,
+    task_id: String,
+    account_id: Option<String>,
+) -> Result<Option<String>, String> {
+    get_estimated_completion_impl(state.inner(), task_id, account_id)
+        .map_err(|error| state.command_error("get_estimated_completion", &error))
+}
+
+#[tauri::command]
+fn suggest_pomodoros(state: tauri::State<'_, AppState>, estimated_minutes: u32) -> Result<u32, String> {
+    suggest_pomodoros_impl(state.inner(), estimated_minutes)
+        .map_err(|error| state.command_error("suggest_pomodoros", &error))
+}
+
 #[tauri::command]
 fn start_block_timer(
     state: tauri::State<'_, AppState>,
@@ -506,36 +944,204 @@ async fn relocate_if_needed(
         .map_err(|error| state.command_error("relocate_if_needed", &error))
 }
 
+#[tauri::command]
+async fn relocate_blocks(
+    state: tauri::State<'_, AppState>,
+    block_ids: Vec<String>,
+    account_id: Option<String>,
+) -> Result<RelocateBlocksResult, String> {
+    relocate_blocks_impl(state.inner(), block_ids, account_id)
+        .await
+        .map_err(|error| state.command_error("relocate_blocks", &error))
+}
+
+#[tauri::command]
+async fn reschedule_block(
+    state: tauri::State<'_, AppState>,
+    block_id: String,
+    account_id: Option<String>,
+) -> Result<Option<Block>, String> {
+    reschedule_block_impl(state.inner(), block_id, account_id)
+        .await
+        .map_err(|error| state.command_error("reschedule_block", &error))
+}
+
+#[tauri::command]
+async fn snooze_block(
+    state: tauri::State<'_, AppState>,
+    block_id: String,
+    minutes: i64,
+    account_id: Option<String>,
+    allow_outside_window: Option<bool>,
+) -> Result<Block, String> {
+    snooze_block_impl(state.inner(), block_id, minutes, account_id, allow_outside_window)
+        .await
+        .map_err(|error| state.command_error("snooze_block", &error))
+}
+
+#[tauri::command]
+async fn reflow_day(
+    state: tauri::State<'_, AppState>,
+    date: String,
+    account_id: Option<String>,
+) -> Result<Vec<Block>, String> {
+    reflow_day_impl(state.inner(), date, account_id)
+        .await
+        .map_err(|error| state.command_error("reflow_day", &error))
+}
+
 #[tauri::command]
 fn get_reflection_summary(
     state: tauri::State<'_, AppState>,
     start: Option<String>,
     end: Option<String>,
+    log_limit: Option<usize>,
 ) -> Result<ReflectionSummaryResponse, String> {
-    get_reflection_summary_impl(state.inner(), start, end)
+    get_reflection_summary_impl(state.inner(), start, end, log_limit)
         .map_err(|error| state.command_error("get_reflection_summary", &error))
 }
 
+#[tauri::command]
+fn get_block_reflection(
+    state: tauri::State<'_, AppState>,
+    block_id: String,
+) -> Result<BlockReflection, String> {
+    get_block_reflection_impl(state.inner(), block_id)
+        .map_err(|error| state.command_error("get_block_reflection", &error))
+}
+
+#[tauri::command]
+fn get_longest_focus(
+    state: tauri::State<'_, AppState>,
+    start: String,
+    end: String,
+) -> Result<Option<LongestFocus>, String> {
+    get_longest_focus_impl(state.inner(), start, end)
+        .map_err(|error| state.command_error("get_longest_focus", &error))
+}
+
+#[tauri::command]
+fn get_task_investment(state: tauri::State<'_, AppState>) -> Result<Vec<TaskInvestment>, String> {
+    get_task_investment_impl(state.inner())
+        .map_err(|error| state.command_error("get_task_investment", &error))
+}
+
+#[tauri::command]
+fn get_effective_config(state: tauri::State<'_, AppState>) -> Result<EffectiveConfig, String> {
+    get_effective_config_impl(state.inner())
+        .map_err(|error| state.command_error("get_effective_config", &error))
+}
+
+#[tauri::command]
+fn lint_config(state: tauri::State<'_, AppState>) -> Result<Vec<ConfigIssue>, String> {
+    lint_config_impl(state.inner()).map_err(|error| state.command_error("lint_config", &error))
+}
+
+#[tauri::command]
+fn next_auto_generation(state: tauri::State<'_, AppState>) -> Result<Option<String>, String> {
+    next_auto_generation_impl(state.inner())
+        .map_err(|error| state.command_error("next_auto_generation", &error))
+}
+
+#[tauri::command]
+fn get_timezone(state: tauri::State<'_, AppState>) -> Result<String, String> {
+    get_timezone_impl(state.inner()).map_err(|error| state.command_error("get_timezone", &error))
+}
+
+#[tauri::command]
+fn list_timezones() -> Vec<String> {
+    list_timezones_impl()
+}
+
+#[tauri::command]
+fn validate_timezone(
+    state: tauri::State<'_, AppState>,
+    timezone: String,
+) -> Result<ValidateTimezoneResponse, String> {
+    validate_timezone_impl(state.inner(), timezone)
+        .map_err(|error| state.command_error("validate_timezone", &error))
+}
+
+#[tauri::command]
+fn is_work_time(state: tauri::State<'_, AppState>) -> Result<bool, String> {
+    is_work_time_impl(state.inner()).map_err(|error| state.command_error("is_work_time", &error))
+}
+
+#[tauri::command]
+fn validate_rrule(
+    state: tauri::State<'_, AppState>,
+    rrule: String,
+) -> Result<RrulePreview, String> {
+    validate_rrule_impl(state.inner(), rrule)
+        .map_err(|error| state.command_error("validate_rrule", &error))
+}
+
+#[tauri::command]
+fn get_metrics(state: tauri::State<'_, AppState>) -> Result<Metrics, String> {
+    get_metrics_impl(state.inner()).map_err(|error| state.command_error("get_metrics", &error))
+}
+
+#[cfg(feature = "debug-tools")]
+#[tauri::command]
+fn debug_roundtrip_block(
+    state: tauri::State<'_, AppState>,
+    block: Block,
+) -> Result<Block, String> {
+    debug_roundtrip_block_impl(state.inner(), block)
+        .map_err(|error| state.command_error("debug_roundtrip_block", &error))
+}
+
 pub fn run() {
     let workspace_root = default_workspace_root().expect("failed to resolve workspace root");
     let app_state = AppState::new(workspace_root).expect("failed to initialize app state");
 
     tauri::Builder::default()
         .manage(app_state)
+        .setup(|app| {
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                run_auto_generation(handle.state::<AppState>().inner()).await;
+            });
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             ping,
             bootstrap,
+            get_workspace_paths,
             authenticate_google,
             authenticate_google_sso,
+            reauthenticate_account,
+            cancel_sso,
+            build_authorization_url,
+            set_blocks_calendar,
             sync_calendar,
+            get_calendar_timezone,
             generate_blocks,
             generate_today_blocks,
+            run_startup_catch_up,
             generate_one_block,
+            generate_from_source,
+            regenerate_auto_blocks,
             approve_blocks,
             delete_block,
+            clear_day,
+            reset_day,
             adjust_block_time,
             list_blocks,
+            get_blocks_localized,
+            get_overlapping_blocks,
+            get_busy_summary,
+            remaining_capacity,
+            get_week_capacity,
+            sync_pending_block_events,
+            cleanup_orphaned_events,
+            #[cfg(feature = "debug-tools")]
+            get_block_calendar_event,
+            export_blocks_ics,
+            export_day_markdown,
             list_synced_events,
+            list_synced_events_range,
+            reset_sync,
             list_recipes,
             create_recipe,
             update_recipe,
@@ -553,28 +1159,69 @@ pub fn run() {
             start_pomodoro,
             start_block_timer,
             pause_pomodoro,
+            set_current_task,
             pause_timer,
             get_pomodoro_state,
+            get_session_logs,
             advance_pomodoro,
+            tick_pomodoro,
+            report_activity,
             next_step,
             interrupt_timer,
             resume_pomodoro,
             resume_timer,
             complete_pomodoro,
+            log_pomodoro,
             list_tasks,
+            list_unassigned_tasks,
+            materialize_recurring_tasks,
             create_task,
+            create_tasks_bulk,
             update_task,
             delete_task,
+            move_task,
             list_routine_schedules,
             list_routines,
             save_routine_schedule,
             save_routine_schedule_group,
             delete_routine_schedule,
+            list_holidays,
+            add_holiday,
+            remove_holiday,
             split_task,
+            begin_task,
             carry_over_task,
+            carry_over_candidates,
+            suggest_block_for_task,
+            get_estimated_completion,
+            suggest_pomodoros,
             relocate_if_needed,
-            get_reflection_summary
+            relocate_blocks,
+            reschedule_block,
+            set_block_project,
+            snooze_block,
+            reflow_day,
+            get_reflection_summary,
+            get_block_reflection,
+            get_longest_focus,
+            get_task_investment,
+            get_metrics,
+            #[cfg(feature = "debug-tools")]
+            debug_roundtrip_block,
+            get_effective_config,
+            lint_config,
+            next_auto_generation,
+            is_work_time,
+            validate_timezone,
+            get_timezone,
+            list_timezones,
+            validate_rrule
         ])
-        .run(tauri::generate_context!())
-        .expect("failed to run tauri app");
+        .build(tauri::generate_context!())
+        .expect("failed to build tauri app")
+        .run(|app, event| {
+            if let tauri::RunEvent::Exit = event {
+                app.state::<AppState>().shutdown_auto_generation();
+            }
+        });
 }